@@ -79,4 +79,19 @@ fn main() {
     assert!(g['G'].get() == 9);
     assert!(g['H'].get() == 8);
     assert!(g['I'].get() == 14);
+
+    // The same distances can be computed without storing them in the nodes
+    // by using the built-in `dijkstra()` method. It takes a closure returning
+    // the weight of an edge and keeps the distances in a side table. The
+    // result can also be used to reconstruct the shortest paths.
+    let paths = g.dijkstra(&'A', |Edge(_, _, e)| *e).unwrap();
+
+    for (key, node) in g.iter() {
+        assert!(paths.distance_to(key) == Some(node.get()));
+    }
+
+    let path = paths.path_to(&'E').unwrap();
+    let weight = path.iter_edges().map(|Edge(_, _, e)| e).sum::<u64>();
+
+    assert!(weight == 21);
 }
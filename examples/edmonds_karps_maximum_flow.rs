@@ -113,4 +113,18 @@ fn main() {
 
     // For this Graph we expect the maximum flow from 0 -> 5 to be 23
     assert!(max_flow(&g) == 23);
+
+    // The library keeps the residual network internally, so the edges only
+    // need to hold their capacity.
+    let g = gdsl::digraph![
+        (usize) => [u64]
+        (0) => [ (1, 16), (2, 13) ]
+        (1) => [ (2, 10), (3, 12) ]
+        (2) => [ (1, 4), (4, 14) ]
+        (3) => [ (2, 9), (5, 20) ]
+        (4) => [ (3, 7), (5, 4) ]
+        (5) => []
+    ];
+    let flow = g.edmonds_karp(&0, &5, |Edge(_, _, c)| *c).unwrap();
+    assert!(flow.value() == 23);
 }
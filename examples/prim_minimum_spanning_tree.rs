@@ -111,4 +111,13 @@ fn main() {
     let forest = prim_minimum_spanning_tree(&g2[0]);
     let sum = forest.iter().fold(0, |acc, e| acc + e.2);
     assert!(sum == 42);
+
+    // The same spanning trees are available as library functions, which
+    // also handle graphs with several components.
+    for (g, expected) in [(&g1, 16), (&g2, 42)] {
+        for forest in [g.prim(|Edge(_, _, e)| *e), g.kruskal(|Edge(_, _, e)| *e)] {
+            let sum = forest.iter().fold(0, |acc, e| acc + e.2);
+            assert!(sum == expected);
+        }
+    }
 }
@@ -0,0 +1,405 @@
+use super::{path::*, shortest_paths::*, *};
+use ahash::AHashMap as HashMap;
+use std::{cmp::Ordering, collections::BinaryHeap, fmt::Debug};
+
+/// The shortest path lengths between all pairs of nodes in a graph.
+///
+/// The table is keyed by pairs of node keys and keeps, for every pair, the
+/// last edge on a shortest path between them, from which the whole path is
+/// reconstructed on demand.
+pub struct DistanceTable<G, W>
+where
+    G: GraphNode,
+{
+    nodes: Vec<G>,
+    index: HashMap<G::Key, usize>,
+    edges: Vec<G::Edge>,
+    distances: Vec<Option<W>>,
+    last: Vec<Option<usize>>,
+}
+
+impl<G, W> DistanceTable<G, W>
+where
+    G: GraphNode,
+    W: Weight,
+{
+    /// Returns the length of a shortest path from the node with the key
+    /// `from` to the node with the key `to`, or `None` if there is no path.
+    pub fn distance(&self, from: &G::Key, to: &G::Key) -> Option<W> {
+        let (i, j) = (*self.index.get(from)?, *self.index.get(to)?);
+        self.distances[i * self.nodes.len() + j]
+    }
+
+    /// Returns a shortest path from the node with the key `from` to the
+    /// node with the key `to`, or `None` if there is no path. The path from
+    /// a node to itself contains no edges.
+    pub fn path(&self, from: &G::Key, to: &G::Key) -> Option<Path<G>> {
+        let (i, mut j) = (*self.index.get(from)?, *self.index.get(to)?);
+        let n = self.nodes.len();
+        self.distances[i * n + j]?;
+
+        let mut edges = vec![];
+        while let Some(e) = self.last[i * n + j] {
+            let edge = &self.edges[e];
+            edges.push(edge.clone());
+            j = self.index[edge.source().key()];
+            if j == i {
+                break;
+            }
+        }
+        edges.reverse();
+        Some(Path { edges })
+    }
+
+    /// Returns an iterator over all pairs of keys that are connected by a
+    /// path, along with the length of a shortest path between them.
+    pub fn iter(&self) -> impl Iterator<Item = (&G::Key, &G::Key, W)> {
+        let n = self.nodes.len();
+        self.distances
+            .iter()
+            .enumerate()
+            .filter_map(move |(ij, distance)| {
+                let (u, v) = (&self.nodes[ij / n], &self.nodes[ij % n]);
+                distance.map(|distance| (u.key(), v.key(), distance))
+            })
+    }
+
+    /// Returns the amount of nodes in the table.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the table contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<G, W> Debug for DistanceTable<G, W>
+where
+    G: GraphNode,
+    W: Weight + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let distances = self
+            .iter()
+            .map(|(u, v, distance)| (u.to_string(), v.to_string(), distance));
+        f.debug_struct("DistanceTable")
+            .field("distances", &distances.collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+// A graph with its nodes and edges numbered, so that the all-pairs
+// algorithms can work on vectors and matrices instead of maps.
+struct Indexed<G, W>
+where
+    G: GraphNode,
+{
+    nodes: Vec<G>,
+    index: HashMap<G::Key, usize>,
+    edges: Vec<G::Edge>,
+    ends: Vec<(usize, usize, W)>,
+    adjacent: Vec<Vec<usize>>,
+}
+
+impl<G, W> Indexed<G, W>
+where
+    G: Neighbors,
+    W: Weight,
+{
+    fn new<C, F>(graph: &C, mut weight: F) -> Self
+    where
+        C: GraphContainer<Node = G>,
+        F: FnMut(&G::Edge) -> W,
+    {
+        let nodes = graph.nodes().cloned().collect::<Vec<_>>();
+        let index = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.key().clone(), i))
+            .collect::<HashMap<_, _>>();
+
+        let mut edges = vec![];
+        let mut ends = vec![];
+        let mut adjacent = vec![vec![]; nodes.len()];
+
+        for (u, node) in nodes.iter().enumerate() {
+            for edge in node.outbound() {
+                // Edges to nodes outside of the graph are ignored.
+                if let Some(&v) = index.get(edge.target().key()) {
+                    adjacent[u].push(edges.len());
+                    ends.push((u, v, weight(&edge)));
+                    edges.push(edge);
+                }
+            }
+        }
+        Indexed {
+            nodes,
+            index,
+            edges,
+            ends,
+            adjacent,
+        }
+    }
+
+    fn into_table(
+        self,
+        distances: Vec<Option<W>>,
+        last: Vec<Option<usize>>,
+    ) -> DistanceTable<G, W> {
+        DistanceTable {
+            nodes: self.nodes,
+            index: self.index,
+            edges: self.edges,
+            distances,
+            last,
+        }
+    }
+
+    // Computes node potentials with Bellman-Ford from a virtual source that
+    // is connected to every node with an edge of length zero. Fails with a
+    // negative cycle if there is one anywhere in the graph.
+    fn potentials(&self) -> Result<Vec<W>, NegativeCycle<G>> {
+        let n = self.nodes.len();
+        let mut potentials = vec![W::default(); n];
+        let mut predecessors = vec![None; n];
+
+        let relax = |potentials: &mut [W], predecessors: &mut [Option<usize>]| {
+            let mut relaxed = false;
+            for (e, &(u, v, w)) in self.ends.iter().enumerate() {
+                if is_less(potentials[u] + w, potentials[v]) {
+                    potentials[v] = potentials[u] + w;
+                    predecessors[v] = Some(e);
+                    relaxed = true;
+                }
+            }
+            relaxed
+        };
+
+        for _ in 0..n {
+            if !relax(&mut potentials, &mut predecessors) {
+                return Ok(potentials);
+            }
+        }
+        // A negative cycle keeps the potentials from converging, but it
+        // eventually shows up among the predecessor edges.
+        loop {
+            if let Some(cycle) = self.predecessor_cycle(&predecessors) {
+                return Err(NegativeCycle::new(cycle));
+            }
+            if !relax(&mut potentials, &mut predecessors) {
+                return Ok(potentials);
+            }
+        }
+    }
+
+    fn predecessor_cycle(&self, predecessors: &[Option<usize>]) -> Option<Path<G>> {
+        let mut walked = vec![usize::MAX; self.nodes.len()];
+        for start in 0..self.nodes.len() {
+            let mut v = start;
+            while let Some(e) = predecessors[v] {
+                if walked[v] == start {
+                    let mut edges = vec![];
+                    let mut u = v;
+                    loop {
+                        let e = predecessors[u].unwrap();
+                        edges.push(self.edges[e].clone());
+                        u = self.ends[e].0;
+                        if u == v {
+                            break;
+                        }
+                    }
+                    edges.reverse();
+                    return Some(Path { edges });
+                }
+                if walked[v] != usize::MAX {
+                    break;
+                }
+                walked[v] = start;
+                v = self.ends[e].0;
+            }
+        }
+        None
+    }
+}
+
+fn is_less<W>(a: W, b: W) -> bool
+where
+    W: PartialOrd,
+{
+    a.partial_cmp(&b) == Some(Ordering::Less)
+}
+
+/// Computes the shortest paths between all pairs of nodes in `graph` with
+/// the Floyd-Warshall algorithm. The running time is cubic in the number of
+/// nodes regardless of the number of edges, which suits dense graphs. The
+/// `weight` closure may return negative values. If the graph contains a
+/// negative cycle, it is returned as the error.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (char) => [i64]
+///     ('A') => [ ('B', 3), ('C', 8) ]
+///     ('B') => [ ('C', -2) ]
+///     ('C') => [ ('A', 1) ]
+///     ('D') => [ ('A', 2) ]
+/// ];
+///
+/// let table = gdsl::algo::floyd_warshall(&g, |Edge(_, _, e)| *e).unwrap();
+///
+/// assert!(table.distance(&'A', &'C') == Some(1));
+/// assert!(table.distance(&'C', &'B') == Some(4));
+/// assert!(table.distance(&'A', &'D') == None);
+/// assert!(table.path(&'D', &'C').unwrap().len() == 4);
+/// ```
+pub fn floyd_warshall<C, G, W, F>(
+    graph: &C,
+    weight: F,
+) -> Result<DistanceTable<G, W>, NegativeCycle<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let indexed = Indexed::new(graph, weight);
+    let n = indexed.nodes.len();
+    let mut distances = vec![None; n * n];
+    let mut last = vec![None; n * n];
+
+    for i in 0..n {
+        distances[i * n + i] = Some(W::default());
+    }
+    for (e, &(u, v, w)) in indexed.ends.iter().enumerate() {
+        match distances[u * n + v] {
+            Some(current) if !is_less(w, current) => {}
+            _ => {
+                distances[u * n + v] = Some(w);
+                last[u * n + v] = Some(e);
+            }
+        }
+    }
+    // The distances around a negative cycle are meaningless and keep
+    // doubling, so the search stops as soon as a node is found to be on
+    // one, before they can overflow. The cycle itself is recovered with
+    // Bellman-Ford.
+    let negative = |d: Option<W>| d.is_some_and(|d| is_less(d, W::default()));
+    if (0..n).any(|i| negative(distances[i * n + i])) {
+        return Err(indexed.potentials().err().unwrap());
+    }
+    for k in 0..n {
+        for i in 0..n {
+            let Some(ik) = distances[i * n + k] else {
+                continue;
+            };
+            for j in 0..n {
+                let Some(kj) = distances[k * n + j] else {
+                    continue;
+                };
+                match distances[i * n + j] {
+                    Some(current) if !is_less(ik + kj, current) => {}
+                    _ => {
+                        distances[i * n + j] = Some(ik + kj);
+                        last[i * n + j] = last[k * n + j];
+                        if i == j && negative(Some(ik + kj)) {
+                            return Err(indexed.potentials().err().unwrap());
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(indexed.into_table(distances, last))
+}
+
+/// Computes the shortest paths between all pairs of nodes in `graph` with
+/// Johnson's algorithm. Edges are reweighted with potentials found by
+/// Bellman-Ford so that none of them is negative, after which Dijkstra's
+/// algorithm is run from every node. This is faster than
+/// [`floyd_warshall`] on sparse graphs. The `weight` closure may return
+/// negative values. If the graph contains a negative cycle, it is returned
+/// as the error.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (char) => [i64]
+///     ('A') => [ ('B', 3), ('C', 8) ]
+///     ('B') => [ ('C', -2) ]
+///     ('C') => [ ('A', 1) ]
+///     ('D') => [ ('A', 2) ]
+/// ];
+///
+/// let table = gdsl::algo::johnson(&g, |Edge(_, _, e)| *e).unwrap();
+///
+/// assert!(table.distance(&'D', &'C') == Some(3));
+///
+/// g['C'].connect(&g['B'], -2);
+///
+/// let cycle = gdsl::algo::johnson(&g, |Edge(_, _, e)| *e).unwrap_err();
+/// assert!(cycle.cycle().len() == 3);
+/// ```
+pub fn johnson<C, G, W, F>(graph: &C, weight: F) -> Result<DistanceTable<G, W>, NegativeCycle<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let indexed = Indexed::new(graph, weight);
+    let potentials = indexed.potentials()?;
+    let n = indexed.nodes.len();
+    let mut distances = vec![None; n * n];
+    let mut last = vec![None; n * n];
+
+    // With the potentials `h` an edge `(u, v, w)` gets the length
+    // `w + h(u) - h(v)`, which is never negative, and the length of every
+    // path from `s` to `t` changes by the same amount `h(s) - h(t)`.
+    let reweighted = |e: usize| {
+        let (u, v, w) = indexed.ends[e];
+        w + potentials[u] - potentials[v]
+    };
+
+    for s in 0..n {
+        let row = &mut distances[s * n..(s + 1) * n];
+        let row_last = &mut last[s * n..(s + 1) * n];
+        let mut finished = vec![false; n];
+        let mut queue = BinaryHeap::new();
+
+        row[s] = Some(W::default());
+        queue.push(MinScored(W::default(), s));
+
+        while let Some(MinScored(distance, u)) = queue.pop() {
+            if finished[u] {
+                continue;
+            }
+            finished[u] = true;
+            for &e in &indexed.adjacent[u] {
+                let v = indexed.ends[e].1;
+                let candidate = distance + reweighted(e);
+                match row[v] {
+                    Some(current) if finished[v] || !is_less(candidate, current) => {}
+                    _ => {
+                        row[v] = Some(candidate);
+                        row_last[v] = Some(e);
+                        queue.push(MinScored(candidate, v));
+                    }
+                }
+            }
+        }
+        for (t, distance) in row.iter_mut().enumerate() {
+            if let Some(d) = distance {
+                *d = *d + potentials[t] - potentials[s];
+            }
+        }
+    }
+    Ok(indexed.into_table(distances, last))
+}
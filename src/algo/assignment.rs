@@ -0,0 +1,236 @@
+use super::*;
+use ahash::AHashMap as HashMap;
+use std::{collections::BinaryHeap, fmt::Debug};
+
+/// An assignment of left nodes to right nodes, given as the edges between
+/// them, along with its total weight.
+pub struct Assignment<G, W>
+where
+    G: GraphNode,
+{
+    edges: Vec<G::Edge>,
+    cost: W,
+}
+
+impl<G, W> Assignment<G, W>
+where
+    G: GraphNode,
+    W: Weight,
+{
+    /// Returns the total weight of the edges of the assignment.
+    pub fn cost(&self) -> W {
+        self.cost
+    }
+
+    /// Returns the edges of the assignment, each from a left node to a
+    /// right node.
+    pub fn edges(&self) -> &[G::Edge] {
+        &self.edges
+    }
+
+    /// Consumes the assignment and returns its edges.
+    pub fn into_edges(self) -> Vec<G::Edge> {
+        self.edges
+    }
+
+    /// Returns the number of assigned pairs.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns true if no pair is assigned.
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+impl<G, W> Debug for Assignment<G, W>
+where
+    G: GraphNode,
+    W: Weight + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pairs = self
+            .edges
+            .iter()
+            .map(|edge| format!("{} -> {}", edge.source().key(), edge.target().key()))
+            .collect::<Vec<_>>();
+        f.debug_struct("Assignment")
+            .field("cost", &self.cost)
+            .field("pairs", &pairs)
+            .finish()
+    }
+}
+
+/// Solves the assignment problem with the Hungarian (Kuhn-Munkres)
+/// algorithm. The nodes for which `left` returns true form the left side,
+/// all other nodes the right side, and `weight` returns the cost of an
+/// edge. The result assigns as many left nodes as possible to distinct
+/// right nodes, and among all such assignments has the smallest total
+/// cost. Negate the weights to maximise instead.
+///
+/// Only the outbound edges of left nodes to right nodes are considered, so
+/// in a directed graph the edges must point from left to right. The graph
+/// does not need to be complete, nor the sides to be of equal size. Edges
+/// to nodes outside of the graph are ignored.
+///
+/// The assignment is grown one pair at a time along a cheapest augmenting
+/// path, found with Dijkstra's algorithm from all unassigned left nodes at
+/// once. Node potentials keep the reduced costs non-negative, so weights
+/// may be negative.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// // The cost of running each job on each machine.
+/// let g = gdsl::digraph![
+///     (&str) => [u32]
+///     ("job1") => [ ("m1", 4), ("m2", 1), ("m3", 3) ]
+///     ("job2") => [ ("m1", 2), ("m2", 0), ("m3", 5) ]
+///     ("job3") => [ ("m1", 3), ("m2", 2), ("m3", 2) ]
+///     ("m1") => []
+///     ("m2") => []
+///     ("m3") => []
+/// ];
+///
+/// let assignment = gdsl::algo::hungarian(
+///     &g,
+///     |node| node.key().starts_with("job"),
+///     |Edge(_, _, cost)| *cost,
+/// );
+///
+/// assert!(assignment.len() == 3);
+/// assert!(assignment.cost() == 5);
+/// ```
+pub fn hungarian<C, G, W, P, F>(graph: &C, mut left: P, mut weight: F) -> Assignment<G, W>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight,
+    P: FnMut(&G) -> bool,
+    F: FnMut(&G::Edge) -> W,
+{
+    let (lefts, rights): (Vec<_>, Vec<_>) = graph.nodes().partition(|node| left(node));
+    let right = rights
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.key().clone(), i))
+        .collect::<HashMap<_, _>>();
+    let adjacent = lefts
+        .iter()
+        .map(|node| {
+            node.outbound()
+                .filter_map(|edge| {
+                    let &r = right.get(edge.target().key())?;
+                    Some((r, weight(&edge), edge))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let (n, m) = (adjacent.len(), rights.len());
+
+    // Every right node starts with the potential of the cheapest edge, so
+    // that all reduced costs are non-negative. Unassigned nodes on the same
+    // side keep equal potentials, so the nearest unassigned right node is
+    // also the cheapest one to reach. Assigned edges always have a reduced
+    // cost of zero.
+    let mut potential_left = vec![W::default(); n];
+    let cheapest = adjacent
+        .iter()
+        .flatten()
+        .map(|&(_, w, _)| w)
+        .reduce(|a, b| if b < a { b } else { a })
+        .unwrap_or_default();
+    let mut potential_right = vec![cheapest; m];
+
+    // The position in `adjacent` of the edge each left node is assigned
+    // by, and the left node each right node is assigned to.
+    let mut mate_left: Vec<Option<usize>> = vec![None; n];
+    let mut mate_right: Vec<Option<usize>> = vec![None; m];
+
+    loop {
+        // Distances are only kept for right nodes, a left node that is
+        // assigned can only be reached through its mate.
+        let mut distance = vec![None; m];
+        let mut predecessor = vec![(0, 0); m];
+        let mut reached = vec![None; n];
+        let mut queue = BinaryHeap::new();
+        let mut relax = |l: usize,
+                         d: W,
+                         distance: &mut Vec<Option<W>>,
+                         queue: &mut BinaryHeap<MinScored<W, usize>>| {
+            for (i, &(r, w, _)) in adjacent[l].iter().enumerate() {
+                let dr = d + (w + potential_left[l] - potential_right[r]);
+                if distance[r].is_none_or(|old| dr < old) {
+                    distance[r] = Some(dr);
+                    predecessor[r] = (l, i);
+                    queue.push(MinScored(dr, r));
+                }
+            }
+        };
+        for l in 0..n {
+            if mate_left[l].is_none() {
+                reached[l] = Some(W::default());
+                relax(l, W::default(), &mut distance, &mut queue);
+            }
+        }
+
+        let mut target = None;
+        while let Some(MinScored(d, r)) = queue.pop() {
+            if distance[r].is_some_and(|dr| dr < d) {
+                continue;
+            }
+            match mate_right[r] {
+                None => {
+                    target = Some((r, d));
+                    break;
+                }
+                Some(l) if reached[l].is_none() => {
+                    reached[l] = Some(d);
+                    relax(l, d, &mut distance, &mut queue);
+                }
+                Some(_) => {}
+            }
+        }
+        let Some((mut r, length)) = target else {
+            break;
+        };
+
+        // Distances beyond the length of the path are cut off, which keeps
+        // the reduced costs non-negative and the potentials of unassigned
+        // nodes equal.
+        let cut = |d: Option<W>| match d {
+            Some(d) if d < length => d,
+            _ => length,
+        };
+        for (potential, &d) in potential_left.iter_mut().zip(&reached) {
+            *potential = *potential + cut(d);
+        }
+        for (potential, &d) in potential_right.iter_mut().zip(&distance) {
+            *potential = *potential + cut(d);
+        }
+
+        loop {
+            let (l, i) = predecessor[r];
+            let previous = mate_left[l].replace(i);
+            mate_right[r] = Some(l);
+            match previous {
+                Some(i) => r = adjacent[l][i].0,
+                None => break,
+            }
+        }
+    }
+
+    let mut cost = W::default();
+    let mut edges = vec![];
+    for (l, &i) in mate_left.iter().enumerate() {
+        if let Some(i) = i {
+            let (_, w, edge) = &adjacent[l][i];
+            cost = cost + *w;
+            edges.push(edge.clone());
+        }
+    }
+    Assignment { edges, cost }
+}
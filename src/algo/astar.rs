@@ -0,0 +1,112 @@
+use super::{path::*, shortest_paths::*, *};
+use std::collections::BinaryHeap;
+
+pub type CostFn<'a, G, W> = Box<dyn FnMut(&<G as GraphNode>::Edge) -> W + 'a>;
+pub type HeuristicFn<'a, G, W> = Box<dyn FnMut(&G) -> W + 'a>;
+
+/// A goal-directed shortest path search.
+///
+/// The `cost` closure returns the length of an edge, which must not be
+/// negative. The `heuristic` closure estimates the remaining distance from
+/// a node to the target. As long as the estimate never exceeds the actual
+/// distance the path found is a shortest one. A heuristic that always
+/// returns zero turns the search into Dijkstra's algorithm.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// // A 3x3 grid where each node stores its coordinates.
+/// let mut g = Graph::<usize, (i32, i32), u32>::new();
+/// for i in 0..9 {
+///     g.insert(Node::new(i, (i as i32 % 3, i as i32 / 3)));
+/// }
+/// for i in 0..9 {
+///     if i % 3 < 2 {
+///         g[i].connect(&g[i + 1], 1);
+///     }
+///     if i / 3 < 2 {
+///         g[i].connect(&g[i + 3], 1);
+///     }
+/// }
+///
+/// // The Manhattan distance to the bottom right corner.
+/// let manhattan = |node: &Node<usize, (i32, i32), u32>| {
+///     let (x, y) = *node.value();
+///     (2 - x).unsigned_abs() + (2 - y).unsigned_abs()
+/// };
+///
+/// let (path, cost) = g[0]
+///     .astar(|Edge(_, _, e)| *e, manhattan)
+///     .target(&8)
+///     .search_path()
+///     .unwrap();
+///
+/// assert!(cost == 4);
+/// assert!(path.len() == 5);
+/// ```
+pub struct AStar<'a, G, W>
+where
+    G: Neighbors,
+{
+    root: G,
+    target: Option<G::Key>,
+    cost: CostFn<'a, G, W>,
+    heuristic: HeuristicFn<'a, G, W>,
+}
+
+impl<'a, G, W> AStar<'a, G, W>
+where
+    G: Neighbors,
+    W: Weight,
+{
+    pub fn new<F, H>(root: &G, cost: F, heuristic: H) -> Self
+    where
+        F: FnMut(&G::Edge) -> W + 'a,
+        H: FnMut(&G) -> W + 'a,
+    {
+        AStar {
+            root: root.clone(),
+            target: None,
+            cost: Box::new(cost),
+            heuristic: Box::new(heuristic),
+        }
+    }
+
+    pub fn target(mut self, target: &G::Key) -> Self {
+        self.target = Some(target.clone());
+        self
+    }
+
+    /// Returns a shortest path from the root to the target along with its
+    /// length, or `None` if no target is set or it can not be reached.
+    pub fn search_path(&mut self) -> Option<(Path<G>, W)> {
+        let target = self.target.clone()?;
+        let mut paths = ShortestPaths::new(&self.root);
+        let mut queue = BinaryHeap::new();
+
+        let estimate = (self.heuristic)(&self.root);
+        queue.push(MinScored(estimate, (W::default(), self.root.clone())));
+
+        while let Some(MinScored(_, (distance, u))) = queue.pop() {
+            // Nodes are queued again when a shorter path to them is found,
+            // entries with an outdated distance are skipped.
+            if paths.distance_to(u.key()).unwrap() < distance {
+                continue;
+            }
+            if u.key() == &target {
+                return Some((paths.path_to(&target).unwrap(), distance));
+            }
+            for edge in u.outbound() {
+                if paths.relax(&edge, (self.cost)(&edge)) {
+                    let v = edge.target();
+                    let distance = paths.distance_to(v.key()).unwrap();
+                    let estimate = distance + (self.heuristic)(v);
+                    queue.push(MinScored(estimate, (distance, v.clone())));
+                }
+            }
+        }
+        None
+    }
+}
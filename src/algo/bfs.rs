@@ -0,0 +1,170 @@
+use super::{path::*, pipeline::*, *};
+use ahash::AHashSet as HashSet;
+use std::collections::VecDeque;
+
+/// A lazy breadth-first search.
+///
+/// Besides the `search*` methods, `Bfs` is an [`Iterator`] over the edges
+/// of the breadth-first search tree in the order they are discovered. The
+/// traversal only advances when `next()` is called, so it can be paused,
+/// resumed or composed with any iterator adaptor. If a target is set, the
+/// iterator ends after yielding the edge leading to the target.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [3]
+///     (2) => [3]
+///     (3) => []
+/// ];
+///
+/// let mut bfs = g[0].bfs();
+///
+/// let Edge(u, v, _) = bfs.next().unwrap();
+/// assert!(u == g[0] && v == g[1]);
+///
+/// let rest = bfs.map(|Edge(_, v, _)| *v.key()).collect::<Vec<_>>();
+/// assert!(rest == vec![2, 3]);
+/// ```
+pub struct Bfs<'a, G>
+where
+    G: Neighbors,
+{
+    root: G,
+    target: Option<G::Key>,
+    pipeline: Pipeline<'a, G>,
+    transpose: Transposition,
+    queue: VecDeque<G>,
+    adjacent: Option<Adjacent<G>>,
+    visited: HashSet<G::Key>,
+    state: State,
+}
+
+impl<'a, G> Bfs<'a, G>
+where
+    G: Neighbors,
+{
+    pub fn new(root: &G) -> Self {
+        Bfs {
+            root: root.clone(),
+            target: None,
+            pipeline: Pipeline::new(),
+            transpose: Transposition::Outbound,
+            queue: VecDeque::new(),
+            adjacent: None,
+            visited: HashSet::default(),
+            state: State::Ready,
+        }
+    }
+
+    pub fn target(mut self, target: &G::Key) -> Self {
+        self.target = Some(target.clone());
+        self
+    }
+
+    pub fn transpose(mut self) -> Self {
+        self.transpose = Transposition::Inbound;
+        self
+    }
+
+    pub fn for_each(mut self, f: ForEach<'a, G>) -> Self {
+        self.pipeline.for_each(f);
+        self
+    }
+
+    pub fn filter(mut self, f: Filter<'a, G>) -> Self {
+        self.pipeline.filter(f);
+        self
+    }
+
+    pub fn inspect(mut self, f: Inspect<'a, G>) -> Self {
+        self.pipeline.inspect(f);
+        self
+    }
+
+    pub fn search(&mut self) -> Option<G> {
+        self.restart(false);
+        let target = self.target.clone();
+        find_target(self.by_ref(), target.as_ref())
+    }
+
+    pub fn search_cycle(&mut self) -> Option<Path<G>> {
+        self.target = Some(self.root.key().clone());
+        self.restart(true);
+        let target = self.target.clone();
+        find_path(self.by_ref(), target.as_ref())
+    }
+
+    pub fn search_path(&mut self) -> Option<Path<G>> {
+        self.restart(false);
+        let target = self.target.clone();
+        find_path(self.by_ref(), target.as_ref())
+    }
+
+    // Resets the traversal to start from the root. When searching for a
+    // cycle the root is not marked as visited so it can be found again.
+    fn restart(&mut self, cycle: bool) {
+        self.queue.clear();
+        self.visited.clear();
+        self.adjacent = None;
+        self.queue.push_back(self.root.clone());
+        if !cycle {
+            self.visited.insert(self.root.key().clone());
+        }
+        self.state = State::Running;
+    }
+}
+
+impl<'a, G> Iterator for Bfs<'a, G>
+where
+    G: Neighbors,
+{
+    type Item = G::Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.state {
+            State::Ready => self.restart(false),
+            State::Running => {}
+            State::Done => return None,
+        }
+        loop {
+            let adjacent = match self.adjacent {
+                Some(ref mut adjacent) => adjacent,
+                None => match self.queue.pop_front() {
+                    Some(node) => self.adjacent.insert(self.transpose.adjacent(&node)),
+                    None => {
+                        self.state = State::Done;
+                        return None;
+                    }
+                },
+            };
+            match adjacent.next() {
+                Some(edge) => match self.pipeline.exec(&edge) {
+                    Control::Continue => {
+                        let v = edge.target();
+                        if !self.visited.contains(v.key()) {
+                            self.visited.insert(v.key().clone());
+                            if self.target.as_ref() == Some(v.key()) {
+                                self.state = State::Done;
+                            } else {
+                                self.queue.push_back(v.clone());
+                            }
+                            return Some(edge);
+                        }
+                    }
+                    Control::Prune => {}
+                    Control::Stop => {
+                        self.state = State::Done;
+                        return None;
+                    }
+                },
+                None => self.adjacent = None,
+            }
+        }
+    }
+}
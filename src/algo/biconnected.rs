@@ -0,0 +1,219 @@
+use super::{dfs::*, *};
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+// Computes the articulation points, bridges and biconnected components of
+// an undirected graph from the lowpoints of a depth-first traversal. The
+// lowpoint of a node is the earliest discovery time reachable from its
+// subtree through at most one back edge. A tree edge `(p, u)` separates
+// the subtree of `u` from the rest of the graph if the lowpoint of `u` is
+// not earlier than `p`.
+struct Lowpoints<G>
+where
+    G: GraphNode,
+{
+    discovered: HashMap<G::Key, usize>,
+    low: HashMap<G::Key, usize>,
+    // The tree edge leading to each node, along with the length of the edge
+    // stack before it was pushed.
+    parent: HashMap<G::Key, (G::Edge, usize)>,
+    children: HashMap<G::Key, usize>,
+    stack: Vec<G::Edge>,
+    articulation_points: Vec<G>,
+    is_articulation_point: HashSet<G::Key>,
+    bridges: Vec<G::Edge>,
+    components: Vec<Vec<G::Edge>>,
+}
+
+impl<G> Lowpoints<G>
+where
+    G: Neighbors,
+{
+    fn new<C>(graph: &C) -> Self
+    where
+        C: GraphContainer<Node = G>,
+    {
+        let mut lowpoints = Lowpoints {
+            discovered: HashMap::default(),
+            low: HashMap::default(),
+            parent: HashMap::default(),
+            children: HashMap::default(),
+            stack: vec![],
+            articulation_points: vec![],
+            is_articulation_point: HashSet::default(),
+            bridges: vec![],
+            components: vec![],
+        };
+        for root in graph.nodes() {
+            if !lowpoints.discovered.contains_key(root.key()) {
+                Dfs::new(root)
+                    .filter(&mut |edge| graph.contains(edge.target().key()))
+                    .visit(&mut lowpoints);
+                if lowpoints.children.get(root.key()).is_some_and(|&n| n > 1) {
+                    lowpoints.articulation_point(root);
+                }
+            }
+        }
+        lowpoints
+    }
+
+    fn articulation_point(&mut self, node: &G) {
+        if self.is_articulation_point.insert(node.key().clone()) {
+            self.articulation_points.push(node.clone());
+        }
+    }
+}
+
+impl<G> DfsVisitor<G> for Lowpoints<G>
+where
+    G: Neighbors,
+{
+    fn discover_node(&mut self, node: &G, time: usize) {
+        self.discovered.insert(node.key().clone(), time);
+        self.low.insert(node.key().clone(), time);
+    }
+
+    fn tree_edge(&mut self, edge: &G::Edge) {
+        let (u, v) = (edge.source(), edge.target());
+        if !self.parent.contains_key(u.key()) {
+            *self.children.entry(u.key().clone()).or_default() += 1;
+        }
+        self.parent
+            .insert(v.key().clone(), (edge.clone(), self.stack.len()));
+        self.stack.push(edge.clone());
+    }
+
+    fn back_edge(&mut self, edge: &G::Edge) {
+        let (u, v) = (edge.source(), edge.target());
+        // A loop neither connects nor separates anything.
+        if u.key() == v.key() {
+            return;
+        }
+        let time = self.discovered[v.key()];
+        let low = self.low.get_mut(u.key()).unwrap();
+        *low = (*low).min(time);
+        self.stack.push(edge.clone());
+    }
+
+    fn finish_node(&mut self, node: &G, _time: usize) {
+        let Some((edge, position)) = self.parent.get(node.key()).cloned() else {
+            return;
+        };
+        let p = edge.source();
+        let low = self.low[node.key()];
+        let parent_low = self.low.get_mut(p.key()).unwrap();
+        *parent_low = (*parent_low).min(low);
+
+        let time = self.discovered[p.key()];
+        if low >= time {
+            if self.parent.contains_key(p.key()) {
+                self.articulation_point(p);
+            }
+            self.components.push(self.stack.split_off(position));
+        }
+        if low > time {
+            self.bridges.push(edge);
+        }
+    }
+}
+
+/// Returns the articulation points of the undirected `graph`: the nodes
+/// whose removal disconnects some of their neighbors from each other. Edges
+/// to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [2]
+///     (2) => [3]
+///     (3) => [4]
+///     (4) => []
+/// ];
+///
+/// let mut keys = gdsl::algo::articulation_points(&g)
+///     .iter()
+///     .map(|node| *node.key())
+///     .collect::<Vec<_>>();
+/// keys.sort();
+///
+/// assert!(keys == vec![2, 3]);
+/// ```
+pub fn articulation_points<C, G>(graph: &C) -> Vec<G>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    Lowpoints::new(graph).articulation_points
+}
+
+/// Returns the bridges of the undirected `graph`: the edges whose removal
+/// disconnects their endpoints. Every bridge is returned once, oriented in
+/// either direction. Edges to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [2]
+///     (2) => [3]
+///     (3) => []
+/// ];
+///
+/// let bridges = gdsl::algo::bridges(&g);
+///
+/// assert!(bridges.len() == 1);
+/// let Edge(u, v, _) = &bridges[0];
+/// assert!(u.key() + v.key() == 5);
+/// ```
+pub fn bridges<C, G>(graph: &C) -> Vec<G::Edge>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    Lowpoints::new(graph).bridges
+}
+
+/// Splits the edges of the undirected `graph` into biconnected components:
+/// maximal groups of edges in which any two edges lie on a common simple
+/// cycle. A bridge forms a component on its own, and components only share
+/// articulation points. Loops and edges to nodes outside of the graph are
+/// ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [2]
+///     (2) => [3, 4]
+///     (3) => [4]
+///     (4) => [5]
+///     (5) => []
+/// ];
+///
+/// let mut sizes = gdsl::algo::biconnected_components(&g)
+///     .iter()
+///     .map(|edges| edges.len())
+///     .collect::<Vec<_>>();
+/// sizes.sort();
+///
+/// assert!(sizes == vec![1, 3, 3]);
+/// ```
+pub fn biconnected_components<C, G>(graph: &C) -> Vec<Vec<G::Edge>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    Lowpoints::new(graph).components
+}
@@ -0,0 +1,336 @@
+use super::{path::*, *};
+use ahash::AHashMap as HashMap;
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+/// A two-colouring of the nodes of a graph, such that every edge connects
+/// nodes of different colours. Nodes of colour `0` are on the left and
+/// nodes of colour `1` on the right.
+pub struct Bipartition<G>
+where
+    G: GraphNode,
+{
+    left: Vec<G>,
+    right: Vec<G>,
+    colours: HashMap<G::Key, usize>,
+}
+
+impl<G> Bipartition<G>
+where
+    G: GraphNode,
+{
+    /// Returns the colour of the node with the key `key`, which is either
+    /// `0` or `1`, or `None` if the node is not part of the graph.
+    pub fn colour(&self, key: &G::Key) -> Option<usize> {
+        self.colours.get(key).copied()
+    }
+
+    /// Returns the nodes of colour `0`.
+    pub fn left(&self) -> &[G] {
+        &self.left
+    }
+
+    /// Returns the nodes of colour `1`.
+    pub fn right(&self) -> &[G] {
+        &self.right
+    }
+}
+
+impl<G> Debug for Bipartition<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keys = |side: &[G]| side.iter().map(|n| n.key().to_string()).collect::<Vec<_>>();
+        f.debug_struct("Bipartition")
+            .field("left", &keys(&self.left))
+            .field("right", &keys(&self.right))
+            .finish()
+    }
+}
+
+/// A cycle of odd length, which proves that a graph is not bipartite.
+pub struct OddCycle<G>
+where
+    G: GraphNode,
+{
+    cycle: Path<G>,
+}
+
+impl<G> OddCycle<G>
+where
+    G: GraphNode,
+{
+    /// Returns the cycle. The path starts and ends at the same node.
+    pub fn cycle(&self) -> &Path<G> {
+        &self.cycle
+    }
+
+    /// Consumes the error and returns the cycle.
+    pub fn into_cycle(self) -> Path<G> {
+        self.cycle
+    }
+}
+
+impl<G> Display for OddCycle<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Odd cycle: {}", self.cycle)
+    }
+}
+
+impl<G> Debug for OddCycle<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<G> std::error::Error for OddCycle<G> where G: GraphNode {}
+
+/// Colours the nodes of the undirected `graph` with two colours so that
+/// every edge connects nodes of different colours. Each connected component
+/// is coloured breadth-first, so within a component the colour of a node is
+/// the parity of its distance to the first node of the component. If no
+/// such colouring exists, a cycle of odd length is returned instead. Edges
+/// to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1, 3]
+///     (1) => [2]
+///     (2) => [3]
+///     (3) => []
+/// ];
+///
+/// let bipartition = gdsl::algo::bipartition(&g).unwrap();
+/// assert!(bipartition.colour(&0) == bipartition.colour(&2));
+/// assert!(bipartition.colour(&0) != bipartition.colour(&1));
+///
+/// g[0].connect(&g[2], ());
+///
+/// let cycle = gdsl::algo::bipartition(&g).unwrap_err().into_cycle();
+/// assert!(cycle.len() == 4);
+/// ```
+pub fn bipartition<C, G>(graph: &C) -> Result<Bipartition<G>, OddCycle<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let mut colours = HashMap::default();
+    // The edge through which each node was reached, from its parent.
+    let mut parent = HashMap::<G::Key, G::Edge>::default();
+    let mut queue = VecDeque::new();
+
+    for root in graph.nodes() {
+        if colours.contains_key(root.key()) {
+            continue;
+        }
+        colours.insert(root.key().clone(), 0);
+        queue.push_back(root.clone());
+
+        while let Some(u) = queue.pop_front() {
+            let colour = colours[u.key()];
+            for edge in u.outbound() {
+                let v = edge.target();
+                if !graph.contains(v.key()) {
+                    continue;
+                }
+                match colours.get(v.key()) {
+                    None => {
+                        colours.insert(v.key().clone(), 1 - colour);
+                        parent.insert(v.key().clone(), edge.clone());
+                        queue.push_back(v.clone());
+                    }
+                    Some(&c) if c == colour => {
+                        return Err(OddCycle {
+                            cycle: odd_cycle(&parent, edge),
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    let (left, right) = graph
+        .nodes()
+        .cloned()
+        .partition(|node| colours[node.key()] == 0);
+    Ok(Bipartition {
+        left,
+        right,
+        colours,
+    })
+}
+
+// Builds the odd cycle closed by an edge between two nodes of the same
+// colour. Both ends have the same distance to the root of the breadth-first
+// tree, so walking up from both in lockstep meets at their lowest common
+// ancestor.
+fn odd_cycle<G>(parent: &HashMap<G::Key, G::Edge>, edge: G::Edge) -> Path<G>
+where
+    G: GraphNode,
+{
+    let (mut u, mut v) = (edge.source().clone(), edge.target().clone());
+    let mut down = vec![];
+    let mut up = vec![];
+    while u.key() != v.key() {
+        let (pu, pv) = (&parent[u.key()], &parent[v.key()]);
+        u = pu.source().clone();
+        v = pv.source().clone();
+        down.push(pu.clone());
+        up.push(pv.reverse());
+    }
+    down.reverse();
+    down.push(edge);
+    down.extend(up);
+    Path { edges: down }
+}
+
+/// Finds a maximum cardinality matching of the bipartite, undirected
+/// `graph` with the Hopcroft-Karp algorithm: a largest set of edges of
+/// which no two share a node. Each phase finds a maximal set of shortest
+/// augmenting paths, and at most about the square root of the number of
+/// nodes phases are needed.
+///
+/// The edges are oriented from the left to the right side of the
+/// [`bipartition`] of the graph. Fails with an odd cycle if the graph is
+/// not bipartite. Edges to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [3, 4]
+///     (1) => [3]
+///     (2) => [3, 5]
+///     (3) => []
+///     (4) => []
+///     (5) => []
+/// ];
+///
+/// let matching = gdsl::algo::hopcroft_karp(&g).unwrap();
+///
+/// assert!(matching.len() == 3);
+/// ```
+pub fn hopcroft_karp<C, G>(graph: &C) -> Result<Vec<G::Edge>, OddCycle<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let bipartition = bipartition(graph)?;
+    let right = bipartition
+        .right
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.key().clone(), i))
+        .collect::<HashMap<_, _>>();
+    let adjacent = bipartition
+        .left
+        .iter()
+        .map(|node| {
+            node.outbound()
+                .filter_map(|edge| right.get(edge.target().key()).map(|&r| (r, edge)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let (n, m) = (adjacent.len(), right.len());
+    // The position in `adjacent` of the edge each left node is matched by,
+    // and the left node each right node is matched to.
+    let mut mate_left = vec![None; n];
+    let mut mate_right = vec![None; m];
+    let mut distance = vec![usize::MAX; n];
+
+    loop {
+        // Layer the left nodes by the length of the shortest alternating
+        // path from an unmatched left node.
+        let mut queue = VecDeque::new();
+        for l in 0..n {
+            distance[l] = match mate_left[l] {
+                None => {
+                    queue.push_back(l);
+                    0
+                }
+                Some(_) => usize::MAX,
+            };
+        }
+        let mut shortest = usize::MAX;
+        while let Some(l) = queue.pop_front() {
+            if distance[l] >= shortest {
+                continue;
+            }
+            for &(r, _) in &adjacent[l] {
+                match mate_right[r] {
+                    None => shortest = shortest.min(distance[l] + 1),
+                    Some(next) if distance[next] == usize::MAX => {
+                        distance[next] = distance[l] + 1;
+                        queue.push_back(next);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if shortest == usize::MAX {
+            break;
+        }
+
+        // Augment along vertex-disjoint shortest alternating paths, found
+        // with a depth-first search that only follows the layers. Each
+        // left node remembers the next edge to try.
+        let mut current = vec![0; n];
+        for root in 0..n {
+            if mate_left[root].is_some() {
+                continue;
+            }
+            let mut stack = vec![root];
+            let mut via = vec![];
+            while let Some(&l) = stack.last() {
+                let Some(&(r, _)) = adjacent[l].get(current[l]) else {
+                    // A dead end, remove it from the layers.
+                    distance[l] = usize::MAX;
+                    stack.pop();
+                    via.pop();
+                    continue;
+                };
+                current[l] += 1;
+                match mate_right[r] {
+                    None if distance[l] + 1 == shortest => {
+                        via.push(current[l] - 1);
+                        for (&l, &i) in stack.iter().zip(&via) {
+                            mate_left[l] = Some(i);
+                            mate_right[adjacent[l][i].0] = Some(l);
+                        }
+                        break;
+                    }
+                    Some(next) if distance[next] == distance[l] + 1 => {
+                        via.push(current[l] - 1);
+                        stack.push(next);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    Ok(mate_left
+        .iter()
+        .enumerate()
+        .filter_map(|(l, &i)| i.map(|i| adjacent[l][i].1.clone()))
+        .collect())
+}
@@ -0,0 +1,668 @@
+use super::{random::Random, *};
+use ahash::AHashMap as HashMap;
+use std::{
+    collections::{BinaryHeap, VecDeque},
+    fmt::{Debug, Display},
+};
+
+// Lists the edges leaving every node as pairs of the other end and the
+// position of the edge in `edges`. Undirected edges are listed at both
+// ends.
+fn adjacency<E>(n: usize, edges: &[(usize, usize, E)], directed: bool) -> Vec<Vec<(usize, usize)>> {
+    let mut adjacent = vec![vec![]; n];
+    for (e, &(u, v, _)) in edges.iter().enumerate() {
+        adjacent[u].push((v, e));
+        if !directed {
+            adjacent[v].push((u, e));
+        }
+    }
+    adjacent
+}
+
+/// The betweenness centrality of the nodes and edges of a graph, as
+/// computed by [`Brandes`].
+pub struct Betweenness<G>
+where
+    G: GraphNode,
+{
+    nodes: HashMap<G::Key, f64>,
+    edges: Vec<(G::Edge, f64)>,
+    directed: bool,
+}
+
+impl<G> Betweenness<G>
+where
+    G: GraphNode,
+{
+    /// Returns the betweenness of the node with the key `key`, or `None`
+    /// if there is no such node.
+    pub fn node(&self, key: &G::Key) -> Option<f64> {
+        self.nodes.get(key).copied()
+    }
+
+    /// Returns the betweenness of every node by its key.
+    pub fn nodes(&self) -> &HashMap<G::Key, f64> {
+        &self.nodes
+    }
+
+    /// Returns the total betweenness of the edges from the node with the
+    /// key `from` to the node with the key `to`, in either direction in an
+    /// undirected graph, or `None` if there is no such edge.
+    pub fn edge(&self, from: &G::Key, to: &G::Key) -> Option<f64> {
+        self.edges
+            .iter()
+            .filter(|(edge, _)| {
+                let (u, v) = (edge.source().key(), edge.target().key());
+                (u == from && v == to) || (!self.directed && u == to && v == from)
+            })
+            .map(|&(_, score)| score)
+            .reduce(|a, b| a + b)
+    }
+
+    /// Returns every edge along with its betweenness. Each undirected edge
+    /// is listed once, in either orientation.
+    pub fn edges(&self) -> &[(G::Edge, f64)] {
+        &self.edges
+    }
+}
+
+impl<G> Debug for Betweenness<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|(key, score)| (key.to_string(), score))
+            .collect::<Vec<_>>();
+        let edges = self
+            .edges
+            .iter()
+            .map(|(edge, score)| {
+                let (u, v) = (edge.source().key(), edge.target().key());
+                (format!("{} -> {}", u, v), score)
+            })
+            .collect::<Vec<_>>();
+        f.debug_struct("Betweenness")
+            .field("nodes", &nodes)
+            .field("edges", &edges)
+            .finish()
+    }
+}
+
+/// Computes betweenness centrality with Brandes' algorithm. The betweenness
+/// of a node is the number of shortest paths between other nodes that pass
+/// through it, where pairs connected by several shortest paths count each
+/// path fractionally. The betweenness of an edge is defined likewise over
+/// all pairs of nodes. Both are found in `O(nm)` time for unweighted
+/// graphs and `O(nm + n² log n)` for weighted ones.
+///
+/// Undirected graphs count each pair of nodes once. Scores can be
+/// normalized by the number of pairs, and approximated by only starting
+/// the shortest paths from a random sample of nodes, scaling the result
+/// up accordingly. Loops and edges to nodes outside of the graph are
+/// ignored, parallel edges count as distinct paths.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// // Two groups of services bridged by 'C' and 'D'.
+/// let g = gdsl::ungraph![
+///     (char)
+///     ('A') => ['B', 'C']
+///     ('B') => ['C']
+///     ('C') => ['D']
+///     ('D') => ['E', 'F']
+///     ('E') => ['F']
+///     ('F') => []
+/// ];
+///
+/// let betweenness = gdsl::algo::Brandes::new(&g).unweighted();
+///
+/// assert!(betweenness.node(&'A') == Some(0.0));
+/// assert!(betweenness.node(&'C') == Some(6.0));
+/// assert!(betweenness.edge(&'D', &'C') == Some(9.0));
+/// ```
+pub struct Brandes<G>
+where
+    G: GraphNode,
+{
+    nodes: Vec<G>,
+    edges: Vec<(usize, usize, G::Edge)>,
+    normalized: bool,
+    sample: Option<(usize, u64)>,
+}
+
+impl<G> Brandes<G>
+where
+    G: Neighbors,
+{
+    /// Prepares the computation over the nodes and edges of `graph`.
+    pub fn new<C>(graph: &C) -> Self
+    where
+        C: GraphContainer<Node = G>,
+    {
+        let (nodes, edges) = edge_list(graph);
+        Brandes {
+            nodes,
+            edges,
+            normalized: false,
+            sample: None,
+        }
+    }
+
+    /// Divides the node scores by the number of pairs of other nodes, and
+    /// the edge scores by the number of pairs of nodes, which puts them
+    /// between 0 and 1.
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
+    }
+
+    /// Only starts shortest paths from `sources` nodes, chosen at random
+    /// from the `seed`, and scales the scores up to estimate the exact
+    /// ones. Has no effect if there are no more nodes than `sources`.
+    pub fn sample(mut self, sources: usize, seed: u64) -> Self {
+        self.sample = Some((sources, seed));
+        self
+    }
+
+    /// Computes the betweenness, taking every edge to be of length one.
+    pub fn unweighted(&self) -> Betweenness<G> {
+        let adjacent = adjacency(self.nodes.len(), &self.edges, G::DIRECTED);
+        self.accumulate(|s, order, predecessors, sigma| {
+            let mut distance = vec![usize::MAX; adjacent.len()];
+            let mut queue = VecDeque::from([s]);
+            distance[s] = 0;
+            sigma[s] = 1.0;
+            while let Some(v) = queue.pop_front() {
+                order.push(v);
+                for &(w, e) in &adjacent[v] {
+                    if distance[w] == usize::MAX {
+                        distance[w] = distance[v] + 1;
+                        queue.push_back(w);
+                    }
+                    if distance[w] == distance[v] + 1 {
+                        sigma[w] += sigma[v];
+                        predecessors[w].push((v, e));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Computes the betweenness, using `weight` as the length of each edge.
+    /// Lengths must be positive.
+    pub fn weighted<W, F>(&self, weight: F) -> Betweenness<G>
+    where
+        W: Weight,
+        F: FnMut(&G::Edge) -> W,
+    {
+        let adjacent = adjacency(self.nodes.len(), &self.edges, G::DIRECTED);
+        let weights = self
+            .edges
+            .iter()
+            .map(|(_, _, edge)| edge)
+            .map(weight)
+            .collect::<Vec<_>>();
+        self.accumulate(|s, order, predecessors, sigma| {
+            let mut distance = vec![None; adjacent.len()];
+            let mut done = vec![false; adjacent.len()];
+            let mut queue = BinaryHeap::from([MinScored(W::default(), s)]);
+            distance[s] = Some(W::default());
+            sigma[s] = 1.0;
+            while let Some(MinScored(d, v)) = queue.pop() {
+                if done[v] {
+                    continue;
+                }
+                done[v] = true;
+                order.push(v);
+                for &(w, e) in &adjacent[v] {
+                    if done[w] {
+                        continue;
+                    }
+                    let dw = d + weights[e];
+                    match distance[w] {
+                        Some(old) if dw == old => {
+                            sigma[w] += sigma[v];
+                            predecessors[w].push((v, e));
+                        }
+                        Some(old) if dw > old => {}
+                        _ => {
+                            distance[w] = Some(dw);
+                            sigma[w] = sigma[v];
+                            predecessors[w].clear();
+                            predecessors[w].push((v, e));
+                            queue.push(MinScored(dw, w));
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    // Runs `paths` from every source to find the nodes in the order of
+    // their distance, the number of shortest paths to each and the last
+    // edges on them, then credits every node and edge with the shortest
+    // paths passing through it, from the farthest node back.
+    fn accumulate<P>(&self, mut paths: P) -> Betweenness<G>
+    where
+        P: FnMut(usize, &mut Vec<usize>, &mut [Vec<(usize, usize)>], &mut [f64]),
+    {
+        let n = self.nodes.len();
+        let mut sources = (0..n).collect::<Vec<_>>();
+        if let Some((k, seed)) = self.sample {
+            if k < n {
+                Random::new(seed).shuffle(&mut sources);
+                sources.truncate(k);
+            }
+        }
+
+        let mut node_scores = vec![0.0; n];
+        let mut edge_scores = vec![0.0; self.edges.len()];
+        let mut order = vec![];
+        let mut predecessors = vec![vec![]; n];
+        let mut sigma = vec![0.0; n];
+        let mut delta = vec![0.0; n];
+        for &s in &sources {
+            order.clear();
+            predecessors.iter_mut().for_each(Vec::clear);
+            sigma.fill(0.0);
+            delta.fill(0.0);
+            paths(s, &mut order, &mut predecessors, &mut sigma);
+            while let Some(w) = order.pop() {
+                for &(v, e) in &predecessors[w] {
+                    let credit = sigma[v] / sigma[w] * (1.0 + delta[w]);
+                    edge_scores[e] += credit;
+                    delta[v] += credit;
+                }
+                if w != s {
+                    node_scores[w] += delta[w];
+                }
+            }
+        }
+
+        let nf = n as f64;
+        let mut node_scale = nf / sources.len().max(1) as f64;
+        let mut edge_scale = node_scale;
+        if !G::DIRECTED {
+            node_scale /= 2.0;
+            edge_scale /= 2.0;
+        }
+        if self.normalized {
+            let pairs = if G::DIRECTED { 1.0 } else { 0.5 };
+            if n > 2 {
+                node_scale /= pairs * (nf - 1.0) * (nf - 2.0);
+            }
+            if n > 1 {
+                edge_scale /= pairs * nf * (nf - 1.0);
+            }
+        }
+
+        Betweenness {
+            nodes: self
+                .nodes
+                .iter()
+                .zip(node_scores)
+                .map(|(node, score)| (node.key().clone(), score * node_scale))
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .zip(edge_scores)
+                .map(|((_, _, edge), score)| (edge.clone(), score * edge_scale))
+                .collect(),
+            directed: G::DIRECTED,
+        }
+    }
+}
+
+// Computes a score for every node from the numbers of edges on the
+// shortest paths from it to the nodes it reaches.
+fn hop_centrality<C, G, S>(graph: &C, mut score: S) -> HashMap<G::Key, f64>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    S: FnMut(&[usize]) -> f64,
+{
+    let (nodes, edges) = edge_list(graph);
+    let adjacent = adjacency(nodes.len(), &edges, G::DIRECTED);
+    let mut distances = vec![];
+    let mut queue = VecDeque::new();
+    let mut seen = vec![false; nodes.len()];
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(s, node)| {
+            distances.clear();
+            seen.fill(false);
+            seen[s] = true;
+            queue.push_back((s, 0));
+            while let Some((v, d)) = queue.pop_front() {
+                if d > 0 {
+                    distances.push(d);
+                }
+                for &(w, _) in &adjacent[v] {
+                    if !seen[w] {
+                        seen[w] = true;
+                        queue.push_back((w, d + 1));
+                    }
+                }
+            }
+            (node.key().clone(), score(&distances))
+        })
+        .collect()
+}
+
+/// Computes the closeness centrality of every node: the inverse of the
+/// average number of edges on the shortest paths from the node to the
+/// nodes it reaches. So that nodes reaching only a few others do not score
+/// high, the value is scaled by the fraction of the other nodes reached
+/// (Wasserman and Faust). A node reaching no other node scores zero. In a
+/// directed graph the paths follow outbound edges.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (char)
+///     ('A') => ['B']
+///     ('B') => ['C']
+///     ('C') => []
+///     ('D') => []
+/// ];
+///
+/// let closeness = gdsl::algo::closeness_centrality(&g);
+///
+/// assert!(closeness[&'B'] == 2.0 / 3.0);
+/// assert!(closeness[&'A'] == 4.0 / 9.0);
+/// assert!(closeness[&'D'] == 0.0);
+/// ```
+pub fn closeness_centrality<C, G>(graph: &C) -> HashMap<G::Key, f64>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let others = graph.len().saturating_sub(1) as f64;
+    hop_centrality(graph, |distances| {
+        let total = distances.iter().sum::<usize>();
+        match total {
+            0 => 0.0,
+            _ => {
+                let reached = distances.len() as f64;
+                reached * reached / (total as f64 * others)
+            }
+        }
+    })
+}
+
+/// Computes the harmonic centrality of every node: the sum of the inverse
+/// numbers of edges on the shortest paths from the node to every other
+/// node, where nodes that are not reached add nothing. Unlike closeness, it
+/// is well-defined for graphs that are not connected. In a directed graph
+/// the paths follow outbound edges.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (char)
+///     ('A') => ['B']
+///     ('B') => ['C']
+///     ('C') => []
+/// ];
+///
+/// let harmonic = gdsl::algo::harmonic_centrality(&g);
+///
+/// assert!(harmonic[&'A'] == 1.5);
+/// assert!(harmonic[&'B'] == 1.0);
+/// assert!(harmonic[&'C'] == 0.0);
+/// ```
+pub fn harmonic_centrality<C, G>(graph: &C) -> HashMap<G::Key, f64>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    hop_centrality(graph, |distances| {
+        distances.iter().map(|&d| 1.0 / d as f64).sum()
+    })
+}
+
+/// The error of a power iteration that did not converge within the allowed
+/// number of iterations, along with the scores it ended up with.
+pub struct NotConverged<G>
+where
+    G: GraphNode,
+{
+    scores: HashMap<G::Key, f64>,
+    iterations: usize,
+}
+
+impl<G> NotConverged<G>
+where
+    G: GraphNode,
+{
+    /// Returns the number of iterations that were run.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns the scores after the last iteration.
+    pub fn scores(&self) -> &HashMap<G::Key, f64> {
+        &self.scores
+    }
+
+    /// Consumes the error and returns the scores after the last iteration.
+    pub fn into_scores(self) -> HashMap<G::Key, f64> {
+        self.scores
+    }
+}
+
+impl<G> Display for NotConverged<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No convergence after {} iterations", self.iterations)
+    }
+}
+
+impl<G> Debug for NotConverged<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<G> std::error::Error for NotConverged<G> where G: GraphNode {}
+
+// Scales the scores to a Euclidean norm of one, unless they are all zero.
+fn normalize_euclidean(scores: &mut [f64]) {
+    let norm = scores.iter().map(|score| score * score).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        scores.iter_mut().for_each(|score| *score /= norm);
+    }
+}
+
+// Repeatedly maps the scores to the sum of the weighted scores of the
+// nodes linking to each node, passed through `step` along with the old
+// score, until they change by less than `tolerance` in total. Undirected
+// edges link both ways.
+fn power_iteration<C, G, F, S>(
+    graph: &C,
+    mut weight: F,
+    initial: f64,
+    mut step: S,
+    tolerance: f64,
+    max_iter: usize,
+) -> Result<HashMap<G::Key, f64>, NotConverged<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    F: FnMut(&G::Edge) -> f64,
+    S: FnMut(&mut [f64], &[f64]),
+{
+    let (nodes, edges) = edge_list(graph);
+    let links = edges
+        .iter()
+        .map(|(u, v, edge)| (*u, *v, weight(edge)))
+        .collect::<Vec<_>>();
+    let mut scores = vec![initial; nodes.len()];
+    let mut converged = false;
+    let mut iterations = 0;
+    while iterations < max_iter && !converged {
+        let mut next = vec![0.0; nodes.len()];
+        for &(u, v, w) in &links {
+            next[v] += w * scores[u];
+            if !G::DIRECTED {
+                next[u] += w * scores[v];
+            }
+        }
+        step(&mut next, &scores);
+        converged = scores
+            .iter()
+            .zip(&next)
+            .map(|(x, y)| (x - y).abs())
+            .sum::<f64>()
+            < tolerance;
+        scores = next;
+        iterations += 1;
+    }
+    normalize_euclidean(&mut scores);
+    let scores = nodes
+        .iter()
+        .map(|node| node.key().clone())
+        .zip(scores)
+        .collect();
+    match converged || nodes.is_empty() {
+        true => Ok(scores),
+        false => Err(NotConverged { scores, iterations }),
+    }
+}
+
+/// Computes the eigenvector centrality of every node: the score of a node
+/// is proportional to the sum of the scores of the nodes linking to it,
+/// each multiplied by the `weight` of the edge. This is the eigenvector of
+/// the largest eigenvalue of the weighted adjacency matrix, transposed for
+/// a directed graph so that inbound edges count. Weights should be
+/// positive.
+///
+/// The scores start out equal and are repeatedly replaced by the sum of
+/// the old score and those linking in, which converges to the same vector
+/// but avoids oscillating on bipartite graphs. Iterates until the scores
+/// change by less than `tolerance` in total, and fails if this does not
+/// happen within `max_iter` iterations. The scores have a Euclidean norm
+/// of one. Edges to nodes outside of the graph and loops are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// // A star around 'A'.
+/// let g = gdsl::ungraph![
+///     (char)
+///     ('A') => ['B', 'C', 'D', 'E']
+///     ('B') => []
+///     ('C') => []
+///     ('D') => []
+///     ('E') => []
+/// ];
+///
+/// let scores = gdsl::algo::eigenvector_centrality(&g, |_| 1.0, 1e-12, 1000).unwrap();
+///
+/// assert!((scores[&'A'] - 0.5f64.sqrt()).abs() < 1e-6);
+/// assert!((scores[&'B'] - 0.125f64.sqrt()).abs() < 1e-6);
+///
+/// let error = gdsl::algo::eigenvector_centrality(&g, |_| 1.0, 1e-12, 3).unwrap_err();
+///
+/// assert!(error.iterations() == 3);
+/// ```
+pub fn eigenvector_centrality<C, G, F>(
+    graph: &C,
+    weight: F,
+    tolerance: f64,
+    max_iter: usize,
+) -> Result<HashMap<G::Key, f64>, NotConverged<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    F: FnMut(&G::Edge) -> f64,
+{
+    let initial = 1.0 / (graph.len().max(1) as f64).sqrt();
+    power_iteration(
+        graph,
+        weight,
+        initial,
+        |next, scores| {
+            next.iter_mut().zip(scores).for_each(|(x, y)| *x += y);
+            normalize_euclidean(next);
+        },
+        tolerance,
+        max_iter,
+    )
+}
+
+/// Computes the Katz centrality of every node: the score of a node is
+/// `beta` plus `alpha` times the sum of the scores of the nodes linking to
+/// it, each multiplied by the `weight` of the edge. This counts the walks
+/// ending at the node, with walks of length `k` discounted by `alpha` to
+/// the power of `k`. The iteration only converges if `alpha` is smaller
+/// than the inverse of the largest eigenvalue of the weighted adjacency
+/// matrix. In a directed graph inbound edges count.
+///
+/// The scores start out as zero and are updated until they change by less
+/// than `tolerance` in total, and the computation fails if this does not
+/// happen within `max_iter` iterations. The scores are then scaled to a
+/// Euclidean norm of one. Edges to nodes outside of the graph and loops
+/// are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (char)
+///     ('A') => ['C']
+///     ('B') => ['C']
+///     ('C') => ['D']
+///     ('D') => []
+/// ];
+///
+/// let scores = gdsl::algo::katz_centrality(&g, 0.5, 1.0, |_| 1.0, 1e-12, 100).unwrap();
+///
+/// assert!(scores[&'A'] == scores[&'B']);
+/// assert!(scores[&'C'] > scores[&'A'] && scores[&'D'] == scores[&'C']);
+/// ```
+pub fn katz_centrality<C, G, F>(
+    graph: &C,
+    alpha: f64,
+    beta: f64,
+    weight: F,
+    tolerance: f64,
+    max_iter: usize,
+) -> Result<HashMap<G::Key, f64>, NotConverged<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    F: FnMut(&G::Edge) -> f64,
+{
+    power_iteration(
+        graph,
+        weight,
+        0.0,
+        |next, _| next.iter_mut().for_each(|x| *x = alpha * *x + beta),
+        tolerance,
+        max_iter,
+    )
+}
@@ -0,0 +1,403 @@
+use super::*;
+use ahash::AHashMap as HashMap;
+use std::fmt::{Debug, Display};
+
+/// The order in which [`greedy_colouring`] colours the nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColouringStrategy {
+    /// Nodes of higher degree first.
+    LargestFirst,
+    /// The reverse of the order in which nodes of smallest degree are
+    /// repeatedly removed from the graph. Uses at most one colour more
+    /// than the degeneracy of the graph.
+    SmallestLast,
+    /// Brélaz's DSatur: the node with the most distinctly coloured
+    /// neighbours next, breaking ties by degree. Colours bipartite graphs
+    /// with two colours.
+    DSatur,
+}
+
+/// The error of a [`chromatic_colouring`] that explored its limit of
+/// partial colourings before the best colouring was proven optimal, along
+/// with the best colouring found.
+pub struct LimitReached<G>
+where
+    G: GraphNode,
+{
+    colouring: HashMap<G::Key, usize>,
+    colours: usize,
+    limit: usize,
+}
+
+impl<G> LimitReached<G>
+where
+    G: GraphNode,
+{
+    /// Returns the best colouring found.
+    pub fn colouring(&self) -> &HashMap<G::Key, usize> {
+        &self.colouring
+    }
+
+    /// Consumes the error and returns the best colouring found.
+    pub fn into_colouring(self) -> HashMap<G::Key, usize> {
+        self.colouring
+    }
+
+    /// Returns the number of colours of the best colouring found.
+    pub fn colours(&self) -> usize {
+        self.colours
+    }
+}
+
+impl<G> Display for LimitReached<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Limit of {} nodes reached with {} colours",
+            self.limit, self.colours
+        )
+    }
+}
+
+impl<G> Debug for LimitReached<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<G> std::error::Error for LimitReached<G> where G: GraphNode {}
+
+// Numbers the nodes of the graph and lists the distinct neighbours of every
+// node by their index, in ascending order.
+fn neighbours<C, G>(graph: &C) -> (Vec<G>, Vec<Vec<usize>>)
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let (nodes, edges) = edge_list(graph);
+    let mut adjacent = vec![vec![]; nodes.len()];
+    for (u, v, _) in edges {
+        adjacent[u].push(v);
+        adjacent[v].push(u);
+    }
+    for list in adjacent.iter_mut() {
+        list.sort_unstable();
+        list.dedup();
+    }
+    (nodes, adjacent)
+}
+
+// Gives every node, in the given order, the smallest colour that none of
+// its coloured neighbours has.
+fn colour_in_order(adjacent: &[Vec<usize>], order: &[usize]) -> Vec<usize> {
+    let mut colour = vec![usize::MAX; adjacent.len()];
+    let mut taken = vec![];
+    for &v in order {
+        taken.clear();
+        taken.resize(adjacent[v].len() + 1, false);
+        for &u in &adjacent[v] {
+            if colour[u] < taken.len() {
+                taken[colour[u]] = true;
+            }
+        }
+        colour[v] = taken.iter().position(|&t| !t).unwrap();
+    }
+    colour
+}
+
+// Orders the nodes so that every node has as few neighbours after it as
+// possible, by repeatedly removing a node of smallest remaining degree and
+// putting it in front of the nodes removed before it.
+fn smallest_last(adjacent: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacent.len();
+    let mut degree = adjacent.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut buckets = vec![vec![]; n];
+    for v in 0..n {
+        buckets[degree[v]].push(v);
+    }
+    let mut removed = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut lowest = 0;
+    while order.len() < n {
+        // Buckets may hold stale entries of nodes whose degree has dropped.
+        let Some(v) = buckets[lowest].pop() else {
+            lowest += 1;
+            continue;
+        };
+        if removed[v] || degree[v] != lowest {
+            continue;
+        }
+        removed[v] = true;
+        order.push(v);
+        for &u in &adjacent[v] {
+            if !removed[u] {
+                degree[u] -= 1;
+                buckets[degree[u]].push(u);
+                lowest = lowest.min(degree[u]);
+            }
+        }
+    }
+    order.reverse();
+    order
+}
+
+// The colours of the neighbours of every node, counted so that colours can
+// also be taken back, and the number of distinct ones.
+struct Saturation {
+    counts: Vec<Vec<usize>>,
+    saturation: Vec<usize>,
+}
+
+impl Saturation {
+    fn new(n: usize) -> Self {
+        Saturation {
+            counts: vec![vec![]; n],
+            saturation: vec![0; n],
+        }
+    }
+
+    fn has(&self, v: usize, colour: usize) -> bool {
+        self.counts[v].get(colour).is_some_and(|&count| count > 0)
+    }
+
+    fn add(&mut self, adjacent: &[usize], colour: usize) {
+        for &u in adjacent {
+            let counts = &mut self.counts[u];
+            if counts.len() <= colour {
+                counts.resize(colour + 1, 0);
+            }
+            counts[colour] += 1;
+            if counts[colour] == 1 {
+                self.saturation[u] += 1;
+            }
+        }
+    }
+
+    fn remove(&mut self, adjacent: &[usize], colour: usize) {
+        for &u in adjacent {
+            self.counts[u][colour] -= 1;
+            if self.counts[u][colour] == 0 {
+                self.saturation[u] -= 1;
+            }
+        }
+    }
+
+    // The uncoloured node with the most distinctly coloured neighbours,
+    // and of those the one with the highest degree.
+    fn next(&self, adjacent: &[Vec<usize>], colour: &[usize]) -> Option<usize> {
+        (0..colour.len())
+            .filter(|&v| colour[v] == usize::MAX)
+            .max_by_key(|&v| (self.saturation[v], adjacent[v].len()))
+    }
+}
+
+// Colours the nodes greedily in the DSatur order.
+fn dsatur(adjacent: &[Vec<usize>]) -> Vec<usize> {
+    let mut colour = vec![usize::MAX; adjacent.len()];
+    let mut saturation = Saturation::new(adjacent.len());
+    while let Some(v) = saturation.next(adjacent, &colour) {
+        let c = (0..).find(|&c| !saturation.has(v, c)).unwrap();
+        colour[v] = c;
+        saturation.add(&adjacent[v], c);
+    }
+    colour
+}
+
+/// Colours the nodes of the undirected `graph` greedily: each node in turn
+/// gets the smallest colour that none of its already coloured neighbours
+/// has, so that no two neighbours share a colour. The `strategy` decides
+/// the order of the nodes, which determines how many colours are used.
+/// Colours are numbered from zero.
+///
+/// Loops and edges to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// // Variables that are live at the same time interfere.
+/// let g = gdsl::ungraph![
+///     (&str)
+///     ("a") => ["b", "c"]
+///     ("b") => ["c", "d"]
+///     ("c") => []
+///     ("d") => []
+/// ];
+///
+/// let registers = gdsl::algo::greedy_colouring(&g, ColouringStrategy::DSatur);
+///
+/// assert!(registers.values().max() == Some(&2));
+/// assert!(registers["a"] != registers["b"]);
+/// assert!(registers["b"] != registers["d"]);
+/// ```
+pub fn greedy_colouring<C, G>(graph: &C, strategy: ColouringStrategy) -> HashMap<G::Key, usize>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let (nodes, adjacent) = neighbours(graph);
+    let colour = match strategy {
+        ColouringStrategy::LargestFirst => {
+            let mut order = (0..nodes.len()).collect::<Vec<_>>();
+            order.sort_by_key(|&v| std::cmp::Reverse(adjacent[v].len()));
+            colour_in_order(&adjacent, &order)
+        }
+        ColouringStrategy::SmallestLast => colour_in_order(&adjacent, &smallest_last(&adjacent)),
+        ColouringStrategy::DSatur => dsatur(&adjacent),
+    };
+    nodes
+        .iter()
+        .map(|node| node.key().clone())
+        .zip(colour)
+        .collect()
+}
+
+// A branch and bound search for a colouring with fewer colours than the
+// best one known, colouring the nodes in the DSatur order.
+struct Exact<'a> {
+    adjacent: &'a [Vec<usize>],
+    colour: Vec<usize>,
+    saturation: Saturation,
+    best: Vec<usize>,
+    best_colours: usize,
+    // No colouring can use fewer colours than this.
+    lower_bound: usize,
+    explored: usize,
+    limit: usize,
+    aborted: bool,
+}
+
+impl Exact<'_> {
+    // Extends the partial colouring, which uses `used` colours. Returns
+    // true once the search is over, because the limit was reached or the
+    // best colouring was proven optimal.
+    fn search(&mut self, used: usize) -> bool {
+        let Some(v) = self.saturation.next(self.adjacent, &self.colour) else {
+            self.best.clone_from(&self.colour);
+            self.best_colours = used;
+            return used == self.lower_bound;
+        };
+        if self.explored == self.limit {
+            self.aborted = true;
+            return true;
+        }
+        self.explored += 1;
+        for c in 0..=used {
+            // Only colourings with fewer colours than the best are of
+            // interest.
+            if used.max(c + 1) >= self.best_colours {
+                break;
+            }
+            if self.saturation.has(v, c) {
+                continue;
+            }
+            self.colour[v] = c;
+            self.saturation.add(&self.adjacent[v], c);
+            let done = self.search(used.max(c + 1));
+            self.saturation.remove(&self.adjacent[v], c);
+            self.colour[v] = usize::MAX;
+            if done {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+// The size of a clique found greedily, starting from the nodes of highest
+// degree.
+fn greedy_clique(adjacent: &[Vec<usize>]) -> usize {
+    let mut order = (0..adjacent.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&v| std::cmp::Reverse(adjacent[v].len()));
+    let mut clique: Vec<usize> = vec![];
+    for v in order {
+        if clique.iter().all(|u| adjacent[v].binary_search(u).is_ok()) {
+            clique.push(v);
+        }
+    }
+    clique.len()
+}
+
+/// Colours the nodes of the undirected `graph` with as few colours as
+/// possible, so that no two neighbours share a colour. The number of
+/// colours used is the chromatic number of the graph. Colours are numbered
+/// from zero.
+///
+/// Starting from a [`greedy_colouring`] with DSatur, a backtracking search
+/// looks for colourings with fewer colours, colouring the nodes in the
+/// DSatur order and giving up on a branch as soon as it cannot beat the
+/// best colouring found. The search stops early when the colours match the
+/// size of a clique of the graph. Since the problem is NP-hard, the search
+/// explores at most `limit` partial colourings; if the limit is reached
+/// before the best colouring is proven optimal, it is returned as the
+/// error instead.
+///
+/// Loops and edges to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// // A cycle of five nodes needs three colours.
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1]
+///     (1) => [2]
+///     (2) => [3]
+///     (3) => [4]
+///     (4) => [0]
+/// ];
+///
+/// let colouring = gdsl::algo::chromatic_colouring(&g, 1000).unwrap();
+///
+/// assert!(colouring.values().max() == Some(&2));
+/// assert!(colouring[&0] != colouring[&4]);
+/// ```
+pub fn chromatic_colouring<C, G>(
+    graph: &C,
+    limit: usize,
+) -> Result<HashMap<G::Key, usize>, LimitReached<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let (nodes, adjacent) = neighbours(graph);
+    let best = dsatur(&adjacent);
+    let best_colours = best.iter().map(|&c| c + 1).max().unwrap_or(0);
+    let mut exact = Exact {
+        adjacent: &adjacent,
+        colour: vec![usize::MAX; nodes.len()],
+        saturation: Saturation::new(nodes.len()),
+        best,
+        best_colours,
+        lower_bound: greedy_clique(&adjacent),
+        explored: 0,
+        limit,
+        aborted: false,
+    };
+    if exact.best_colours > exact.lower_bound {
+        exact.search(0);
+    }
+    let (optimal, best_colours) = (!exact.aborted, exact.best_colours);
+    let colouring = nodes
+        .iter()
+        .map(|node| node.key().clone())
+        .zip(exact.best)
+        .collect();
+    match optimal {
+        true => Ok(colouring),
+        false => Err(LimitReached {
+            colouring,
+            colours: best_colours,
+            limit,
+        }),
+    }
+}
@@ -0,0 +1,394 @@
+use super::{random::Random, *};
+
+// Lists the nodes and the weighted edges of the graph like `edge_list`,
+// with each edge stored once as `(u, v, weight)` where `u <= v`.
+fn weighted_edges<C, G, F>(graph: &C, weight: F) -> (Vec<G>, Vec<(usize, usize, f64)>)
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    F: FnMut(&G::Edge) -> f64,
+{
+    let (nodes, edges) = edge_list(graph);
+    let order = (0..nodes.len()).collect();
+    reorder(nodes, edges, order, weight)
+}
+
+// Like `weighted_edges`, but with the nodes sorted by their keys, so that
+// the randomized algorithms give the same result for the same seed
+// regardless of the order in which the graph stores its nodes.
+fn sorted_weighted_edges<C, G, F>(graph: &C, weight: F) -> (Vec<G>, Vec<(usize, usize, f64)>)
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    G::Key: Ord,
+    F: FnMut(&G::Edge) -> f64,
+{
+    let (nodes, edges) = edge_list(graph);
+    let mut order = (0..nodes.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| nodes[a].key().cmp(nodes[b].key()));
+    reorder(nodes, edges, order, weight)
+}
+
+// Numbers the nodes by their position in `order` and weighs the edges.
+fn reorder<G, F>(
+    nodes: Vec<G>,
+    edges: Vec<(usize, usize, G::Edge)>,
+    order: Vec<usize>,
+    mut weight: F,
+) -> (Vec<G>, Vec<(usize, usize, f64)>)
+where
+    G: Neighbors,
+    F: FnMut(&G::Edge) -> f64,
+{
+    let mut position = vec![0; nodes.len()];
+    for (i, &old) in order.iter().enumerate() {
+        position[old] = i;
+    }
+    let mut edges = edges
+        .iter()
+        .map(|(u, v, edge)| {
+            let (u, v) = (position[*u], position[*v]);
+            (u.min(v), u.max(v), weight(edge))
+        })
+        .collect::<Vec<_>>();
+    edges.sort_by_key(|&(u, v, _)| (u, v));
+    let nodes = order.into_iter().map(|i| nodes[i].clone()).collect();
+    (nodes, edges)
+}
+
+/// Computes the modularity of a `partition` of the nodes of the undirected
+/// `graph` into communities, using `weight` as the weight of each edge. The
+/// modularity is the fraction of the total weight of the edges that falls
+/// within communities, minus the fraction expected if the edges were
+/// placed at random with the same degrees. It is at most one, and higher
+/// for partitions with denser communities.
+///
+/// Nodes that are not part of the partition form communities of their
+/// own. A graph without edges has a modularity of zero. Loops and edges to
+/// nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// // Two triangles joined by an edge.
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [2]
+///     (2) => [3]
+///     (3) => [4, 5]
+///     (4) => [5]
+///     (5) => []
+/// ];
+///
+/// let partition = gdsl::algo::louvain(&g, |_| 1.0, 7);
+/// let modularity = gdsl::algo::modularity(&g, &partition, |_| 1.0);
+///
+/// assert!(partition.len() == 2);
+/// assert!((modularity - 5.0 / 14.0).abs() < 1e-12);
+/// ```
+pub fn modularity<C, G, F>(graph: &C, partition: &Components<G>, weight: F) -> f64
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    F: FnMut(&G::Edge) -> f64,
+{
+    let (nodes, edges) = weighted_edges(graph, weight);
+    let total = edges.iter().map(|&(_, _, w)| w).sum::<f64>();
+    if total == 0.0 {
+        return 0.0;
+    }
+
+    // Nodes outside of the partition get a community after the others.
+    let mut next = partition.len();
+    let community = nodes
+        .iter()
+        .map(|node| {
+            partition.component(node.key()).unwrap_or_else(|| {
+                next += 1;
+                next - 1
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut inside = vec![0.0; next];
+    let mut degree = vec![0.0; next];
+    for &(u, v, w) in &edges {
+        let (cu, cv) = (community[u], community[v]);
+        if cu == cv {
+            inside[cu] += w;
+        }
+        degree[cu] += w;
+        degree[cv] += w;
+    }
+    inside
+        .iter()
+        .zip(&degree)
+        .map(|(inside, degree)| inside / total - (degree / (2.0 * total)).powi(2))
+        .sum()
+}
+
+// A weighted undirected graph of numbered nodes, where each node stands for
+// a community of the level below. The weights of the edges between the
+// members of a community are kept as the weight of its loop.
+struct Network {
+    adjacent: Vec<Vec<(usize, f64)>>,
+    loops: Vec<f64>,
+}
+
+impl Network {
+    fn new(n: usize, edges: &[(usize, usize, f64)]) -> Self {
+        let mut adjacent = vec![vec![]; n];
+        for &(u, v, w) in edges {
+            adjacent[u].push((v, w));
+            adjacent[v].push((u, w));
+        }
+        Network {
+            adjacent,
+            loops: vec![0.0; n],
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.adjacent.len()
+    }
+
+    // The weighted degree of a node, to which its loop counts twice.
+    fn degree(&self, v: usize) -> f64 {
+        self.adjacent[v].iter().map(|&(_, w)| w).sum::<f64>() + 2.0 * self.loops[v]
+    }
+
+    // Starting from every node in a community of its own, moves single
+    // nodes, in a random order, to the neighbouring community that gains
+    // the most modularity until no move gains any. Returns the community of
+    // every node, numbered from zero, along with the number of communities.
+    fn move_nodes(&self, total: f64, random: &mut Random) -> (Vec<usize>, usize) {
+        let n = self.len();
+        let degree = (0..n).map(|v| self.degree(v)).collect::<Vec<_>>();
+        let mut community = (0..n).collect::<Vec<_>>();
+        let mut community_degree = degree.clone();
+        // The weight of the edges from the current node to each community.
+        let mut links = vec![0.0; n];
+        let mut touched = vec![];
+        let mut order = (0..n).collect::<Vec<_>>();
+        random.shuffle(&mut order);
+
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for &v in &order {
+                for &(u, w) in &self.adjacent[v] {
+                    touched.push(community[u]);
+                    links[community[u]] += w;
+                }
+                let own = community[v];
+                community_degree[own] -= degree[v];
+                let gain = |c: usize| links[c] - community_degree[c] * degree[v] / (2.0 * total);
+                let mut best = (own, gain(own));
+                for &c in &touched {
+                    if gain(c) > best.1 {
+                        best = (c, gain(c));
+                    }
+                }
+                community[v] = best.0;
+                community_degree[best.0] += degree[v];
+                moved |= best.0 != own;
+                touched.drain(..).for_each(|c| links[c] = 0.0);
+            }
+        }
+        let count = renumber(&mut community);
+        (community, count)
+    }
+
+    // Builds the network of which the nodes are the communities of this
+    // one, merging the edges between each pair of communities.
+    fn aggregate(&self, community: &[usize], count: usize) -> Network {
+        let mut loops = vec![0.0; count];
+        let mut links = vec![vec![]; count];
+        for (u, adjacent) in self.adjacent.iter().enumerate() {
+            let cu = community[u];
+            loops[cu] += self.loops[u];
+            for &(v, w) in adjacent {
+                match community[v] {
+                    // Seen from both ends.
+                    cv if cv == cu => loops[cu] += w / 2.0,
+                    cv => links[cu].push((cv, w)),
+                }
+            }
+        }
+        let adjacent = links
+            .into_iter()
+            .map(|mut links| {
+                links.sort_by_key(|&(c, _)| c);
+                let mut merged: Vec<(usize, f64)> = vec![];
+                for (c, w) in links {
+                    match merged.last_mut() {
+                        Some(last) if last.0 == c => last.1 += w,
+                        _ => merged.push((c, w)),
+                    }
+                }
+                merged
+            })
+            .collect();
+        Network { adjacent, loops }
+    }
+}
+
+// Numbers the labels from zero in the order of their first appearance and
+// returns the number of distinct labels.
+fn renumber(labels: &mut [usize]) -> usize {
+    let mut number = vec![usize::MAX; labels.len()];
+    let mut count = 0;
+    for label in labels.iter_mut() {
+        if number[*label] == usize::MAX {
+            number[*label] = count;
+            count += 1;
+        }
+        *label = number[*label];
+    }
+    count
+}
+
+/// Partitions the nodes of the undirected `graph` into communities with the
+/// Louvain method, using `weight` as the weight of each edge. Starting from
+/// every node in a community of its own, nodes are moved one at a time to
+/// the neighbouring community that increases the [`modularity`] the most,
+/// until no move increases it. The communities are then merged into single
+/// nodes, and the process is repeated on the resulting graph until the
+/// communities no longer change.
+///
+/// Nodes are visited in a random order drawn from `seed`, so the same graph
+/// and seed always give the same partition. Weights should be positive. Loops and edges to
+/// nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// // Two groups of friends who know each other through 'C' and 'D'.
+/// let g = gdsl::ungraph![
+///     (char)
+///     ('A') => ['B', 'C']
+///     ('B') => ['C']
+///     ('C') => ['D']
+///     ('D') => ['E', 'F']
+///     ('E') => ['F']
+///     ('F') => []
+/// ];
+///
+/// let communities = gdsl::algo::louvain(&g, |_| 1.0, 42);
+///
+/// assert!(communities.len() == 2);
+/// assert!(communities.same_component(&'A', &'C'));
+/// assert!(communities.same_component(&'D', &'F'));
+/// assert!(!communities.same_component(&'C', &'D'));
+/// ```
+pub fn louvain<C, G, F>(graph: &C, weight: F, seed: u64) -> Components<G>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    G::Key: Ord,
+    F: FnMut(&G::Edge) -> f64,
+{
+    let (nodes, edges) = sorted_weighted_edges(graph, weight);
+    let total = edges.iter().map(|&(_, _, w)| w).sum::<f64>();
+    let mut labels = (0..nodes.len()).collect::<Vec<_>>();
+    if total == 0.0 {
+        return Components::from_labels(&nodes, &labels);
+    }
+
+    let mut random = Random::new(seed);
+    let mut network = Network::new(nodes.len(), &edges);
+    loop {
+        let (community, count) = network.move_nodes(total, &mut random);
+        if count == network.len() {
+            break;
+        }
+        labels
+            .iter_mut()
+            .for_each(|label| *label = community[*label]);
+        network = network.aggregate(&community, count);
+    }
+    Components::from_labels(&nodes, &labels)
+}
+
+/// Partitions the nodes of the undirected `graph` into communities with
+/// asynchronous label propagation, using `weight` as the weight of each
+/// edge. Every node starts with a label of its own. The nodes are then
+/// visited in a random order, and each takes the label with the largest
+/// total weight among its neighbours, keeping its own label if that is
+/// one of them and picking at random between ties otherwise. This is
+/// repeated until every node has a most common label of its neighbours.
+/// The nodes sharing a label form a community.
+///
+/// The random choices are drawn from `seed`, so the same graph and seed
+/// always give the same partition. Weights should be positive. Loops and
+/// edges to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// // Two cliques joined by a single edge.
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1, 2, 3]
+///     (1) => [2, 3]
+///     (2) => [3]
+///     (3) => [4]
+///     (4) => [5, 6, 7]
+///     (5) => [6, 7]
+///     (6) => [7]
+///     (7) => []
+/// ];
+///
+/// let communities = gdsl::algo::label_propagation(&g, |_| 1.0, 7);
+///
+/// assert!(communities.len() == 2);
+/// assert!(communities.same_component(&0, &3));
+/// assert!(!communities.same_component(&3, &4));
+/// ```
+pub fn label_propagation<C, G, F>(graph: &C, weight: F, seed: u64) -> Components<G>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    G::Key: Ord,
+    F: FnMut(&G::Edge) -> f64,
+{
+    let (nodes, edges) = sorted_weighted_edges(graph, weight);
+    let network = Network::new(nodes.len(), &edges);
+    let n = nodes.len();
+    let mut labels = (0..n).collect::<Vec<_>>();
+    let mut random = Random::new(seed);
+    let mut order = (0..n).collect::<Vec<_>>();
+    // The weight of the edges from the current node to each label.
+    let mut links = vec![0.0; n];
+    let mut touched = vec![];
+    let mut best = vec![];
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        random.shuffle(&mut order);
+        for &v in &order {
+            for &(u, w) in &network.adjacent[v] {
+                touched.push(labels[u]);
+                links[labels[u]] += w;
+            }
+            let most = touched.iter().map(|&l| links[l]).fold(0.0, f64::max);
+            best.clear();
+            best.extend(touched.iter().copied().filter(|&l| links[l] == most));
+            best.sort_unstable();
+            best.dedup();
+            if !best.is_empty() && !best.contains(&labels[v]) {
+                labels[v] = best[random.below(best.len())];
+                changed = true;
+            }
+            touched.drain(..).for_each(|l| links[l] = 0.0);
+        }
+    }
+    Components::from_labels(&nodes, &labels)
+}
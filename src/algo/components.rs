@@ -0,0 +1,179 @@
+use super::*;
+use ahash::AHashMap as HashMap;
+use std::{collections::VecDeque, fmt::Debug, ops::Index};
+
+/// A partition of the nodes of a graph into components, such as its
+/// connected components or its communities.
+///
+/// Components are numbered from zero in the order they are found. The
+/// groups of nodes are accessed by id, and the id of a node by its key.
+pub struct Components<G>
+where
+    G: GraphNode,
+{
+    groups: Vec<Vec<G>>,
+    ids: HashMap<G::Key, usize>,
+}
+
+impl<G> Components<G>
+where
+    G: GraphNode,
+{
+    /// Returns the id of the component containing the node with the key
+    /// `key`, or `None` if the node is not part of the graph.
+    pub fn component(&self, key: &G::Key) -> Option<usize> {
+        self.ids.get(key).copied()
+    }
+
+    /// Returns true if the nodes with the keys `u` and `v` are in the same
+    /// component.
+    pub fn same_component(&self, u: &G::Key, v: &G::Key) -> bool {
+        match (self.component(u), self.component(v)) {
+            (Some(u), Some(v)) => u == v,
+            _ => false,
+        }
+    }
+
+    /// Returns the nodes of each component, indexed by component id.
+    pub fn groups(&self) -> &[Vec<G>] {
+        &self.groups
+    }
+
+    /// Consumes the components and returns the nodes of each component.
+    pub fn into_groups(self) -> Vec<Vec<G>> {
+        self.groups
+    }
+
+    /// Returns an iterator over the keys of all nodes along with the id of
+    /// their component.
+    pub fn ids(&self) -> impl Iterator<Item = (&G::Key, usize)> {
+        self.ids.iter().map(|(key, &id)| (key, id))
+    }
+
+    /// Returns the amount of components.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns true if there are no components, i.e. the graph is empty.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+impl<G> Components<G>
+where
+    G: GraphNode,
+{
+    // Groups the nodes by their labels, which are numbered from zero in the
+    // order of their first appearance.
+    pub(crate) fn from_labels(nodes: &[G], labels: &[usize]) -> Self {
+        let mut groups: Vec<Vec<G>> = vec![];
+        let mut ids = HashMap::default();
+        let mut renumbered = HashMap::default();
+        for (node, label) in nodes.iter().zip(labels) {
+            let id = *renumbered.entry(label).or_insert(groups.len());
+            if id == groups.len() {
+                groups.push(vec![]);
+            }
+            groups[id].push(node.clone());
+            ids.insert(node.key().clone(), id);
+        }
+        Components { groups, ids }
+    }
+}
+
+impl<G> Index<usize> for Components<G>
+where
+    G: GraphNode,
+{
+    type Output = Vec<G>;
+
+    fn index(&self, id: usize) -> &Self::Output {
+        &self.groups[id]
+    }
+}
+
+impl<G> Debug for Components<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let groups = self
+            .groups
+            .iter()
+            .map(|group| group.iter().map(|node| node.key().to_string()).collect())
+            .collect::<Vec<Vec<_>>>();
+        f.debug_struct("Components")
+            .field("groups", &groups)
+            .finish()
+    }
+}
+
+/// Splits the nodes of `graph` into connected components. In a directed
+/// graph edges are followed in both directions, which yields the weakly
+/// connected components. Edges to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1]
+///     (1) => [2]
+///     (2) => []
+///     (3) => [4]
+///     (4) => []
+///     (5) => []
+/// ];
+///
+/// let components = gdsl::algo::connected_components(&g);
+///
+/// assert!(components.len() == 3);
+/// assert!(components.same_component(&0, &2));
+/// assert!(!components.same_component(&2, &3));
+///
+/// let id = components.component(&3).unwrap();
+/// assert!(components[id].len() == 2);
+/// ```
+pub fn connected_components<C, G>(graph: &C) -> Components<G>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let mut groups = vec![];
+    let mut ids = HashMap::default();
+    let mut queue = VecDeque::new();
+
+    for root in graph.nodes() {
+        if ids.contains_key(root.key()) {
+            continue;
+        }
+        let id = groups.len();
+        let mut group = vec![];
+        ids.insert(root.key().clone(), id);
+        queue.push_back(root.clone());
+
+        while let Some(node) = queue.pop_front() {
+            let inbound = match G::DIRECTED {
+                true => Some(Transposition::Inbound.adjacent(&node)),
+                false => None,
+            };
+            for edge in Transposition::Outbound
+                .adjacent(&node)
+                .chain(inbound.into_iter().flatten())
+            {
+                let v = edge.target();
+                if !ids.contains_key(v.key()) && graph.contains(v.key()) {
+                    ids.insert(v.key().clone(), id);
+                    queue.push_back(v.clone());
+                }
+            }
+            group.push(node);
+        }
+        groups.push(group);
+    }
+    Components { groups, ids }
+}
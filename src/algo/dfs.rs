@@ -0,0 +1,328 @@
+use super::{path::*, pipeline::*, *};
+use ahash::AHashSet as HashSet;
+
+/// A lazy depth-first search.
+///
+/// Besides the `search*` methods, `Dfs` is an [`Iterator`] over the edges
+/// of the depth-first search tree in the order they are discovered. The
+/// traversal only advances when `next()` is called, so it can be paused,
+/// resumed or composed with any iterator adaptor. If a target is set, the
+/// iterator ends after yielding the edge leading to the target.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [3]
+///     (2) => []
+///     (3) => []
+/// ];
+///
+/// let keys = g[0]
+///     .dfs()
+///     .map(|Edge(_, v, _)| *v.key())
+///     .take_while(|&key| key != 2)
+///     .collect::<Vec<_>>();
+///
+/// assert!(keys == vec![1, 3]);
+/// ```
+pub struct Dfs<'a, G>
+where
+    G: Neighbors,
+{
+    root: G,
+    target: Option<G::Key>,
+    pipeline: Pipeline<'a, G>,
+    transpose: Transposition,
+    stack: Vec<Adjacent<G>>,
+    visited: HashSet<G::Key>,
+    state: State,
+}
+
+impl<'a, G> Dfs<'a, G>
+where
+    G: Neighbors,
+{
+    pub fn new(root: &G) -> Self {
+        Dfs {
+            root: root.clone(),
+            target: None,
+            pipeline: Pipeline::new(),
+            transpose: Transposition::Outbound,
+            stack: vec![],
+            visited: HashSet::default(),
+            state: State::Ready,
+        }
+    }
+
+    pub fn target(mut self, target: &G::Key) -> Self {
+        self.target = Some(target.clone());
+        self
+    }
+
+    pub fn transpose(mut self) -> Self {
+        self.transpose = Transposition::Inbound;
+        self
+    }
+
+    pub fn for_each(mut self, f: ForEach<'a, G>) -> Self {
+        self.pipeline.for_each(f);
+        self
+    }
+
+    pub fn filter(mut self, f: Filter<'a, G>) -> Self {
+        self.pipeline.filter(f);
+        self
+    }
+
+    pub fn inspect(mut self, f: Inspect<'a, G>) -> Self {
+        self.pipeline.inspect(f);
+        self
+    }
+
+    /// Runs a depth-first traversal from the root and reports every node
+    /// and edge to `visitor`, see [`DfsVisitor`]. Edges pruned by the
+    /// search's pipeline are not reported and stopping it ends the
+    /// traversal. The target of the search is ignored.
+    ///
+    /// Nodes are discovered and finished at increasing timestamps drawn
+    /// from a single clock, so the interval of a descendant is nested in
+    /// the interval of its ancestors.
+    pub fn visit<V>(&mut self, visitor: &mut V)
+    where
+        V: DfsVisitor<G>,
+    {
+        let mut time = 0;
+        let mut active = HashSet::default();
+        let mut finished = HashSet::default();
+
+        active.insert(self.root.key().clone());
+        visitor.discover_node(&self.root, time);
+        time += 1;
+
+        let mut stack = vec![Frame {
+            node: self.root.clone(),
+            adjacent: self.transpose.adjacent(&self.root),
+            parent: None,
+        }];
+
+        while let Some(frame) = stack.last_mut() {
+            match frame.adjacent.next() {
+                Some(edge) => {
+                    match self.pipeline.exec(&edge) {
+                        Control::Continue => {}
+                        Control::Prune => continue,
+                        Control::Stop => return,
+                    }
+                    let v = edge.target();
+                    if active.contains(v.key()) {
+                        // In an undirected graph the tree edge leading to a
+                        // node is seen again from the other end, which is
+                        // not a back edge.
+                        if !G::DIRECTED && frame.parent.as_ref() == Some(v.key()) {
+                            frame.parent = None;
+                        } else {
+                            visitor.back_edge(&edge);
+                        }
+                    } else if finished.contains(v.key()) {
+                        // An undirected edge to a finished node has already
+                        // been reported as a back edge from that node.
+                        if G::DIRECTED {
+                            visitor.forward_or_cross_edge(&edge);
+                        }
+                    } else {
+                        let v = v.clone();
+                        visitor.tree_edge(&edge);
+                        active.insert(v.key().clone());
+                        visitor.discover_node(&v, time);
+                        time += 1;
+                        stack.push(Frame {
+                            parent: Some(edge.source().key().clone()),
+                            adjacent: self.transpose.adjacent(&v),
+                            node: v,
+                        });
+                    }
+                }
+                None => {
+                    let frame = stack.pop().unwrap();
+                    active.remove(frame.node.key());
+                    visitor.finish_node(&frame.node, time);
+                    finished.insert(frame.node.key().clone());
+                    time += 1;
+                }
+            }
+        }
+    }
+
+    pub fn search(&mut self) -> Option<G> {
+        self.restart(false);
+        let target = self.target.clone();
+        find_target(self.by_ref(), target.as_ref())
+    }
+
+    pub fn search_cycle(&mut self) -> Option<Path<G>> {
+        self.target = Some(self.root.key().clone());
+        self.restart(true);
+        let target = self.target.clone();
+        find_path(self.by_ref(), target.as_ref())
+    }
+
+    pub fn search_path(&mut self) -> Option<Path<G>> {
+        self.restart(false);
+        let target = self.target.clone();
+        find_path(self.by_ref(), target.as_ref())
+    }
+
+    // Resets the traversal to start from the root. When searching for a
+    // cycle the root is not marked as visited so it can be found again.
+    fn restart(&mut self, cycle: bool) {
+        self.stack.clear();
+        self.visited.clear();
+        self.stack.push(self.transpose.adjacent(&self.root));
+        if !cycle {
+            self.visited.insert(self.root.key().clone());
+        }
+        self.state = State::Running;
+    }
+}
+
+impl<'a, G> Iterator for Dfs<'a, G>
+where
+    G: Neighbors,
+{
+    type Item = G::Edge;
+
+    // Iterative depth-first traversal. Each stack frame holds the remaining
+    // adjacent edges of a node, so edges are explored in the same order as
+    // a recursive traversal without being bounded by the call stack.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.state {
+            State::Ready => self.restart(false),
+            State::Running => {}
+            State::Done => return None,
+        }
+        while let Some(adjacent) = self.stack.last_mut() {
+            match adjacent.next() {
+                Some(edge) => match self.pipeline.exec(&edge) {
+                    Control::Continue => {
+                        let v = edge.target();
+                        if !self.visited.contains(v.key()) {
+                            self.visited.insert(v.key().clone());
+                            if self.target.as_ref() == Some(v.key()) {
+                                self.state = State::Done;
+                            } else {
+                                self.stack.push(self.transpose.adjacent(v));
+                            }
+                            return Some(edge);
+                        }
+                    }
+                    Control::Prune => {}
+                    Control::Stop => {
+                        self.state = State::Done;
+                        return None;
+                    }
+                },
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        self.state = State::Done;
+        None
+    }
+}
+
+/// Callbacks for the events of a depth-first traversal, see [`Dfs::visit`].
+///
+/// All methods have empty default implementations, so a visitor only needs
+/// to implement the events it is interested in. Edges are classified as
+/// follows:
+///
+/// - A tree edge leads to a node that has not been discovered yet.
+/// - A back edge leads to a node that has been discovered but not finished,
+///   i.e. an ancestor of the current node on the search tree.
+/// - A forward or cross edge leads to a node that has already been
+///   finished. These only occur in directed graphs: in an undirected graph
+///   every edge is either a tree edge or a back edge and is reported once.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// #[derive(Default)]
+/// struct Classify {
+///     tree: Vec<(usize, usize)>,
+///     back: Vec<(usize, usize)>,
+///     other: Vec<(usize, usize)>,
+///     finished: Vec<usize>,
+/// }
+///
+/// impl DfsVisitor<Node<usize, (), ()>> for Classify {
+///     fn tree_edge(&mut self, Edge(u, v, _): &Edge<usize, (), ()>) {
+///         self.tree.push((*u.key(), *v.key()));
+///     }
+///
+///     fn back_edge(&mut self, Edge(u, v, _): &Edge<usize, (), ()>) {
+///         self.back.push((*u.key(), *v.key()));
+///     }
+///
+///     fn forward_or_cross_edge(&mut self, Edge(u, v, _): &Edge<usize, (), ()>) {
+///         self.other.push((*u.key(), *v.key()));
+///     }
+///
+///     fn finish_node(&mut self, node: &Node<usize, (), ()>, _time: usize) {
+///         self.finished.push(*node.key());
+///     }
+/// }
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [2]
+///     (2) => [0]
+/// ];
+///
+/// let mut visitor = Classify::default();
+/// g[0].dfs().visit(&mut visitor);
+///
+/// assert!(visitor.tree == vec![(0, 1), (1, 2)]);
+/// assert!(visitor.back == vec![(2, 0)]);
+/// assert!(visitor.other == vec![(0, 2)]);
+/// assert!(visitor.finished == vec![2, 1, 0]);
+/// ```
+pub trait DfsVisitor<G>
+where
+    G: GraphNode,
+{
+    /// Called when `node` is reached for the first time.
+    fn discover_node(&mut self, _node: &G, _time: usize) {}
+
+    /// Called for an edge leading to an undiscovered node, before the node
+    /// is discovered.
+    fn tree_edge(&mut self, _edge: &G::Edge) {}
+
+    /// Called for an edge leading to an ancestor of its source.
+    fn back_edge(&mut self, _edge: &G::Edge) {}
+
+    /// Called for an edge leading to an already finished node.
+    fn forward_or_cross_edge(&mut self, _edge: &G::Edge) {}
+
+    /// Called when all edges of `node` have been explored.
+    fn finish_node(&mut self, _node: &G, _time: usize) {}
+}
+
+// A node on the stack of `Dfs::visit`, along with its remaining adjacent
+// edges and the key of its parent in the search tree.
+struct Frame<G>
+where
+    G: Neighbors,
+{
+    node: G,
+    adjacent: Adjacent<G>,
+    parent: Option<G::Key>,
+}
@@ -0,0 +1,523 @@
+use super::*;
+use ahash::AHashMap as HashMap;
+use std::{collections::VecDeque, fmt::Debug};
+
+/// A maximum flow from a source to a sink node, along with a minimum cut.
+///
+/// The flow is given per edge of the original graph. The cut partitions the
+/// nodes into those still reachable from the source in the residual network
+/// and the rest. The capacities of the edges leading from the source side
+/// to the sink side add up to the value of the flow.
+pub struct MaxFlow<G, W>
+where
+    G: GraphNode,
+{
+    nodes: Vec<G>,
+    index: HashMap<G::Key, usize>,
+    edges: Vec<G::Edge>,
+    flows: Vec<W>,
+    value: W,
+    source_side: Vec<bool>,
+}
+
+impl<G, W> MaxFlow<G, W>
+where
+    G: GraphNode,
+    W: Weight,
+{
+    /// Returns the value of the flow, i.e. the net amount leaving the
+    /// source.
+    pub fn value(&self) -> W {
+        self.value
+    }
+
+    /// Returns the total flow on the edges from the node with the key
+    /// `from` to the node with the key `to`, or `None` if there is no such
+    /// edge.
+    pub fn flow(&self, from: &G::Key, to: &G::Key) -> Option<W> {
+        self.iter()
+            .filter(|(edge, _)| edge.source().key() == from && edge.target().key() == to)
+            .map(|(_, flow)| flow)
+            .reduce(|a, b| a + b)
+    }
+
+    /// Returns an iterator over all edges of the network along with the
+    /// flow on them.
+    pub fn iter(&self) -> impl Iterator<Item = (&G::Edge, W)> {
+        self.edges.iter().zip(self.flows.iter().copied())
+    }
+
+    /// Returns true if the node with the key `key` is on the source side of
+    /// the minimum cut.
+    pub fn is_source_side(&self, key: &G::Key) -> bool {
+        self.index.get(key).is_some_and(|&i| self.source_side[i])
+    }
+
+    /// Returns the nodes on the source side and on the sink side of the
+    /// minimum cut.
+    pub fn min_cut(&self) -> (Vec<G>, Vec<G>) {
+        let (source, sink): (Vec<_>, Vec<_>) = self
+            .nodes
+            .iter()
+            .enumerate()
+            .partition(|(i, _)| self.source_side[*i]);
+        let nodes = |side: Vec<(usize, &G)>| side.into_iter().map(|(_, n)| n.clone()).collect();
+        (nodes(source), nodes(sink))
+    }
+
+    /// Returns the edges leading from the source side to the sink side of
+    /// the minimum cut. All of them are saturated.
+    pub fn cut_edges(&self) -> Vec<G::Edge> {
+        self.edges
+            .iter()
+            .filter(|edge| {
+                self.is_source_side(edge.source().key())
+                    && !self.is_source_side(edge.target().key())
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+impl<G, W> Debug for MaxFlow<G, W>
+where
+    G: GraphNode,
+    W: Weight + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flows = self
+            .iter()
+            .map(|(edge, flow)| {
+                let (u, v) = (edge.source().key(), edge.target().key());
+                (format!("{} -> {}", u, v), flow)
+            })
+            .collect::<Vec<_>>();
+        f.debug_struct("MaxFlow")
+            .field("value", &self.value)
+            .field("flows", &flows)
+            .finish()
+    }
+}
+
+// The residual network of a flow problem. Every edge `i` of the graph is
+// turned into the arc `2 * i` in its direction and the arc `2 * i + 1`
+// against it, so the reverse of an arc `a` is `a ^ 1`. The residual
+// capacity of the reverse arc is the flow on the edge. Nodes and arcs that
+// are added after the graph's come after them.
+pub(crate) struct Residual<G, W>
+where
+    G: GraphNode,
+{
+    pub(crate) nodes: Vec<G>,
+    pub(crate) index: HashMap<G::Key, usize>,
+    pub(crate) edges: Vec<G::Edge>,
+    pub(crate) adjacent: Vec<Vec<usize>>,
+    pub(crate) head: Vec<usize>,
+    pub(crate) capacity: Vec<W>,
+}
+
+impl<G, W> Residual<G, W>
+where
+    G: Neighbors,
+    W: Weight,
+{
+    pub(crate) fn new<C, F>(graph: &C, mut capacity: F) -> Self
+    where
+        C: GraphContainer<Node = G>,
+        F: FnMut(&G::Edge) -> W,
+    {
+        let nodes = graph.nodes().cloned().collect::<Vec<_>>();
+        let index = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.key().clone(), i))
+            .collect::<HashMap<_, _>>();
+        let mut residual = Residual {
+            adjacent: vec![vec![]; nodes.len()],
+            edges: vec![],
+            head: vec![],
+            capacity: vec![],
+            nodes,
+            index,
+        };
+        for u in 0..residual.nodes.len() {
+            for edge in residual.nodes[u].outbound() {
+                if let Some(&v) = residual.index.get(edge.target().key()) {
+                    residual.add_arc(u, v, capacity(&edge));
+                    residual.edges.push(edge);
+                }
+            }
+        }
+        residual
+    }
+
+    // Returns the indices of the source and the sink, or `None` if either
+    // is not in the network.
+    pub(crate) fn terminals(&self, source: &G::Key, sink: &G::Key) -> Option<(usize, usize)> {
+        Some((*self.index.get(source)?, *self.index.get(sink)?))
+    }
+
+    // Adds a node that does not belong to the graph and returns its index.
+    pub(crate) fn add_node(&mut self) -> usize {
+        self.adjacent.push(vec![]);
+        self.adjacent.len() - 1
+    }
+
+    // Adds an arc from `u` to `v` along with its reverse.
+    pub(crate) fn add_arc(&mut self, u: usize, v: usize, capacity: W) {
+        let arc = self.head.len();
+        self.adjacent[u].push(arc);
+        self.adjacent[v].push(arc + 1);
+        self.head.extend([v, u]);
+        self.capacity.extend([capacity, W::default()]);
+    }
+
+    pub(crate) fn has_capacity(&self, arc: usize) -> bool {
+        self.capacity[arc] > W::default()
+    }
+
+    pub(crate) fn push(&mut self, arc: usize, amount: W) {
+        self.capacity[arc] = self.capacity[arc] - amount;
+        self.capacity[arc ^ 1] = self.capacity[arc ^ 1] + amount;
+    }
+
+    // Returns the arcs leading to each node on a path with the least amount
+    // of arcs from `source`, following only arcs with residual capacity.
+    pub(crate) fn bfs(&self, source: usize) -> Vec<Option<usize>> {
+        let mut predecessor = vec![None; self.adjacent.len()];
+        let mut visited = vec![false; self.adjacent.len()];
+        let mut queue = VecDeque::from([source]);
+        visited[source] = true;
+
+        while let Some(u) = queue.pop_front() {
+            for &arc in &self.adjacent[u] {
+                let v = self.head[arc];
+                if !visited[v] && self.has_capacity(arc) {
+                    visited[v] = true;
+                    predecessor[v] = Some(arc);
+                    queue.push_back(v);
+                }
+            }
+        }
+        predecessor
+    }
+
+    // Returns the arcs of the path ending at `sink` that is recorded in
+    // `predecessor`, along with the least residual capacity on it.
+    fn path(&self, predecessor: &[Option<usize>], sink: usize) -> (Vec<usize>, W) {
+        let mut path = vec![];
+        let mut v = sink;
+        while let Some(arc) = predecessor[v] {
+            path.push(arc);
+            v = self.head[arc ^ 1];
+        }
+        let bottleneck = path
+            .iter()
+            .map(|&arc| self.capacity[arc])
+            .reduce(|a, b| if b < a { b } else { a })
+            .unwrap_or_default();
+        (path, bottleneck)
+    }
+
+    fn into_max_flow(self, source: usize, value: W) -> MaxFlow<G, W> {
+        let mut source_side = vec![false; self.nodes.len()];
+        let predecessor = self.bfs(source);
+        for (v, side) in source_side.iter_mut().enumerate() {
+            *side = v == source || predecessor[v].is_some();
+        }
+        let flows = (0..self.edges.len())
+            .map(|i| self.capacity[2 * i + 1])
+            .collect();
+        MaxFlow {
+            nodes: self.nodes,
+            index: self.index,
+            edges: self.edges,
+            flows,
+            value,
+            source_side,
+        }
+    }
+}
+
+/// Computes a maximum flow from the node with the key `source` to the node
+/// with the key `sink` with the Edmonds-Karp algorithm, which augments the
+/// flow along shortest paths in the residual network. `capacity` returns
+/// the capacity of an edge, which must not be negative. Edges to nodes
+/// outside of the graph are ignored. Returns `None` if the source or the
+/// sink is not in the graph.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize) => [u64]
+///     (0) => [ (1, 3), (2, 2) ]
+///     (1) => [ (3, 2) ]
+///     (2) => [ (3, 3) ]
+///     (3) => []
+/// ];
+///
+/// let flow = gdsl::algo::edmonds_karp(&g, &0, &3, |Edge(_, _, c)| *c).unwrap();
+///
+/// assert!(flow.value() == 4);
+/// assert!(flow.flow(&1, &3) == Some(2));
+/// ```
+pub fn edmonds_karp<C, G, W, F>(
+    graph: &C,
+    source: &G::Key,
+    sink: &G::Key,
+    capacity: F,
+) -> Option<MaxFlow<G, W>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let mut residual = Residual::new(graph, capacity);
+    let (s, t) = residual.terminals(source, sink)?;
+    if s == t {
+        return Some(residual.into_max_flow(s, W::default()));
+    }
+    let mut value = W::default();
+
+    loop {
+        let predecessor = residual.bfs(s);
+        if predecessor[t].is_none() {
+            break;
+        }
+        let (path, bottleneck) = residual.path(&predecessor, t);
+        for arc in path {
+            residual.push(arc, bottleneck);
+        }
+        value = value + bottleneck;
+    }
+    Some(residual.into_max_flow(s, value))
+}
+
+/// Computes a maximum flow from the node with the key `source` to the node
+/// with the key `sink` with Dinic's algorithm. Each phase layers the
+/// residual network by distance from the source and saturates all shortest
+/// paths at once, which makes it much faster than [`edmonds_karp`] on large
+/// networks. `capacity` returns the capacity of an edge, which must not be
+/// negative. Edges to nodes outside of the graph are ignored. Returns
+/// `None` if the source or the sink is not in the graph.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize) => [u64]
+///     (0) => [ (1, 3), (2, 2) ]
+///     (1) => [ (2, 1), (3, 2) ]
+///     (2) => [ (3, 3) ]
+///     (3) => []
+/// ];
+///
+/// let flow = gdsl::algo::dinic(&g, &0, &3, |Edge(_, _, c)| *c).unwrap();
+///
+/// assert!(flow.value() == 5);
+/// ```
+pub fn dinic<C, G, W, F>(
+    graph: &C,
+    source: &G::Key,
+    sink: &G::Key,
+    capacity: F,
+) -> Option<MaxFlow<G, W>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let mut residual = Residual::new(graph, capacity);
+    let (s, t) = residual.terminals(source, sink)?;
+    if s == t {
+        return Some(residual.into_max_flow(s, W::default()));
+    }
+    let n = residual.nodes.len();
+    let mut value = W::default();
+
+    loop {
+        // Layer the nodes by their distance from the source.
+        let mut level = vec![usize::MAX; n];
+        let mut queue = VecDeque::from([s]);
+        level[s] = 0;
+        while let Some(u) = queue.pop_front() {
+            for &arc in &residual.adjacent[u] {
+                let v = residual.head[arc];
+                if level[v] == usize::MAX && residual.has_capacity(arc) {
+                    level[v] = level[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+        if level[t] == usize::MAX {
+            break;
+        }
+
+        // Find augmenting paths that only go one level deeper on every
+        // arc. Each node remembers the first of its arcs that may still
+        // lead to the sink, so dead ends are never explored twice.
+        let mut current = vec![0; n];
+        let mut path = vec![];
+        let mut u = s;
+        loop {
+            if u == t {
+                let bottleneck = path
+                    .iter()
+                    .map(|&arc| residual.capacity[arc])
+                    .reduce(|a: W, b| if b < a { b } else { a })
+                    .unwrap();
+                for &arc in &path {
+                    residual.push(arc, bottleneck);
+                }
+                value = value + bottleneck;
+                path.clear();
+                u = s;
+                continue;
+            }
+            let next = residual.adjacent[u][current[u]..].iter().position(|&arc| {
+                residual.has_capacity(arc) && level[residual.head[arc]] == level[u] + 1
+            });
+            match next {
+                Some(offset) => {
+                    current[u] += offset;
+                    let arc = residual.adjacent[u][current[u]];
+                    path.push(arc);
+                    u = residual.head[arc];
+                }
+                None => {
+                    // A dead end, remove it from the layered network.
+                    current[u] = residual.adjacent[u].len();
+                    match path.pop() {
+                        Some(arc) => {
+                            u = residual.head[arc ^ 1];
+                            current[u] += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+    }
+    Some(residual.into_max_flow(s, value))
+}
+
+/// Computes a maximum flow from the node with the key `source` to the node
+/// with the key `sink` with the push-relabel algorithm. Instead of
+/// augmenting paths, it floods the network from the source and pushes
+/// excess flow towards the sink along edges that lead downhill according
+/// to a height label, raising labels where flow gets stuck. Active nodes
+/// are processed in FIFO order. `capacity` returns the capacity of an edge,
+/// which must not be negative. With floating point capacities, excess flow
+/// that rounding errors strand at a node is dropped. Edges to nodes
+/// outside of the graph are ignored. Returns `None` if the source or the
+/// sink is not in the graph.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize) => [f64]
+///     (0) => [ (1, 1.5), (2, 2.0) ]
+///     (1) => [ (3, 2.0) ]
+///     (2) => [ (3, 1.0) ]
+///     (3) => []
+/// ];
+///
+/// let flow = gdsl::algo::push_relabel(&g, &0, &3, |Edge(_, _, c)| *c).unwrap();
+///
+/// assert!(flow.value() == 2.5);
+/// assert!(flow.cut_edges().len() == 2);
+/// ```
+pub fn push_relabel<C, G, W, F>(
+    graph: &C,
+    source: &G::Key,
+    sink: &G::Key,
+    capacity: F,
+) -> Option<MaxFlow<G, W>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let mut residual = Residual::new(graph, capacity);
+    let (s, t) = residual.terminals(source, sink)?;
+    if s == t {
+        return Some(residual.into_max_flow(s, W::default()));
+    }
+    let n = residual.nodes.len();
+    let mut height = vec![0; n];
+    let mut excess = vec![W::default(); n];
+    let mut current = vec![0; n];
+    let mut active = vec![false; n];
+    let mut queue = VecDeque::new();
+
+    // Saturate every arc leaving the source.
+    height[s] = n;
+    for i in 0..residual.adjacent[s].len() {
+        let arc = residual.adjacent[s][i];
+        let v = residual.head[arc];
+        let amount = residual.capacity[arc];
+        if v != s && residual.has_capacity(arc) {
+            residual.push(arc, amount);
+            excess[v] = excess[v] + amount;
+            if v != t && !active[v] {
+                active[v] = true;
+                queue.push_back(v);
+            }
+        }
+    }
+
+    while let Some(u) = queue.pop_front() {
+        active[u] = false;
+        // Discharge the node: push its excess to lower neighbors and raise
+        // it above its lowest residual neighbor when none are left.
+        while excess[u] > W::default() {
+            if current[u] == residual.adjacent[u].len() {
+                let lowest = residual.adjacent[u]
+                    .iter()
+                    .filter(|&&arc| residual.has_capacity(arc) && residual.head[arc] != u)
+                    .map(|&arc| height[residual.head[arc]])
+                    .min();
+                // Heights stay below `2 * n` when the arithmetic is exact.
+                // Rounding errors with floating point capacities can leave
+                // a node with an excess that has no way back to the source,
+                // which is dropped so that the algorithm terminates.
+                match lowest {
+                    Some(lowest) if lowest + 1 < 2 * n => height[u] = lowest + 1,
+                    _ => excess[u] = W::default(),
+                }
+                current[u] = 0;
+                continue;
+            }
+            let arc = residual.adjacent[u][current[u]];
+            let v = residual.head[arc];
+            if residual.has_capacity(arc) && height[u] == height[v] + 1 {
+                let amount = match residual.capacity[arc] < excess[u] {
+                    true => residual.capacity[arc],
+                    false => excess[u],
+                };
+                residual.push(arc, amount);
+                excess[u] = excess[u] - amount;
+                excess[v] = excess[v] + amount;
+                if v != s && v != t && !active[v] {
+                    active[v] = true;
+                    queue.push_back(v);
+                }
+            } else {
+                current[u] += 1;
+            }
+        }
+    }
+    let value = excess[t];
+    Some(residual.into_max_flow(s, value))
+}
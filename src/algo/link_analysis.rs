@@ -0,0 +1,248 @@
+use super::*;
+use ahash::AHashMap as HashMap;
+
+// Numbers the nodes of the graph and lists the targets of the outbound
+// edges of every node by their index. Parallel edges are kept, so a node
+// linking twice to another passes it twice the share.
+fn out_links<C, G>(graph: &C) -> (Vec<G::Key>, Vec<Vec<usize>>)
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let keys = graph
+        .nodes()
+        .map(|node| node.key().clone())
+        .collect::<Vec<_>>();
+    let index = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| (key.clone(), i))
+        .collect::<HashMap<_, _>>();
+    let links = graph
+        .nodes()
+        .map(|node| {
+            node.outbound()
+                .filter_map(|edge| index.get(edge.target().key()).copied())
+                .collect()
+        })
+        .collect();
+    (keys, links)
+}
+
+// The sum of the absolute differences of two score vectors.
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum()
+}
+
+// Scales the scores to sum up to one, unless they are all zero.
+fn normalize(scores: &mut [f64]) {
+    let sum = scores.iter().sum::<f64>();
+    if sum > 0.0 {
+        scores.iter_mut().for_each(|score| *score /= sum);
+    }
+}
+
+// Runs the PageRank power iteration. `teleport` must sum up to one, and is
+// also where the rank of nodes without outbound edges goes.
+fn power_iteration(
+    links: &[Vec<usize>],
+    teleport: &[f64],
+    damping: f64,
+    tolerance: f64,
+    max_iter: usize,
+) -> Vec<f64> {
+    let n = links.len();
+    let mut rank = teleport.to_vec();
+    for _ in 0..max_iter {
+        let mut next = vec![0.0; n];
+        let mut dangling = 0.0;
+        for (u, targets) in links.iter().enumerate() {
+            match targets.len() {
+                0 => dangling += rank[u],
+                len => {
+                    let share = damping * rank[u] / len as f64;
+                    targets.iter().for_each(|&v| next[v] += share);
+                }
+            }
+        }
+        let jump = damping * dangling + (1.0 - damping);
+        for (score, weight) in next.iter_mut().zip(teleport) {
+            *score += jump * weight;
+        }
+        let change = distance(&rank, &next);
+        rank = next;
+        if change < tolerance {
+            break;
+        }
+    }
+    rank
+}
+
+/// Computes the PageRank of every node of the directed `graph`: the
+/// probability that a surfer who keeps following random outbound edges is
+/// found at the node. At every step the surfer follows an edge with the
+/// probability `damping`, commonly 0.85, and jumps to a random node
+/// otherwise. Nodes without outbound edges are left by a jump.
+///
+/// The scores start out uniform and are updated until they change by less
+/// than `tolerance` in total, or for at most `max_iter` iterations. They
+/// sum up to one. Edges to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (char)
+///     ('A') => ['B', 'C']
+///     ('B') => ['C']
+///     ('C') => ['A']
+///     ('D') => ['C']
+/// ];
+///
+/// let rank = gdsl::algo::page_rank(&g, 0.85, 1e-9, 100);
+///
+/// assert!(rank[&'C'] > rank[&'A'] && rank[&'A'] > rank[&'B']);
+/// assert!((rank.values().sum::<f64>() - 1.0).abs() < 1e-9);
+/// ```
+pub fn page_rank<C, G>(
+    graph: &C,
+    damping: f64,
+    tolerance: f64,
+    max_iter: usize,
+) -> HashMap<G::Key, f64>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    personalized_page_rank(graph, damping, &HashMap::default(), tolerance, max_iter)
+}
+
+/// Computes the personalized PageRank of every node of the directed
+/// `graph`. Like [`page_rank`], except that random jumps land on a node
+/// with a probability proportional to its `teleport` weight, so that the
+/// scores measure the importance of nodes as seen from the nodes of
+/// positive weight. Nodes missing from `teleport` have a weight of zero,
+/// and if no weight is positive the jumps are uniform. The scores start
+/// out as the normalized weights, so nodes that cannot be reached from a
+/// node of positive weight score zero.
+///
+/// # Panics
+///
+/// Panics if a weight in `teleport` is negative or NaN.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+/// use ahash::AHashMap as HashMap;
+///
+/// let g = gdsl::digraph![
+///     (char)
+///     ('A') => ['B']
+///     ('B') => ['A']
+///     ('C') => ['D']
+///     ('D') => ['C']
+/// ];
+///
+/// let teleport = HashMap::from([('A', 1.0)]);
+/// let rank = gdsl::algo::personalized_page_rank(&g, 0.85, &teleport, 1e-9, 100);
+///
+/// assert!(rank[&'A'] > rank[&'B'] && rank[&'B'] > 0.0);
+/// assert!(rank[&'C'] == 0.0 && rank[&'D'] == 0.0);
+/// ```
+pub fn personalized_page_rank<C, G>(
+    graph: &C,
+    damping: f64,
+    teleport: &HashMap<G::Key, f64>,
+    tolerance: f64,
+    max_iter: usize,
+) -> HashMap<G::Key, f64>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    assert!(
+        teleport.values().all(|&weight| weight >= 0.0),
+        "teleport weights must not be negative or NaN"
+    );
+    let (keys, links) = out_links(graph);
+    let mut weights = keys
+        .iter()
+        .map(|key| teleport.get(key).copied().unwrap_or(0.0))
+        .collect::<Vec<_>>();
+    if weights.iter().all(|&weight| weight == 0.0) {
+        weights.fill(1.0);
+    }
+    normalize(&mut weights);
+    let rank = power_iteration(&links, &weights, damping, tolerance, max_iter);
+    keys.into_iter().zip(rank).collect()
+}
+
+/// Computes the hub and authority scores of every node of the directed
+/// `graph` with Kleinberg's HITS algorithm. A good hub links to many good
+/// authorities, and a good authority is linked to by many good hubs.
+///
+/// Both scores start out uniform and are updated alternately until the
+/// hub scores change by less than `tolerance` in total, or for at most
+/// `max_iter` iterations. Each kind of score sums up to one, unless the
+/// graph has no edges, in which case all scores are zero. Edges to nodes
+/// outside of the graph are ignored. Returns the hub and the authority
+/// scores, in this order.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// // Two directories linking to the same two pages.
+/// let g = gdsl::digraph![
+///     (&str)
+///     ("dir1") => ["page1", "page2"]
+///     ("dir2") => ["page1"]
+///     ("page1") => []
+///     ("page2") => []
+/// ];
+///
+/// let (hubs, authorities) = gdsl::algo::hits(&g, 1e-9, 100);
+///
+/// assert!(hubs["dir1"] > hubs["dir2"] && hubs["page1"] == 0.0);
+/// assert!(authorities["page1"] > authorities["page2"]);
+/// ```
+pub fn hits<C, G>(
+    graph: &C,
+    tolerance: f64,
+    max_iter: usize,
+) -> (HashMap<G::Key, f64>, HashMap<G::Key, f64>)
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let (keys, links) = out_links(graph);
+    let n = keys.len();
+    let mut hubs = vec![1.0 / n as f64; n];
+    let mut authorities = vec![0.0; n];
+    for _ in 0..max_iter {
+        authorities.fill(0.0);
+        for (u, targets) in links.iter().enumerate() {
+            targets.iter().for_each(|&v| authorities[v] += hubs[u]);
+        }
+        normalize(&mut authorities);
+
+        let mut next = links
+            .iter()
+            .map(|targets| targets.iter().map(|&v| authorities[v]).sum())
+            .collect::<Vec<f64>>();
+        normalize(&mut next);
+        let change = distance(&hubs, &next);
+        hubs = next;
+        if change < tolerance {
+            break;
+        }
+    }
+    (
+        keys.iter().cloned().zip(hubs).collect(),
+        keys.into_iter().zip(authorities).collect(),
+    )
+}
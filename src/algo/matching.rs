@@ -0,0 +1,788 @@
+use super::*;
+use std::{collections::VecDeque, ops::Div};
+
+/// Finds a maximum cardinality matching of the undirected `graph` with
+/// Edmonds' blossom algorithm: a largest set of edges of which no two share
+/// a node. Unlike [`hopcroft_karp`], the graph does not need to be
+/// bipartite; odd cycles met while searching for an augmenting path are
+/// contracted into a single node, a blossom. Runs in `O(n³)` time.
+///
+/// Each matched pair is returned as one of the edges between the two
+/// nodes. Loops and edges to nodes outside of the graph are ignored. In a
+/// directed graph, edges are taken regardless of their direction.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// // A triangle with a tail, the triangle forces a blossom.
+/// let g = gdsl::ungraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [2]
+///     (2) => [3]
+///     (3) => []
+/// ];
+///
+/// let matching = gdsl::algo::maximum_matching(&g);
+///
+/// assert!(matching.len() == 2);
+/// ```
+pub fn maximum_matching<C, G>(graph: &C) -> Vec<G::Edge>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let (nodes, edges) = edge_list(graph);
+    let n = nodes.len();
+    let mut adjacent = vec![vec![]; n];
+    for &(u, v, _) in &edges {
+        adjacent[u].push(v);
+        adjacent[v].push(u);
+    }
+
+    let mut blossom = Blossom {
+        mate: vec![None; n],
+        parent: vec![None; n],
+        base: (0..n).collect(),
+        used: vec![false; n],
+        adjacent,
+    };
+    // A greedy matching to start from saves most of the searches.
+    for u in 0..n {
+        if blossom.mate[u].is_none() {
+            let free = blossom.adjacent[u]
+                .iter()
+                .copied()
+                .find(|&v| blossom.mate[v].is_none());
+            if let Some(v) = free {
+                blossom.mate[u] = Some(v);
+                blossom.mate[v] = Some(u);
+            }
+        }
+    }
+    // A node without an augmenting path never gets one later, so a single
+    // search from every unmatched node suffices.
+    for root in 0..n {
+        if blossom.mate[root].is_none() {
+            if let Some(end) = blossom.augmenting_path(root) {
+                blossom.augment(end);
+            }
+        }
+    }
+
+    edges
+        .into_iter()
+        .filter(|&(u, v, _)| match blossom.mate[u] {
+            Some(w) if w == v => {
+                // Only one of several parallel edges is kept.
+                blossom.mate[u] = None;
+                blossom.mate[v] = None;
+                true
+            }
+            _ => false,
+        })
+        .map(|(_, _, edge)| edge)
+        .collect()
+}
+
+// The state of a search for an augmenting path. `base` maps every node to
+// the base of the outermost blossom containing it, and `parent` points
+// from odd nodes of the search tree, and from nodes inside blossoms, to
+// the node they were reached from.
+struct Blossom {
+    adjacent: Vec<Vec<usize>>,
+    mate: Vec<Option<usize>>,
+    parent: Vec<Option<usize>>,
+    base: Vec<usize>,
+    used: Vec<bool>,
+}
+
+impl Blossom {
+    // Grows an alternating tree from the unmatched `root` breadth-first,
+    // contracting blossoms along the way, until an unmatched node is
+    // reached. Returns that node, the path to it follows the parents.
+    fn augmenting_path(&mut self, root: usize) -> Option<usize> {
+        let n = self.adjacent.len();
+        self.used.fill(false);
+        self.parent.fill(None);
+        for (i, base) in self.base.iter_mut().enumerate() {
+            *base = i;
+        }
+        self.used[root] = true;
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(v) = queue.pop_front() {
+            for i in 0..self.adjacent[v].len() {
+                let to = self.adjacent[v][i];
+                if self.base[v] == self.base[to] || self.mate[v] == Some(to) {
+                    continue;
+                }
+                let odd_cycle =
+                    to == root || self.mate[to].is_some_and(|m| self.parent[m].is_some());
+                if odd_cycle {
+                    let base = self.common_base(v, to);
+                    let mut inside = vec![false; n];
+                    self.mark_path(v, base, to, &mut inside);
+                    self.mark_path(to, base, v, &mut inside);
+                    for u in 0..n {
+                        if inside[self.base[u]] {
+                            self.base[u] = base;
+                            if !self.used[u] {
+                                self.used[u] = true;
+                                queue.push_back(u);
+                            }
+                        }
+                    }
+                } else if self.parent[to].is_none() {
+                    self.parent[to] = Some(v);
+                    let Some(m) = self.mate[to] else {
+                        return Some(to);
+                    };
+                    self.used[m] = true;
+                    queue.push_back(m);
+                }
+            }
+        }
+        None
+    }
+
+    // Finds the base of the blossom closed by the edge between the even
+    // nodes `a` and `b`, the nearest common ancestor of their bases.
+    fn common_base(&self, mut a: usize, mut b: usize) -> usize {
+        let mut seen = vec![false; self.adjacent.len()];
+        loop {
+            a = self.base[a];
+            seen[a] = true;
+            match self.mate[a] {
+                Some(m) => a = self.parent[m].unwrap(),
+                None => break,
+            }
+        }
+        loop {
+            b = self.base[b];
+            if seen[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b].unwrap()].unwrap();
+        }
+    }
+
+    // Marks the blossoms on the path from `v` up to `base`, and points the
+    // parents along it back around the cycle through `child`.
+    fn mark_path(&mut self, mut v: usize, base: usize, mut child: usize, inside: &mut [bool]) {
+        while self.base[v] != base {
+            let m = self.mate[v].unwrap();
+            inside[self.base[v]] = true;
+            inside[self.base[m]] = true;
+            self.parent[v] = Some(child);
+            child = m;
+            v = self.parent[m].unwrap();
+        }
+    }
+
+    // Flips the matching along the path from the unmatched `end` to the
+    // root of the search.
+    fn augment(&mut self, end: usize) {
+        let mut v = Some(end);
+        while let Some(u) = v {
+            let p = self.parent[u].unwrap();
+            v = self.mate[p];
+            self.mate[u] = Some(p);
+            self.mate[p] = Some(u);
+        }
+    }
+}
+
+/// Finds a matching of maximum total weight in the undirected `graph` with
+/// the weighted version of Edmonds' blossom algorithm, using `weight` as
+/// the weight of each edge. The matching does not need to be of maximum
+/// cardinality: edges of negative weight are never chosen, and a heavy
+/// edge may be preferred over two lighter ones. Runs in `O(n³)` time.
+///
+/// The algorithm keeps a dual variable for every node and blossom and
+/// halves slacks, hence the additional bounds on `W`. With integer weights
+/// all computations stay exact.
+///
+/// Each matched pair is returned as one of the edges between the two
+/// nodes. Loops and edges to nodes outside of the graph are ignored. In a
+/// directed graph, edges are taken regardless of their direction.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize) => [u32]
+///     (0) => [ (1, 3), (2, 5) ]
+///     (1) => [ (2, 4) ]
+///     (2) => [ (3, 2) ]
+///     (3) => []
+/// ];
+///
+/// let matching = gdsl::algo::maximum_weight_matching(&g, |Edge(_, _, e)| *e);
+///
+/// assert!(matching.iter().map(|Edge(_, _, e)| e).sum::<u32>() == 5);
+/// ```
+pub fn maximum_weight_matching<C, G, W, F>(graph: &C, mut weight: F) -> Vec<G::Edge>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight + Div<Output = W> + From<u8>,
+    F: FnMut(&G::Edge) -> W,
+{
+    let (nodes, edges) = edge_list(graph);
+    let weights = edges
+        .iter()
+        .map(|&(u, v, ref edge)| (u, v, weight(edge)))
+        .collect();
+    let mut blossom = WeightedBlossom::new(nodes.len(), weights);
+    blossom.solve();
+
+    edges
+        .into_iter()
+        .enumerate()
+        .filter(|&(k, (u, _, _))| blossom.mate[u] == Some(2 * k + 1))
+        .map(|(_, (_, _, edge))| edge)
+        .collect()
+}
+
+// The kind of the dual adjustment that limits a stage of the weighted
+// blossom algorithm.
+enum Delta {
+    // A node dual reaches zero, the matching is optimal.
+    Node,
+    // An edge from an S-node to a free node becomes tight.
+    Free(usize),
+    // An edge between two S-blossoms becomes tight.
+    Outer(usize),
+    // The dual of a T-blossom reaches zero and it is expanded.
+    Expand(usize),
+}
+
+// The primal-dual weighted blossom algorithm after Galil, "Efficient
+// algorithms for finding maximum matching in graphs", in the formulation
+// by Joris van Rantwijk. Nodes are numbered `0..n` and blossoms `n..2n`.
+// Every edge `k` has the endpoints `2k` and `2k + 1`, and `mate` holds
+// the remote endpoint of the matched edge of each node. Labels are 1 for
+// S (outer), 2 for T (inner) and 0 for free. Dual variables are doubled,
+// so that an edge is tight when its slack `y(u) + y(v) - 2w` is zero.
+struct WeightedBlossom<W> {
+    n: usize,
+    edges: Vec<(usize, usize, W)>,
+    endpoint: Vec<usize>,
+    neighbors: Vec<Vec<usize>>,
+    mate: Vec<Option<usize>>,
+    label: Vec<u8>,
+    label_end: Vec<Option<usize>>,
+    in_blossom: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    base: Vec<Option<usize>>,
+    endpoints: Vec<Vec<usize>>,
+    best_edge: Vec<Option<usize>>,
+    best_edges: Vec<Option<Vec<usize>>>,
+    unused: Vec<usize>,
+    dual: Vec<W>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl<W> WeightedBlossom<W>
+where
+    W: Weight + Div<Output = W> + From<u8>,
+{
+    fn new(n: usize, edges: Vec<(usize, usize, W)>) -> Self {
+        let max = edges
+            .iter()
+            .map(|&(_, _, w)| w)
+            .fold(W::default(), |a, b| if b > a { b } else { a });
+        let mut neighbors = vec![vec![]; n];
+        for (k, &(u, v, _)) in edges.iter().enumerate() {
+            neighbors[u].push(2 * k + 1);
+            neighbors[v].push(2 * k);
+        }
+        let mut dual = vec![max; n];
+        dual.resize(2 * n, W::default());
+        let mut base = (0..n).map(Some).collect::<Vec<_>>();
+        base.resize(2 * n, None);
+        WeightedBlossom {
+            n,
+            endpoint: edges.iter().flat_map(|&(u, v, _)| [u, v]).collect(),
+            neighbors,
+            mate: vec![None; n],
+            label: vec![0; 2 * n],
+            label_end: vec![None; 2 * n],
+            in_blossom: (0..n).collect(),
+            parent: vec![None; 2 * n],
+            children: vec![vec![]; 2 * n],
+            base,
+            endpoints: vec![vec![]; 2 * n],
+            best_edge: vec![None; 2 * n],
+            best_edges: vec![None; 2 * n],
+            unused: (n..2 * n).collect(),
+            dual,
+            allowed: vec![false; edges.len()],
+            queue: vec![],
+            edges,
+        }
+    }
+
+    fn slack(&self, k: usize) -> W {
+        let (u, v, w) = self.edges[k];
+        self.dual[u] + self.dual[v] - (w + w)
+    }
+
+    // The nodes inside the blossom `b`, or `b` itself if it is a node.
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = vec![];
+        let mut stack = vec![b];
+        while let Some(t) = stack.pop() {
+            match t < self.n {
+                true => leaves.push(t),
+                false => stack.extend(self.children[t].iter().rev()),
+            }
+        }
+        leaves
+    }
+
+    // Labels the node `w` and its top-level blossom with `t`, reached
+    // through the endpoint `p`. A T-blossom labels its mate S in turn.
+    fn assign_label(&mut self, w: usize, t: u8, p: Option<usize>) {
+        let b = self.in_blossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        self.best_edge[w] = None;
+        self.best_edge[b] = None;
+        if t == 1 {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            let m = self.mate[self.base[b].unwrap()].unwrap();
+            self.assign_label(self.endpoint[m], 1, Some(m ^ 1));
+        }
+    }
+
+    // Traces back from the S-nodes `v` and `w` towards the roots of their
+    // trees. Returns the base of a new blossom if the paths meet, or `None`
+    // if they reach different roots, i.e. an augmenting path was found.
+    fn scan_blossom(&mut self, v: usize, w: usize) -> Option<usize> {
+        let mut path = vec![];
+        let mut base = None;
+        let (mut v, mut w) = (Some(v), Some(w));
+        while let Some(u) = v {
+            let b = self.in_blossom[u];
+            if self.label[b] & 4 != 0 {
+                base = self.base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            v = self.label_end[b].map(|p| {
+                let t = self.in_blossom[self.endpoint[p]];
+                self.endpoint[self.label_end[t].unwrap()]
+            });
+            if w.is_some() {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    // Contracts the odd cycle closed by the edge `k` into a new S-blossom
+    // with the given base.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (v, w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let (mut bv, mut bw) = (self.in_blossom[v], self.in_blossom[w]);
+        let b = self.unused.pop().unwrap();
+        self.base[b] = Some(base);
+        self.parent[b] = None;
+        self.parent[bb] = Some(b);
+
+        let mut path = vec![];
+        let mut endpoints = vec![];
+        while bv != bb {
+            self.parent[bv] = Some(b);
+            path.push(bv);
+            let p = self.label_end[bv].unwrap();
+            endpoints.push(p);
+            bv = self.in_blossom[self.endpoint[p]];
+        }
+        path.push(bb);
+        path.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+        while bw != bb {
+            self.parent[bw] = Some(b);
+            path.push(bw);
+            let p = self.label_end[bw].unwrap();
+            endpoints.push(p ^ 1);
+            bw = self.in_blossom[self.endpoint[p]];
+        }
+
+        self.label[b] = 1;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = W::default();
+        self.children[b] = path.clone();
+        self.endpoints[b] = endpoints;
+        for v in self.leaves(b) {
+            if self.label[self.in_blossom[v]] == 2 {
+                // Former T-nodes become S-nodes and need to be scanned.
+                self.queue.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+
+        // Keep the least slack edge to every neighbouring S-blossom.
+        let mut best_to = vec![None; 2 * self.n];
+        for bv in path {
+            let lists = match self.best_edges[bv].take() {
+                Some(list) => vec![list],
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .map(|v| self.neighbors[v].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+            for k in lists.into_iter().flatten() {
+                let (i, j, _) = self.edges[k];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.label[bj] == 1
+                    && best_to[bj].is_none_or(|e| self.slack(k) < self.slack(e))
+                {
+                    best_to[bj] = Some(k);
+                }
+            }
+            self.best_edge[bv] = None;
+        }
+        let best = best_to.into_iter().flatten().collect::<Vec<_>>();
+        self.best_edge[b] = None;
+        for &k in &best {
+            if self.best_edge[b].is_none_or(|e| self.slack(k) < self.slack(e)) {
+                self.best_edge[b] = Some(k);
+            }
+        }
+        self.best_edges[b] = Some(best);
+    }
+
+    // Dissolves the blossom `b` into its sub-blossoms. During a stage, a
+    // T-blossom relabels the even length path through it from where it was
+    // entered to its base. At the end of a stage, blossoms of zero dual
+    // are expanded recursively.
+    fn expand_blossom(&mut self, b: usize, end_of_stage: bool) {
+        let children = std::mem::take(&mut self.children[b]);
+        for &s in &children {
+            self.parent[s] = None;
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_of_stage && self.dual[s] == W::default() {
+                self.expand_blossom(s, end_of_stage);
+            } else {
+                for v in self.leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+
+        if !end_of_stage && self.label[b] == 2 {
+            let endpoints = std::mem::take(&mut self.endpoints[b]);
+            let len = children.len() as isize;
+            let at = |j: isize| j.rem_euclid(len) as usize;
+            let entry = self.in_blossom[self.endpoint[self.label_end[b].unwrap() ^ 1]];
+            let mut j = children.iter().position(|&c| c == entry).unwrap() as isize;
+            // Walk towards the base along the even length side.
+            let (step, trick) = match j & 1 {
+                1 => {
+                    j -= len;
+                    (1, 0)
+                }
+                _ => (-1, 1),
+            };
+            let mut p = self.label_end[b].unwrap();
+            while j != 0 {
+                self.label[self.endpoint[p ^ 1]] = 0;
+                let q = endpoints[at(j - trick)] ^ trick as usize;
+                self.label[self.endpoint[q ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, Some(p));
+                self.allowed[endpoints[at(j - trick)] / 2] = true;
+                j += step;
+                p = endpoints[at(j - trick)] ^ trick as usize;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+            // The base sub-blossom becomes a T-blossom without relabeling
+            // its mate.
+            let bv = children[at(j)];
+            let x = self.endpoint[p ^ 1];
+            self.label[x] = 2;
+            self.label[bv] = 2;
+            self.label_end[x] = Some(p);
+            self.label_end[bv] = Some(p);
+            self.best_edge[bv] = None;
+            j += step;
+            // Sub-blossoms on the odd length side that were reached from
+            // outside keep a T-label.
+            while children[at(j)] != entry {
+                let bv = children[at(j)];
+                if self.label[bv] != 1 {
+                    let reached = self.leaves(bv).into_iter().find(|&v| self.label[v] != 0);
+                    if let Some(v) = reached {
+                        self.label[v] = 0;
+                        let m = self.mate[self.base[bv].unwrap()].unwrap();
+                        self.label[self.endpoint[m]] = 0;
+                        self.assign_label(v, 2, self.label_end[v]);
+                    }
+                }
+                j += step;
+            }
+        }
+
+        self.label[b] = 0;
+        self.label_end[b] = None;
+        self.endpoints[b].clear();
+        self.base[b] = None;
+        self.best_edges[b] = None;
+        self.best_edge[b] = None;
+        self.unused.push(b);
+    }
+
+    // Flips the matched and unmatched edges on the even length path inside
+    // the blossom `b` from the node `v` to its base, and makes the
+    // sub-blossom containing `v` the new base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.parent[t] != Some(b) {
+            t = self.parent[t].unwrap();
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let children = self.children[b].clone();
+        let endpoints = self.endpoints[b].clone();
+        let len = children.len() as isize;
+        let at = |j: isize| j.rem_euclid(len) as usize;
+        let i = children.iter().position(|&c| c == t).unwrap();
+        let mut j = i as isize;
+        let (step, trick) = match i & 1 {
+            1 => {
+                j -= len;
+                (1, 0)
+            }
+            _ => (-1, 1),
+        };
+        while j != 0 {
+            j += step;
+            let t = children[at(j)];
+            let p = endpoints[at(j - trick)] ^ trick as usize;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += step;
+            let t = children[at(j)];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = Some(p ^ 1);
+            self.mate[self.endpoint[p ^ 1]] = Some(p);
+        }
+        self.children[b].rotate_left(i);
+        self.endpoints[b].rotate_left(i);
+        self.base[b] = self.base[self.children[b][0]];
+    }
+
+    // Augments the matching along the path through the tight edge `k`
+    // between two S-blossoms of different trees.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = Some(p);
+                let Some(end) = self.label_end[bs] else {
+                    break;
+                };
+                let bt = self.in_blossom[self.endpoint[end]];
+                let end = self.label_end[bt].unwrap();
+                s = self.endpoint[end];
+                let j = self.endpoint[end ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = Some(end);
+                p = end ^ 1;
+            }
+        }
+    }
+
+    // Grows alternating trees from all unmatched S-nodes along tight edges,
+    // adjusting the duals whenever no tight edge is left, until the
+    // matching is augmented or proven optimal. Each stage augments once.
+    fn solve(&mut self) {
+        let n = self.n;
+        let zero = W::default();
+        let two = W::from(2);
+        for _ in 0..n {
+            self.label.fill(0);
+            self.best_edge.fill(None);
+            self.best_edges[n..].fill(None);
+            self.allowed.fill(false);
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v].is_none() && self.label[self.in_blossom[v]] == 0 {
+                    self.assign_label(v, 1, None);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                while !augmented {
+                    let Some(v) = self.queue.pop() else {
+                        break;
+                    };
+                    for i in 0..self.neighbors[v].len() {
+                        let p = self.neighbors[v][i];
+                        let (k, w) = (p / 2, self.endpoint[p]);
+                        if self.in_blossom[v] == self.in_blossom[w] {
+                            continue;
+                        }
+                        let mut slack = zero;
+                        if !self.allowed[k] {
+                            slack = self.slack(k);
+                            self.allowed[k] = slack <= zero;
+                        }
+                        let bw = self.in_blossom[w];
+                        if self.allowed[k] {
+                            if self.label[bw] == 0 {
+                                self.assign_label(w, 2, Some(p ^ 1));
+                            } else if self.label[bw] == 1 {
+                                match self.scan_blossom(v, w) {
+                                    Some(base) => self.add_blossom(base, k),
+                                    None => {
+                                        self.augment_matching(k);
+                                        augmented = true;
+                                        break;
+                                    }
+                                }
+                            } else if self.label[w] == 0 {
+                                self.label[w] = 2;
+                                self.label_end[w] = Some(p ^ 1);
+                            }
+                        } else if self.label[bw] == 1 {
+                            let b = self.in_blossom[v];
+                            if self.best_edge[b].is_none_or(|e| slack < self.slack(e)) {
+                                self.best_edge[b] = Some(k);
+                            }
+                        } else if self.label[w] == 0
+                            && self.best_edge[w].is_none_or(|e| slack < self.slack(e))
+                        {
+                            self.best_edge[w] = Some(k);
+                        }
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                let mut delta = self.dual[..n]
+                    .iter()
+                    .copied()
+                    .reduce(|a, b| if b < a { b } else { a })
+                    .unwrap();
+                let mut kind = Delta::Node;
+                for v in 0..n {
+                    if self.label[self.in_blossom[v]] == 0 {
+                        if let Some(k) = self.best_edge[v] {
+                            if self.slack(k) < delta {
+                                delta = self.slack(k);
+                                kind = Delta::Free(k);
+                            }
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.parent[b].is_none() && self.label[b] == 1 {
+                        if let Some(k) = self.best_edge[b] {
+                            let d = self.slack(k) / two;
+                            if d < delta {
+                                delta = d;
+                                kind = Delta::Outer(k);
+                            }
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if self.base[b].is_some()
+                        && self.parent[b].is_none()
+                        && self.label[b] == 2
+                        && self.dual[b] < delta
+                    {
+                        delta = self.dual[b];
+                        kind = Delta::Expand(b);
+                    }
+                }
+
+                for v in 0..n {
+                    match self.label[self.in_blossom[v]] {
+                        1 => self.dual[v] = self.dual[v] - delta,
+                        2 => self.dual[v] = self.dual[v] + delta,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if self.base[b].is_some() && self.parent[b].is_none() {
+                        match self.label[b] {
+                            1 => self.dual[b] = self.dual[b] + delta,
+                            2 => self.dual[b] = self.dual[b] - delta,
+                            _ => {}
+                        }
+                    }
+                }
+
+                match kind {
+                    Delta::Node => break,
+                    Delta::Free(k) => {
+                        self.allowed[k] = true;
+                        let (i, j, _) = self.edges[k];
+                        let i = if self.label[self.in_blossom[i]] == 0 {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(i);
+                    }
+                    Delta::Outer(k) => {
+                        self.allowed[k] = true;
+                        self.queue.push(self.edges[k].0);
+                    }
+                    Delta::Expand(b) => self.expand_blossom(b, false),
+                }
+            }
+            if !augmented {
+                break;
+            }
+
+            for b in n..2 * n {
+                if self.parent[b].is_none()
+                    && self.base[b].is_some()
+                    && self.label[b] == 1
+                    && self.dual[b] == zero
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,426 @@
+use super::{flow::*, path::*, shortest_paths::*, *};
+use std::{collections::BinaryHeap, fmt::Debug, ops::Mul};
+
+/// A flow of minimum cost, given per edge of the original graph.
+pub struct MinCostFlow<G, W>
+where
+    G: GraphNode,
+{
+    edges: Vec<G::Edge>,
+    flows: Vec<W>,
+    value: W,
+    cost: W,
+}
+
+impl<G, W> MinCostFlow<G, W>
+where
+    G: GraphNode,
+    W: Weight,
+{
+    /// Returns the amount of flow sent from the source to the sink. This
+    /// is zero for a circulation.
+    pub fn value(&self) -> W {
+        self.value
+    }
+
+    /// Returns the total cost of the flow, i.e. the sum of the flow on
+    /// every edge times its cost.
+    pub fn cost(&self) -> W {
+        self.cost
+    }
+
+    /// Returns the total flow on the edges from the node with the key
+    /// `from` to the node with the key `to`, or `None` if there is no such
+    /// edge.
+    pub fn flow(&self, from: &G::Key, to: &G::Key) -> Option<W> {
+        self.iter()
+            .filter(|(edge, _)| edge.source().key() == from && edge.target().key() == to)
+            .map(|(_, flow)| flow)
+            .reduce(|a, b| a + b)
+    }
+
+    /// Returns an iterator over all edges of the network along with the
+    /// flow on them.
+    pub fn iter(&self) -> impl Iterator<Item = (&G::Edge, W)> {
+        self.edges.iter().zip(self.flows.iter().copied())
+    }
+}
+
+impl<G, W> Debug for MinCostFlow<G, W>
+where
+    G: GraphNode,
+    W: Weight + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flows = self
+            .iter()
+            .map(|(edge, flow)| {
+                let (u, v) = (edge.source().key(), edge.target().key());
+                (format!("{} -> {}", u, v), flow)
+            })
+            .collect::<Vec<_>>();
+        f.debug_struct("MinCostFlow")
+            .field("value", &self.value)
+            .field("cost", &self.cost)
+            .field("flows", &flows)
+            .finish()
+    }
+}
+
+// A residual network along with the cost of every edge and a potential for
+// every node. Only the cost of the forward arc of an edge is stored, the
+// reverse arc costs its negation. Reduced costs are evaluated so that no
+// intermediate value is negative as long as the reduced cost is not, which
+// keeps unsigned costs usable.
+struct CostNetwork<G, W>
+where
+    G: GraphNode,
+{
+    residual: Residual<G, W>,
+    cost: Vec<W>,
+    potential: Vec<W>,
+}
+
+impl<G, W> CostNetwork<G, W>
+where
+    G: Neighbors,
+    W: Weight + Mul<Output = W>,
+{
+    fn new<C, F, H>(graph: &C, capacity: F, mut cost: H) -> Self
+    where
+        C: GraphContainer<Node = G>,
+        F: FnMut(&G::Edge) -> W,
+        H: FnMut(&G::Edge) -> W,
+    {
+        let residual = Residual::new(graph, capacity);
+        let cost = residual.edges.iter().map(&mut cost).collect();
+        CostNetwork {
+            potential: vec![W::default(); residual.nodes.len()],
+            residual,
+            cost,
+        }
+    }
+
+    // The cost of an arc that does not belong to an edge of the graph is
+    // zero.
+    fn reduced_cost(&self, arc: usize) -> W {
+        let (u, v) = (self.residual.head[arc ^ 1], self.residual.head[arc]);
+        let cost = self.cost.get(arc / 2).copied().unwrap_or_default();
+        match arc % 2 {
+            0 => cost + self.potential[u] - self.potential[v],
+            _ => self.potential[u] - (self.potential[v] + cost),
+        }
+    }
+
+    fn add_node(&mut self) -> usize {
+        self.potential.push(W::default());
+        self.residual.add_node()
+    }
+
+    // Sets the potentials to the shortest path lengths from `source` with
+    // Bellman-Ford, so that every reduced cost becomes non-negative. Fails
+    // with a cycle of negative cost if there is one reachable from the
+    // source.
+    fn init_potentials(&mut self, source: usize) -> Result<(), NegativeCycle<G>> {
+        let n = self.residual.adjacent.len();
+        let mut distance = vec![None; n];
+        let mut predecessor = vec![None; n];
+        distance[source] = Some(W::default());
+
+        for round in 0..n {
+            let mut relaxed = None;
+            for u in 0..n {
+                let Some(du) = distance[u] else {
+                    continue;
+                };
+                for &arc in &self.residual.adjacent[u] {
+                    if !self.residual.has_capacity(arc) {
+                        continue;
+                    }
+                    let v = self.residual.head[arc];
+                    let dv = du + self.cost.get(arc / 2).copied().unwrap_or_default();
+                    if distance[v].is_none_or(|d| dv < d) {
+                        distance[v] = Some(dv);
+                        predecessor[v] = Some(arc);
+                        relaxed = Some(v);
+                    }
+                }
+            }
+            match relaxed {
+                None => break,
+                Some(v) if round == n - 1 => return Err(self.negative_cycle(&predecessor, v)),
+                Some(_) => {}
+            }
+        }
+        for (potential, distance) in self.potential.iter_mut().zip(distance) {
+            *potential = distance.unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    // Walks back from a node relaxed in the last round of Bellman-Ford until
+    // a node repeats, which closes a negative cycle.
+    fn negative_cycle(&self, predecessor: &[Option<usize>], mut v: usize) -> NegativeCycle<G> {
+        for _ in 0..predecessor.len() {
+            v = self.residual.head[predecessor[v].unwrap() ^ 1];
+        }
+        let mut edges = vec![];
+        let start = v;
+        loop {
+            let arc = predecessor[v].unwrap();
+            edges.push(self.residual.edges[arc / 2].clone());
+            v = self.residual.head[arc ^ 1];
+            if v == start {
+                break;
+            }
+        }
+        edges.reverse();
+        NegativeCycle::new(Path { edges })
+    }
+
+    // Sends as much flow as possible from `source` to `sink`, always along
+    // a cheapest path in the residual network. The potentials must make all
+    // reduced costs non-negative, and are kept that way by adding the
+    // distances found by Dijkstra's algorithm. Returns the amount of flow
+    // sent.
+    fn successive_shortest_paths(&mut self, source: usize, sink: usize) -> W {
+        let n = self.residual.adjacent.len();
+        let mut value = W::default();
+
+        loop {
+            let mut distance = vec![None; n];
+            let mut predecessor = vec![None; n];
+            let mut queue = BinaryHeap::new();
+            distance[source] = Some(W::default());
+            queue.push(MinScored(W::default(), source));
+
+            while let Some(MinScored(d, u)) = queue.pop() {
+                if distance[u].is_some_and(|du| du < d) {
+                    continue;
+                }
+                for &arc in &self.residual.adjacent[u] {
+                    if !self.residual.has_capacity(arc) {
+                        continue;
+                    }
+                    let v = self.residual.head[arc];
+                    let dv = d + self.reduced_cost(arc);
+                    if distance[v].is_none_or(|d| dv < d) {
+                        distance[v] = Some(dv);
+                        predecessor[v] = Some(arc);
+                        queue.push(MinScored(dv, v));
+                    }
+                }
+            }
+            if distance[sink].is_none() {
+                return value;
+            }
+            for (potential, distance) in self.potential.iter_mut().zip(&distance) {
+                if let Some(distance) = distance {
+                    *potential = *potential + *distance;
+                }
+            }
+
+            let mut path = vec![];
+            let mut v = sink;
+            while let Some(arc) = predecessor[v] {
+                path.push(arc);
+                v = self.residual.head[arc ^ 1];
+            }
+            let bottleneck = path
+                .iter()
+                .map(|&arc| self.residual.capacity[arc])
+                .reduce(|a, b| if b < a { b } else { a })
+                .unwrap();
+            for arc in path {
+                self.residual.push(arc, bottleneck);
+            }
+            value = value + bottleneck;
+        }
+    }
+
+    // Collects the flow on every edge, adding the given lower bounds.
+    fn into_min_cost_flow(self, value: W, lower: Option<Vec<W>>) -> MinCostFlow<G, W> {
+        let flows = (0..self.residual.edges.len())
+            .map(|i| {
+                let flow = self.residual.capacity[2 * i + 1];
+                match &lower {
+                    Some(lower) => flow + lower[i],
+                    None => flow,
+                }
+            })
+            .collect::<Vec<_>>();
+        let cost = flows
+            .iter()
+            .zip(&self.cost)
+            .fold(W::default(), |total, (&flow, &cost)| total + flow * cost);
+        MinCostFlow {
+            edges: self.residual.edges,
+            flows,
+            value,
+            cost,
+        }
+    }
+}
+
+/// Computes a maximum flow of minimum cost from the node with the key
+/// `source` to the node with the key `sink`, by augmenting the flow along
+/// cheapest paths in the residual network. Node potentials keep the reduced
+/// costs non-negative, so each path is found with Dijkstra's algorithm.
+///
+/// `capacity` returns the capacity of an edge, which must not be negative,
+/// and `cost` the cost per unit of flow on it. Costs may be negative, in
+/// which case the initial potentials are found with Bellman-Ford, which
+/// fails with a cycle of negative cost reachable from the source. Edges to
+/// nodes outside of the graph are ignored. Returns `None` if the source or
+/// the sink is not in the graph.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// // Two routes of capacity 2, the one through 1 is cheaper.
+/// let g = gdsl::digraph![
+///     (usize) => [(u32, u32)]
+///     (0) => [ (1, (2, 1)), (2, (2, 3)) ]
+///     (1) => [ (3, (2, 1)) ]
+///     (2) => [ (3, (2, 1)) ]
+///     (3) => []
+/// ];
+///
+/// let flow = gdsl::algo::min_cost_max_flow(
+///     &g,
+///     &0,
+///     &3,
+///     |Edge(_, _, (capacity, _))| *capacity,
+///     |Edge(_, _, (_, cost))| *cost,
+/// )
+/// .unwrap()
+/// .unwrap();
+///
+/// assert!(flow.value() == 4);
+/// assert!(flow.cost() == 12);
+/// ```
+pub fn min_cost_max_flow<C, G, W, F, H>(
+    graph: &C,
+    source: &G::Key,
+    sink: &G::Key,
+    capacity: F,
+    cost: H,
+) -> Option<Result<MinCostFlow<G, W>, NegativeCycle<G>>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight + Mul<Output = W>,
+    F: FnMut(&G::Edge) -> W,
+    H: FnMut(&G::Edge) -> W,
+{
+    let mut network = CostNetwork::new(graph, capacity, cost);
+    let (s, t) = network.residual.terminals(source, sink)?;
+    if network.cost.iter().any(|&cost| cost < W::default()) {
+        if let Err(cycle) = network.init_potentials(s) {
+            return Some(Err(cycle));
+        }
+    }
+    let value = match s == t {
+        true => W::default(),
+        false => network.successive_shortest_paths(s, t),
+    };
+    Some(Ok(network.into_min_cost_flow(value, None)))
+}
+
+/// Computes a circulation of minimum cost: a flow in which every node
+/// receives as much as it sends, and every edge carries at least `lower`
+/// and at most `capacity`. `cost` returns the cost per unit of flow on an
+/// edge, which may be negative. Edges to nodes outside of the graph are
+/// ignored. Returns `None` if no circulation meets the bounds.
+///
+/// Edges of negative cost are saturated up front, leaving a residual
+/// network without negative costs. The remaining imbalances are then
+/// settled with a minimum cost flow from a virtual source to a virtual
+/// sink.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// // At least 2 units must go around the cycle 0 -> 1 -> 2 -> 0, and each
+/// // unit on the edge 2 -> 0 earns 5.
+/// let g = gdsl::digraph![
+///     (usize) => [(i64, i64, i64)]
+///     (0) => [ (1, (2, 4, 1)) ]
+///     (1) => [ (2, (0, 3, 1)) ]
+///     (2) => [ (0, (0, 9, -5)) ]
+/// ];
+///
+/// let flow = gdsl::algo::min_cost_circulation(
+///     &g,
+///     |Edge(_, _, (lower, _, _))| *lower,
+///     |Edge(_, _, (_, capacity, _))| *capacity,
+///     |Edge(_, _, (_, _, cost))| *cost,
+/// )
+/// .unwrap();
+///
+/// assert!(flow.flow(&0, &1) == Some(3));
+/// assert!(flow.cost() == -9);
+/// ```
+pub fn min_cost_circulation<C, G, W, L, F, H>(
+    graph: &C,
+    mut lower: L,
+    capacity: F,
+    cost: H,
+) -> Option<MinCostFlow<G, W>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight + Mul<Output = W>,
+    L: FnMut(&G::Edge) -> W,
+    F: FnMut(&G::Edge) -> W,
+    H: FnMut(&G::Edge) -> W,
+{
+    let mut network = CostNetwork::new(graph, capacity, cost);
+    let n = network.residual.nodes.len();
+    let lower = network
+        .residual
+        .edges
+        .iter()
+        .map(&mut lower)
+        .collect::<Vec<_>>();
+
+    // Send the lower bound on every edge, and saturate the edges of
+    // negative cost. What flows into and out of each node so far is kept
+    // apart, so the amounts never become negative.
+    let mut inflow = vec![W::default(); n];
+    let mut outflow = vec![W::default(); n];
+    for (i, &lower) in lower.iter().enumerate() {
+        let arc = 2 * i;
+        let (u, v) = (network.residual.head[arc ^ 1], network.residual.head[arc]);
+        let capacity = network.residual.capacity[arc];
+        if lower < W::default() || capacity < lower {
+            return None;
+        }
+        network.residual.capacity[arc] = capacity - lower;
+        if network.cost[i] < W::default() {
+            network.residual.push(arc, capacity - lower);
+        }
+        let sent = lower + network.residual.capacity[arc ^ 1];
+        outflow[u] = outflow[u] + sent;
+        inflow[v] = inflow[v] + sent;
+    }
+
+    let (s, t) = (network.add_node(), network.add_node());
+    let mut demand = W::default();
+    for v in 0..n {
+        if outflow[v] < inflow[v] {
+            network.residual.add_arc(s, v, inflow[v] - outflow[v]);
+            demand = demand + (inflow[v] - outflow[v]);
+        } else if inflow[v] < outflow[v] {
+            network.residual.add_arc(v, t, outflow[v] - inflow[v]);
+        }
+    }
+    match network.successive_shortest_paths(s, t) < demand {
+        true => None,
+        false => Some(network.into_min_cost_flow(W::default(), Some(lower))),
+    }
+}
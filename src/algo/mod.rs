@@ -0,0 +1,272 @@
+//! # Algorithms
+//!
+//! Traversal and path machinery shared by all graph flavours. The search
+//! objects are generic over any node type implementing
+//! [`Neighbors`](crate::traits::Neighbors), and each graph module exposes
+//! them through its `Node` type (`node.bfs()`, `node.dfs()`, ...) and
+//! through type aliases such as `digraph::Bfs<K, N, E>`.
+//!
+//! Every search carries a pipeline of closures. Each call to `filter()`,
+//! `for_each()` or `inspect()` appends a stage, and every edge a search
+//! encounters is passed through the stages in the order they were added.
+//! A filter prunes the edge by returning `false`, a `for_each` visitor
+//! always lets the edge pass, and an inspector returns a [`Control`] to
+//! continue, prune the edge or stop the search altogether. Once an edge
+//! has been pruned or the search stopped, later stages do not see it.
+//!
+//! # Example
+//!
+//! ```
+//! use gdsl::digraph::*;
+//! use std::cell::Cell;
+//!
+//! let g = gdsl::digraph![
+//!     (char, Cell<u64>) => [u64]
+//!     ('A', Cell::new(u64::MAX)) => [ ('B', 1), ('C', 4) ]
+//!     ('B', Cell::new(u64::MAX)) => [ ('C', 1), ('D', 5) ]
+//!     ('C', Cell::new(u64::MAX)) => [ ('D', 1) ]
+//!     ('D', Cell::new(u64::MAX)) => [ ('E', 9) ]
+//!     ('E', Cell::new(u64::MAX)) => [ ('F', 1) ]
+//!     ('F', Cell::new(u64::MAX)) => []
+//! ];
+//!
+//! g['A'].set(0);
+//!
+//! // Relax every edge, but do not explore past a distance of 5.
+//! g['A']
+//!     .pfs()
+//!     .for_each(&mut |Edge(u, v, e)| {
+//!         if v.get() > u.get() + e {
+//!             v.set(u.get() + e);
+//!         }
+//!     })
+//!     .inspect(&mut |Edge(_, v, _)| match v.get() > 5 {
+//!         true => Control::Prune,
+//!         false => Control::Continue,
+//!     })
+//!     .search();
+//!
+//! assert!(g['C'].get() == 2);
+//! assert!(g['D'].get() == 3);
+//! assert!(g['E'].get() == 12);
+//! assert!(g['F'].get() == u64::MAX);
+//! ```
+
+pub mod all_pairs;
+pub mod assignment;
+pub mod astar;
+pub mod bfs;
+pub mod biconnected;
+pub mod bipartite;
+pub mod centrality;
+pub mod colouring;
+pub mod community;
+pub mod components;
+pub mod dfs;
+pub mod flow;
+pub mod link_analysis;
+pub mod matching;
+pub mod min_cost_flow;
+pub mod mst;
+pub mod order;
+pub mod path;
+pub mod pfs;
+pub mod scc;
+pub mod shortest_paths;
+pub mod topo;
+pub mod union_find;
+
+mod pipeline;
+mod random;
+
+pub use self::{
+    all_pairs::{floyd_warshall, johnson, DistanceTable},
+    assignment::{hungarian, Assignment},
+    astar::AStar,
+    bfs::Bfs,
+    biconnected::{articulation_points, biconnected_components, bridges},
+    bipartite::{bipartition, hopcroft_karp, Bipartition, OddCycle},
+    centrality::{
+        closeness_centrality, eigenvector_centrality, harmonic_centrality, katz_centrality,
+        Betweenness, Brandes, NotConverged,
+    },
+    colouring::{chromatic_colouring, greedy_colouring, ColouringStrategy, LimitReached},
+    community::{label_propagation, louvain, modularity},
+    components::{connected_components, Components},
+    dfs::{Dfs, DfsVisitor},
+    flow::{dinic, edmonds_karp, push_relabel, MaxFlow},
+    link_analysis::{hits, page_rank, personalized_page_rank},
+    matching::{maximum_matching, maximum_weight_matching},
+    min_cost_flow::{min_cost_circulation, min_cost_max_flow, MinCostFlow},
+    mst::{kruskal, prim},
+    order::Order,
+    path::Path,
+    pfs::{Pfs, PfsBy},
+    pipeline::Control,
+    scc::strongly_connected_components,
+    shortest_paths::{bellman_ford, dijkstra, spfa, NegativeCycle, ShortestPaths},
+    topo::{
+        topological_generations, topological_generations_latest, toposort, toposort_lexicographic,
+        CycleError,
+    },
+    union_find::UnionFind,
+};
+
+use crate::traits::*;
+use std::{
+    cmp::Ordering,
+    ops::{Add, Sub},
+};
+
+/// A numeric edge weight or path length. `Default::default()` is taken to
+/// be zero. Implemented for every type with the required operations, such
+/// as the primitive integer and floating point types.
+pub trait Weight: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Default {}
+
+impl<W> Weight for W where W: Copy + PartialOrd + Add<Output = W> + Sub<Output = W> + Default {}
+
+// A node in a priority queue ordered by its score, smallest score first.
+// Scores that can not be compared, such as NaN, are treated as equal.
+pub(crate) struct MinScored<W, G>(pub W, pub G);
+
+impl<W, G> PartialEq for MinScored<W, G>
+where
+    W: PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W, G> Eq for MinScored<W, G> where W: PartialOrd {}
+
+impl<W, G> PartialOrd for MinScored<W, G>
+where
+    W: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W, G> Ord for MinScored<W, G>
+where
+    W: PartialOrd,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// The direction in which a search follows edges.
+#[derive(Clone, Copy)]
+pub(crate) enum Transposition {
+    Outbound,
+    Inbound,
+}
+
+impl Transposition {
+    /// Returns the edges adjacent to `node` in this direction, oriented so
+    /// that `node` is always the source of the edge.
+    pub(crate) fn adjacent<G>(self, node: &G) -> Adjacent<G>
+    where
+        G: Neighbors,
+    {
+        match self {
+            Transposition::Outbound => Adjacent::Outbound(node.outbound()),
+            Transposition::Inbound => Adjacent::Inbound(node.inbound()),
+        }
+    }
+}
+
+pub(crate) enum Adjacent<G>
+where
+    G: Neighbors,
+{
+    Outbound(G::Outbound),
+    Inbound(G::Inbound),
+}
+
+impl<G> Iterator for Adjacent<G>
+where
+    G: Neighbors,
+{
+    type Item = G::Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Adjacent::Outbound(iter) => iter.next(),
+            Adjacent::Inbound(iter) => iter.next().map(|edge| edge.reverse()),
+        }
+    }
+}
+
+/// The progress of a lazy traversal. A search starts out `Ready` and is
+/// initialized from its root on the first call to `next()`.
+#[derive(Clone, Copy)]
+pub(crate) enum State {
+    Ready,
+    Running,
+    Done,
+}
+
+// Drains `edges` until an edge leading to `target` is found and returns
+// that edge's target node. Without a target the iterator is exhausted.
+pub(crate) fn find_target<G, I>(edges: I, target: Option<&G::Key>) -> Option<G>
+where
+    G: GraphNode,
+    I: Iterator<Item = G::Edge>,
+{
+    for edge in edges {
+        if Some(edge.target().key()) == target {
+            return Some(edge.target().clone());
+        }
+    }
+    None
+}
+
+// Collects the edges of `edges` until an edge leading to `target` is found
+// and backtracks them into the path from the root to the target.
+pub(crate) fn find_path<G, I>(edges: I, target: Option<&G::Key>) -> Option<Path<G>>
+where
+    G: GraphNode,
+    I: Iterator<Item = G::Edge>,
+{
+    let mut tree = vec![];
+    for edge in edges {
+        let found = Some(edge.target().key()) == target;
+        tree.push(edge);
+        if found {
+            return Some(Path::from_edge_tree(tree));
+        }
+    }
+    None
+}
+
+// Collects the nodes of the graph and every edge between two distinct
+// nodes of it once, as the indices of its ends in the node list along with
+// the edge. An undirected edge is seen from both of its ends, only the
+// copy from the end with the smaller index is kept.
+pub(crate) fn edge_list<C, G>(graph: &C) -> (Vec<G>, Vec<(usize, usize, G::Edge)>)
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let nodes = graph.nodes().cloned().collect::<Vec<_>>();
+    let index = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.key().clone(), i))
+        .collect::<ahash::AHashMap<_, _>>();
+    let mut edges = vec![];
+    for (u, node) in nodes.iter().enumerate() {
+        for edge in node.outbound() {
+            if let Some(&v) = index.get(edge.target().key()) {
+                if u < v || (G::DIRECTED && u != v) {
+                    edges.push((u, v, edge));
+                }
+            }
+        }
+    }
+    (nodes, edges)
+}
@@ -0,0 +1,130 @@
+use super::*;
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use std::collections::BinaryHeap;
+
+/// Finds a minimum spanning forest of the undirected `graph` with Kruskal's
+/// algorithm: edges are considered by increasing weight and kept unless
+/// they would close a cycle. The forest holds a minimum spanning tree of
+/// every connected component. Edges to nodes outside of the graph are
+/// ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize) => [u32]
+///     (0) => [ (1, 1), (2, 4) ]
+///     (1) => [ (2, 2) ]
+///     (2) => []
+///     (3) => [ (4, 7) ]
+///     (4) => []
+/// ];
+///
+/// let forest = gdsl::algo::kruskal(&g, |Edge(_, _, e)| *e);
+///
+/// assert!(forest.len() == 3);
+/// assert!(forest.iter().map(|Edge(_, _, e)| e).sum::<u32>() == 10);
+/// ```
+pub fn kruskal<C, G, W, F>(graph: &C, mut weight: F) -> Vec<G::Edge>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let index = graph
+        .nodes()
+        .enumerate()
+        .map(|(i, node)| (node.key().clone(), i))
+        .collect::<HashMap<_, _>>();
+
+    // Every undirected edge is seen from both ends, the second copy is
+    // rejected as it would close a cycle.
+    let mut edges = graph
+        .nodes()
+        .flat_map(|node| node.outbound())
+        .filter(|edge| index.contains_key(edge.target().key()))
+        .map(|edge| (weight(&edge), edge))
+        .collect::<Vec<_>>();
+    edges.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let mut sets = UnionFind::new(index.len());
+    let mut forest = vec![];
+    for (_, edge) in edges {
+        let (u, v) = (index[edge.source().key()], index[edge.target().key()]);
+        if sets.union(u, v) {
+            forest.push(edge);
+            if sets.count() == 1 {
+                break;
+            }
+        }
+    }
+    forest
+}
+
+/// Finds a minimum spanning forest of the undirected `graph` with Prim's
+/// algorithm: a tree is grown from a node by repeatedly adding the lightest
+/// edge to a node outside of it, and a new tree is started whenever a
+/// component is exhausted. Edges are oriented away from the root of their
+/// tree. Edges to nodes outside of the graph are ignored.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::ungraph::*;
+///
+/// let g = gdsl::ungraph![
+///     (usize) => [u32]
+///     (0) => [ (1, 1), (2, 4) ]
+///     (1) => [ (2, 2) ]
+///     (2) => []
+///     (3) => [ (4, 7) ]
+///     (4) => []
+/// ];
+///
+/// let forest = gdsl::algo::prim(&g, |Edge(_, _, e)| *e);
+///
+/// assert!(forest.len() == 3);
+/// assert!(forest.iter().map(|Edge(_, _, e)| e).sum::<u32>() == 10);
+/// ```
+pub fn prim<C, G, W, F>(graph: &C, mut weight: F) -> Vec<G::Edge>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let mut in_tree = HashSet::default();
+    let mut queue = BinaryHeap::new();
+    let mut forest = vec![];
+
+    for root in graph.nodes() {
+        if !in_tree.insert(root.key().clone()) {
+            continue;
+        }
+        let mut node = root.clone();
+        loop {
+            for edge in node.outbound() {
+                let v = edge.target();
+                if !in_tree.contains(v.key()) && graph.contains(v.key()) {
+                    queue.push(MinScored(weight(&edge), edge));
+                }
+            }
+            // Edges are queued once for every tree node they touch, those
+            // leading back into the tree are skipped.
+            let next = std::iter::from_fn(|| queue.pop())
+                .find(|MinScored(_, edge)| !in_tree.contains(edge.target().key()));
+            match next {
+                Some(MinScored(_, edge)) => {
+                    node = edge.target().clone();
+                    in_tree.insert(node.key().clone());
+                    forest.push(edge);
+                }
+                None => break,
+            }
+        }
+    }
+    forest
+}
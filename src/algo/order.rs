@@ -0,0 +1,262 @@
+use super::{pipeline::*, *};
+use ahash::AHashSet as HashSet;
+
+#[derive(Clone, Copy)]
+pub enum Ordering {
+    Pre,
+    Post,
+}
+
+/// A lazy depth-first ordering of the nodes reachable from a root.
+///
+/// Besides `search_nodes()` and `search_edges()`, `Order` is an
+/// [`Iterator`] over the edges of the depth-first search tree. In preorder
+/// an edge is yielded when its target is discovered, in postorder when the
+/// traversal of its target has finished. Use `nodes()` to iterate over the
+/// nodes instead, including the root.
+///
+/// Constructed directly, an `Order` follows outbound edges unless
+/// `transpose()` is called and yields edges from the parent to the child.
+/// The node methods of each graph flavour keep their own defaults, e.g.
+/// the digraph `postorder()` follows inbound edges and the ungraph
+/// `order()` yields its edges reversed.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [3]
+///     (2) => []
+///     (3) => []
+/// ];
+///
+/// let mut order = g[0].preorder().post();
+///
+/// let Edge(u, v, _) = order.next().unwrap();
+/// assert!(u == g[1] && v == g[3]);
+///
+/// let keys = g[0]
+///     .preorder()
+///     .post()
+///     .nodes()
+///     .map(|node| *node.key())
+///     .collect::<Vec<_>>();
+///
+/// assert!(keys == vec![3, 1, 2, 0]);
+/// ```
+pub struct Order<'a, G>
+where
+    G: Neighbors,
+{
+    root: G,
+    pipeline: Pipeline<'a, G>,
+    order: Ordering,
+    transpose: Transposition,
+    stack: Vec<(Option<G::Edge>, Adjacent<G>)>,
+    visited: HashSet<G::Key>,
+    state: State,
+    root_last: bool,
+    reverse_edges: bool,
+}
+
+impl<'a, G> Order<'a, G>
+where
+    G: Neighbors,
+{
+    pub fn new(root: &G) -> Self {
+        Self::preorder(root)
+    }
+
+    pub fn preorder(root: &G) -> Self {
+        Self {
+            root: root.clone(),
+            pipeline: Pipeline::new(),
+            order: Ordering::Pre,
+            transpose: Transposition::Outbound,
+            stack: vec![],
+            visited: HashSet::default(),
+            state: State::Ready,
+            root_last: false,
+            reverse_edges: false,
+        }
+    }
+
+    pub fn postorder(root: &G) -> Self {
+        Self::preorder(root).post()
+    }
+
+    pub fn pre(mut self) -> Self {
+        self.order = Ordering::Pre;
+        self.root_last = false;
+        self
+    }
+
+    pub fn post(mut self) -> Self {
+        self.order = Ordering::Post;
+        self.root_last = true;
+        self
+    }
+
+    pub fn transpose(mut self) -> Self {
+        self.transpose = Transposition::Inbound;
+        self
+    }
+
+    // Keeps the discovery order of the edges but puts the root last in
+    // `search_nodes()` and `nodes()`, as the digraph `postorder()` did.
+    pub(crate) fn root_last(mut self) -> Self {
+        self.root_last = true;
+        self
+    }
+
+    // Yields the edges from the child to the parent, as the ungraph
+    // `order()` did.
+    pub(crate) fn reverse_edges(mut self) -> Self {
+        self.reverse_edges = true;
+        self
+    }
+
+    pub fn for_each(mut self, f: ForEach<'a, G>) -> Self {
+        self.pipeline.for_each(f);
+        self
+    }
+
+    pub fn filter(mut self, f: Filter<'a, G>) -> Self {
+        self.pipeline.filter(f);
+        self
+    }
+
+    pub fn inspect(mut self, f: Inspect<'a, G>) -> Self {
+        self.pipeline.inspect(f);
+        self
+    }
+
+    /// Turns the ordering into an iterator over nodes. The root is yielded
+    /// first in preorder and last in postorder.
+    pub fn nodes(self) -> Nodes<'a, G> {
+        Nodes {
+            root: Some(self.root.clone()),
+            order: self,
+        }
+    }
+
+    pub fn search_nodes(&mut self) -> Vec<G> {
+        let mut nodes = vec![];
+        self.restart();
+        let edges = std::iter::from_fn(|| self.step()).collect::<Vec<_>>();
+
+        if !self.root_last {
+            nodes.push(self.root.clone());
+        }
+        nodes.extend(edges.iter().map(|edge| edge.target().clone()));
+        if self.root_last {
+            nodes.push(self.root.clone());
+        }
+        nodes
+    }
+
+    pub fn search_edges(&mut self) -> Vec<G::Edge> {
+        self.restart();
+        self.by_ref().collect()
+    }
+
+    fn restart(&mut self) {
+        self.stack.clear();
+        self.visited.clear();
+        self.visited.insert(self.root.key().clone());
+        self.stack.push((None, self.transpose.adjacent(&self.root)));
+        self.state = State::Running;
+    }
+
+    // Each stack frame holds the tree edge leading to a node and the
+    // node's remaining adjacent edges. In preorder a tree edge is returned
+    // when it is discovered, in postorder when the traversal of its target
+    // node has finished.
+    fn step(&mut self) -> Option<G::Edge> {
+        match self.state {
+            State::Ready => self.restart(),
+            State::Running => {}
+            State::Done => return None,
+        }
+        while let Some((_, adjacent)) = self.stack.last_mut() {
+            match adjacent.next() {
+                Some(edge) => match self.pipeline.exec(&edge) {
+                    Control::Continue => {
+                        let v = edge.target().clone();
+                        if !self.visited.contains(v.key()) {
+                            self.visited.insert(v.key().clone());
+                            let adjacent = self.transpose.adjacent(&v);
+                            match self.order {
+                                Ordering::Pre => {
+                                    self.stack.push((None, adjacent));
+                                    return Some(edge);
+                                }
+                                Ordering::Post => self.stack.push((Some(edge), adjacent)),
+                            }
+                        }
+                    }
+                    Control::Prune => {}
+                    Control::Stop => {
+                        self.state = State::Done;
+                        return None;
+                    }
+                },
+                None => {
+                    if let (Some(edge), _) = self.stack.pop().unwrap() {
+                        return Some(edge);
+                    }
+                }
+            }
+        }
+        self.state = State::Done;
+        None
+    }
+}
+
+impl<'a, G> Iterator for Order<'a, G>
+where
+    G: Neighbors,
+{
+    type Item = G::Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let edge = self.step()?;
+        match self.reverse_edges {
+            true => Some(edge.reverse()),
+            false => Some(edge),
+        }
+    }
+}
+
+/// An iterator over the nodes of an [`Order`], see [`Order::nodes`].
+pub struct Nodes<'a, G>
+where
+    G: Neighbors,
+{
+    order: Order<'a, G>,
+    root: Option<G>,
+}
+
+impl<'a, G> Iterator for Nodes<'a, G>
+where
+    G: Neighbors,
+{
+    type Item = G;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.order.root_last {
+            false => match self.root.take() {
+                Some(root) => Some(root),
+                None => self.order.step().map(|edge| edge.target().clone()),
+            },
+            true => match self.order.step() {
+                Some(edge) => Some(edge.target().clone()),
+                None => self.root.take(),
+            },
+        }
+    }
+}
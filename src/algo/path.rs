@@ -0,0 +1,178 @@
+use crate::traits::*;
+use std::{fmt::Display, ops::Index};
+
+pub fn backtrack_edge_tree<G>(edge_tree: Vec<G::Edge>) -> Vec<G::Edge>
+where
+    G: GraphNode,
+{
+    let mut path: Vec<G::Edge> = Vec::new();
+
+    if edge_tree.len() == 1 {
+        path.push(edge_tree[0].clone());
+        return path;
+    }
+    let w = edge_tree.last().unwrap();
+    path.push(w.clone());
+    let mut i = 0;
+    for edge in edge_tree.iter().rev() {
+        if path[i].source() == edge.target() {
+            path.push(edge.clone());
+            i += 1;
+        }
+    }
+    path.reverse();
+    path
+}
+
+pub struct Path<G>
+where
+    G: GraphNode,
+{
+    pub edges: Vec<G::Edge>,
+}
+
+impl<G> Path<G>
+where
+    G: GraphNode,
+{
+    pub fn len(&self) -> usize {
+        // Conceptually a path always contains at least one node,
+        // the root node. The path containes edges, so the length
+        // of the path is the number of edges plus one.
+        self.edges.len() + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn from_edge_tree(edge_tree: Vec<G::Edge>) -> Path<G> {
+        Path {
+            edges: backtrack_edge_tree::<G>(edge_tree),
+        }
+    }
+
+    pub fn iter_nodes(&self) -> PathNodeIterator<'_, G> {
+        PathNodeIterator {
+            path: self,
+            position: 0,
+        }
+    }
+
+    pub fn iter_edges(&self) -> PathEdgeIterator<'_, G> {
+        PathEdgeIterator {
+            path: self,
+            position: 0,
+        }
+    }
+
+    pub fn first_edge(&self) -> Option<&G::Edge> {
+        self.edges.first()
+    }
+
+    pub fn first_node(&self) -> Option<&G> {
+        self.edges.first().map(|e| e.target())
+    }
+
+    pub fn last_edge(&self) -> Option<&G::Edge> {
+        self.edges.last()
+    }
+
+    pub fn last_node(&self) -> Option<&G> {
+        self.edges.last().map(|e| e.target())
+    }
+
+    pub fn to_vec_nodes(&self) -> Vec<G> {
+        self.iter_nodes().collect()
+    }
+
+    pub fn to_vec_edges(&self) -> Vec<G::Edge> {
+        self.edges.clone()
+    }
+}
+
+impl<G> Index<usize> for Path<G>
+where
+    G: GraphNode,
+{
+    type Output = G::Edge;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.edges[index]
+    }
+}
+
+/// Formats the path as the keys of its nodes, e.g. `A -> B -> C`.
+impl<G> Display for Path<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, node) in self.iter_nodes().enumerate() {
+            if i > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", node.key())?;
+        }
+        Ok(())
+    }
+}
+
+pub struct PathEdgeIterator<'a, G>
+where
+    G: GraphNode,
+{
+    path: &'a Path<G>,
+    position: usize,
+}
+
+impl<'a, G> Iterator for PathEdgeIterator<'a, G>
+where
+    G: GraphNode,
+{
+    type Item = G::Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.path.edges.get(self.position) {
+            Some(edge) => {
+                self.position += 1;
+                Some(edge.clone())
+            }
+            None => None,
+        }
+    }
+}
+
+pub struct PathNodeIterator<'a, G>
+where
+    G: GraphNode,
+{
+    path: &'a Path<G>,
+    position: usize,
+}
+
+impl<'a, G> Iterator for PathNodeIterator<'a, G>
+where
+    G: GraphNode,
+{
+    type Item = G;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position == 0 {
+            match self.path.edges.get(self.position) {
+                Some(edge) => {
+                    self.position += 1;
+                    return Some(edge.source().clone());
+                }
+                None => return None,
+            }
+        }
+        match self.path.edges.get(self.position - 1) {
+            Some(edge) => {
+                self.position += 1;
+                Some(edge.target().clone())
+            }
+            None => None,
+        }
+    }
+}
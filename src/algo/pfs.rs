@@ -0,0 +1,514 @@
+use super::{path::*, pipeline::*, *};
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+#[derive(Clone, Copy)]
+enum Priority {
+    Min,
+    Max,
+}
+
+// A node in the priority queue. The ordering of the node's values is
+// reversed for a min-priority search so that `BinaryHeap` pops the smallest
+// node first.
+struct Prioritized<G> {
+    node: G,
+    priority: Priority,
+}
+
+impl<G> PartialEq for Prioritized<G>
+where
+    G: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<G> Eq for Prioritized<G> where G: Ord {}
+
+impl<G> PartialOrd for Prioritized<G>
+where
+    G: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<G> Ord for Prioritized<G>
+where
+    G: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.priority {
+            Priority::Min => other.node.cmp(&self.node),
+            Priority::Max => self.node.cmp(&other.node),
+        }
+    }
+}
+
+/// A lazy priority-first search.
+///
+/// Besides the `search*` methods, `Pfs` is an [`Iterator`] over the edges
+/// of the search tree in the order they are discovered. Nodes are expanded
+/// in order of their value, smallest first by default or largest first
+/// after calling `max()`. If a target is set, the iterator ends after
+/// yielding the edge leading to the target.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize, usize)
+///     (0, 0) => [1, 2]
+///     (1, 5) => [3]
+///     (2, 1) => [4]
+///     (3, 0) => []
+///     (4, 0) => []
+/// ];
+///
+/// let keys = g[0]
+///     .pfs()
+///     .map(|Edge(_, v, _)| *v.key())
+///     .collect::<Vec<_>>();
+///
+/// assert!(keys == vec![1, 2, 4, 3]);
+/// ```
+pub struct Pfs<'a, G>
+where
+    G: Neighbors,
+{
+    root: G,
+    target: Option<G::Key>,
+    pipeline: Pipeline<'a, G>,
+    transpose: Transposition,
+    priority: Priority,
+    queue: BinaryHeap<Prioritized<G>>,
+    adjacent: Option<Adjacent<G>>,
+    visited: HashSet<G::Key>,
+    state: State,
+}
+
+impl<'a, G> Pfs<'a, G>
+where
+    G: Neighbors + Ord,
+{
+    pub fn new(root: &G) -> Self {
+        Pfs {
+            root: root.clone(),
+            target: None,
+            pipeline: Pipeline::new(),
+            transpose: Transposition::Outbound,
+            priority: Priority::Min,
+            queue: BinaryHeap::new(),
+            adjacent: None,
+            visited: HashSet::default(),
+            state: State::Ready,
+        }
+    }
+
+    pub fn min(mut self) -> Self {
+        self.priority = Priority::Min;
+        self
+    }
+
+    pub fn max(mut self) -> Self {
+        self.priority = Priority::Max;
+        self
+    }
+
+    pub fn target(mut self, target: &G::Key) -> Self {
+        self.target = Some(target.clone());
+        self
+    }
+
+    pub fn transpose(mut self) -> Self {
+        self.transpose = Transposition::Inbound;
+        self
+    }
+
+    pub fn for_each(mut self, f: ForEach<'a, G>) -> Self {
+        self.pipeline.for_each(f);
+        self
+    }
+
+    pub fn filter(mut self, f: Filter<'a, G>) -> Self {
+        self.pipeline.filter(f);
+        self
+    }
+
+    pub fn inspect(mut self, f: Inspect<'a, G>) -> Self {
+        self.pipeline.inspect(f);
+        self
+    }
+
+    pub fn search(&mut self) -> Option<G> {
+        self.search_path()
+            .map(|path| path.last_node().unwrap().clone())
+    }
+
+    pub fn search_cycle(&mut self) -> Option<Path<G>> {
+        self.target = Some(self.root.key().clone());
+        self.restart(true);
+        let target = self.target.clone();
+        find_path(self.by_ref(), target.as_ref())
+    }
+
+    pub fn search_path(&mut self) -> Option<Path<G>> {
+        self.restart(false);
+        let target = self.target.clone();
+        find_path(self.by_ref(), target.as_ref())
+    }
+
+    // Resets the traversal to start from the root. When searching for a
+    // cycle the root is not marked as visited so it can be found again.
+    fn restart(&mut self, cycle: bool) {
+        self.queue.clear();
+        self.visited.clear();
+        self.adjacent = None;
+        self.queue.push(Prioritized {
+            node: self.root.clone(),
+            priority: self.priority,
+        });
+        if !cycle {
+            self.visited.insert(self.root.key().clone());
+        }
+        self.state = State::Running;
+    }
+}
+
+impl<'a, G> Iterator for Pfs<'a, G>
+where
+    G: Neighbors + Ord,
+{
+    type Item = G::Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.state {
+            State::Ready => self.restart(false),
+            State::Running => {}
+            State::Done => return None,
+        }
+        loop {
+            let adjacent = match self.adjacent {
+                Some(ref mut adjacent) => adjacent,
+                None => match self.queue.pop() {
+                    Some(Prioritized { node, .. }) => {
+                        self.adjacent.insert(self.transpose.adjacent(&node))
+                    }
+                    None => {
+                        self.state = State::Done;
+                        return None;
+                    }
+                },
+            };
+            match adjacent.next() {
+                Some(edge) => match self.pipeline.exec(&edge) {
+                    Control::Continue => {
+                        let v = edge.target();
+                        if !self.visited.contains(v.key()) {
+                            self.visited.insert(v.key().clone());
+                            if self.target.as_ref() == Some(v.key()) {
+                                self.state = State::Done;
+                            } else {
+                                self.queue.push(Prioritized {
+                                    node: v.clone(),
+                                    priority: self.priority,
+                                });
+                            }
+                            return Some(edge);
+                        }
+                    }
+                    Control::Prune => {}
+                    Control::Stop => {
+                        self.state = State::Done;
+                        return None;
+                    }
+                },
+                None => self.adjacent = None,
+            }
+        }
+    }
+}
+
+pub type PriorityFn<'a, G, P> = Box<dyn FnMut(&<G as GraphNode>::Edge, &P) -> P + 'a>;
+
+/// A lazy priority-first search ordered by priorities computed from the
+/// edges instead of the node values.
+///
+/// The root starts with an initial priority, and the priority of the node
+/// reached over an edge is computed from the edge and the priority of the
+/// edge's source. The search keeps the priorities in a side table, so any
+/// node value can be used and nothing has to be stored in the nodes.
+///
+/// Nodes are expanded in order of their priority, smallest first by
+/// default or largest first after calling `max()`. A node is finished when
+/// it is taken from the queue, at which point its priority is the best one
+/// found. `PfsBy` is an [`Iterator`] over the edges leading to the finished
+/// nodes, paired with the node's final priority. If a target is set, the
+/// iterator ends after yielding the edge leading to the target.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (char) => [u64]
+///     ('A') => [ ('B', 4), ('C', 1) ]
+///     ('B') => [ ('D', 1) ]
+///     ('C') => [ ('B', 2), ('D', 5) ]
+///     ('D') => []
+/// ];
+///
+/// // Priorities are accumulated path lengths, as in Dijkstra's algorithm.
+/// let order = g['A']
+///     .pfs_by(0, |Edge(_, _, e), d| d + e)
+///     .map(|(Edge(_, v, _), d)| (*v.key(), d))
+///     .collect::<Vec<_>>();
+///
+/// assert!(order == vec![('C', 1), ('B', 3), ('D', 4)]);
+/// ```
+pub struct PfsBy<'a, G, P>
+where
+    G: Neighbors,
+{
+    root: G,
+    initial: P,
+    priority_fn: PriorityFn<'a, G, P>,
+    target: Option<G::Key>,
+    pipeline: Pipeline<'a, G>,
+    transpose: Transposition,
+    priority: Priority,
+    queue: BinaryHeap<Scored<G, P>>,
+    best: HashMap<G::Key, P>,
+    finished: HashSet<G::Key>,
+    state: State,
+}
+
+impl<'a, G, P> PfsBy<'a, G, P>
+where
+    G: Neighbors,
+    P: Clone + PartialOrd,
+{
+    pub fn new<F>(root: &G, initial: P, f: F) -> Self
+    where
+        F: FnMut(&G::Edge, &P) -> P + 'a,
+    {
+        PfsBy {
+            root: root.clone(),
+            initial,
+            priority_fn: Box::new(f),
+            target: None,
+            pipeline: Pipeline::new(),
+            transpose: Transposition::Outbound,
+            priority: Priority::Min,
+            queue: BinaryHeap::new(),
+            best: HashMap::default(),
+            finished: HashSet::default(),
+            state: State::Ready,
+        }
+    }
+
+    pub fn min(mut self) -> Self {
+        self.priority = Priority::Min;
+        self
+    }
+
+    pub fn max(mut self) -> Self {
+        self.priority = Priority::Max;
+        self
+    }
+
+    pub fn target(mut self, target: &G::Key) -> Self {
+        self.target = Some(target.clone());
+        self
+    }
+
+    pub fn transpose(mut self) -> Self {
+        self.transpose = Transposition::Inbound;
+        self
+    }
+
+    pub fn for_each(mut self, f: ForEach<'a, G>) -> Self {
+        self.pipeline.for_each(f);
+        self
+    }
+
+    pub fn filter(mut self, f: Filter<'a, G>) -> Self {
+        self.pipeline.filter(f);
+        self
+    }
+
+    pub fn inspect(mut self, f: Inspect<'a, G>) -> Self {
+        self.pipeline.inspect(f);
+        self
+    }
+
+    /// Returns the best priority found so far for the node with the given
+    /// key. Once the node has been yielded its priority is final.
+    pub fn priority(&self, key: &G::Key) -> Option<&P> {
+        self.best.get(key)
+    }
+
+    pub fn search(&mut self) -> Option<G> {
+        self.restart();
+        let target = self.target.clone();
+        find_target(self.by_ref().map(|(edge, _)| edge), target.as_ref())
+    }
+
+    pub fn search_path(&mut self) -> Option<Path<G>> {
+        self.restart();
+        let target = self.target.clone();
+        find_path(self.by_ref().map(|(edge, _)| edge), target.as_ref())
+    }
+
+    fn restart(&mut self) {
+        self.queue.clear();
+        self.best.clear();
+        self.finished.clear();
+        self.best
+            .insert(self.root.key().clone(), self.initial.clone());
+        self.queue.push(Scored {
+            score: self.initial.clone(),
+            node: self.root.clone(),
+            edge: None,
+            priority: self.priority,
+        });
+        self.state = State::Running;
+    }
+
+    // Returns true if `a` is a better priority than `b`.
+    fn is_better(&self, a: &P, b: &P) -> bool {
+        let ordering = match self.priority {
+            Priority::Min => Ordering::Less,
+            Priority::Max => Ordering::Greater,
+        };
+        a.partial_cmp(b) == Some(ordering)
+    }
+}
+
+impl<'a, G, P> Iterator for PfsBy<'a, G, P>
+where
+    G: Neighbors,
+    P: Clone + PartialOrd,
+{
+    type Item = (G::Edge, P);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.state {
+            State::Ready => self.restart(),
+            State::Running => {}
+            State::Done => return None,
+        }
+        while let Some(Scored {
+            score, node, edge, ..
+        }) = self.queue.pop()
+        {
+            // A node may be queued several times as its priority improves,
+            // only the first entry taken from the queue is used.
+            if !self.finished.insert(node.key().clone()) {
+                continue;
+            }
+            if let Some(ref edge) = edge {
+                if self.target.as_ref() == Some(node.key()) {
+                    self.state = State::Done;
+                    return Some((edge.clone(), score));
+                }
+            }
+            for next in self.transpose.adjacent(&node) {
+                match self.pipeline.exec(&next) {
+                    Control::Continue => {}
+                    Control::Prune => continue,
+                    Control::Stop => {
+                        self.state = State::Done;
+                        return None;
+                    }
+                }
+                let v = next.target();
+                if self.finished.contains(v.key()) {
+                    continue;
+                }
+                let candidate = (self.priority_fn)(&next, &score);
+                let improves = match self.best.get(v.key()) {
+                    Some(best) => self.is_better(&candidate, best),
+                    None => true,
+                };
+                if improves {
+                    self.best.insert(v.key().clone(), candidate.clone());
+                    self.queue.push(Scored {
+                        score: candidate,
+                        node: v.clone(),
+                        edge: Some(next),
+                        priority: self.priority,
+                    });
+                }
+            }
+            if let Some(edge) = edge {
+                return Some((edge, score));
+            }
+        }
+        self.state = State::Done;
+        None
+    }
+}
+
+// A queued node with the priority it was reached with and the edge it was
+// reached over. The ordering is reversed for a min-priority search so that
+// `BinaryHeap` pops the smallest priority first.
+struct Scored<G, P>
+where
+    G: GraphNode,
+{
+    score: P,
+    node: G,
+    edge: Option<G::Edge>,
+    priority: Priority,
+}
+
+impl<G, P> PartialEq for Scored<G, P>
+where
+    G: GraphNode,
+    P: PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<G, P> Eq for Scored<G, P>
+where
+    G: GraphNode,
+    P: PartialOrd,
+{
+}
+
+impl<G, P> PartialOrd for Scored<G, P>
+where
+    G: GraphNode,
+    P: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<G, P> Ord for Scored<G, P>
+where
+    G: GraphNode,
+    P: PartialOrd,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = self
+            .score
+            .partial_cmp(&other.score)
+            .unwrap_or(Ordering::Equal);
+        match self.priority {
+            Priority::Min => ordering.reverse(),
+            Priority::Max => ordering,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::traits::*;
+
+pub type Filter<'a, G> = &'a mut dyn FnMut(&<G as GraphNode>::Edge) -> bool;
+pub type ForEach<'a, G> = &'a mut dyn FnMut(&<G as GraphNode>::Edge);
+pub type Inspect<'a, G> = &'a mut dyn FnMut(&<G as GraphNode>::Edge) -> Control;
+
+/// Decides how a search proceeds after an edge has been inspected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    /// Follow the edge and keep searching.
+    Continue,
+    /// Do not follow the edge, the branch behind it is not explored
+    /// through this edge.
+    Prune,
+    /// End the search.
+    Stop,
+}
+
+enum Stage<'a, G>
+where
+    G: GraphNode,
+{
+    Filter(Filter<'a, G>),
+    ForEach(ForEach<'a, G>),
+    Inspect(Inspect<'a, G>),
+}
+
+// The closures attached to a search. Every edge a search encounters is
+// passed through the stages in the order they were added until one of
+// them prunes the edge or stops the search.
+pub struct Pipeline<'a, G>
+where
+    G: GraphNode,
+{
+    stages: Vec<Stage<'a, G>>,
+}
+
+impl<'a, G> Pipeline<'a, G>
+where
+    G: GraphNode,
+{
+    pub fn new() -> Self {
+        Pipeline { stages: vec![] }
+    }
+
+    pub fn filter(&mut self, f: Filter<'a, G>) {
+        self.stages.push(Stage::Filter(f));
+    }
+
+    pub fn for_each(&mut self, f: ForEach<'a, G>) {
+        self.stages.push(Stage::ForEach(f));
+    }
+
+    pub fn inspect(&mut self, f: Inspect<'a, G>) {
+        self.stages.push(Stage::Inspect(f));
+    }
+
+    pub fn exec(&mut self, e: &G::Edge) -> Control {
+        for stage in self.stages.iter_mut() {
+            let control = match stage {
+                Stage::Filter(f) => match f(e) {
+                    true => Control::Continue,
+                    false => Control::Prune,
+                },
+                Stage::ForEach(f) => {
+                    f(e);
+                    Control::Continue
+                }
+                Stage::Inspect(f) => f(e),
+            };
+            if control != Control::Continue {
+                return control;
+            }
+        }
+        Control::Continue
+    }
+}
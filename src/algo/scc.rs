@@ -0,0 +1,139 @@
+use super::*;
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+/// Finds the strongly connected components of `graph` with an iterative
+/// version of Tarjan's algorithm, in time linear in the number of nodes and
+/// edges. Every node belongs to exactly one component; a node that is not
+/// on a cycle forms a component on its own. Edges to nodes outside of the
+/// graph are ignored.
+///
+/// The components are returned in topological order: an edge between
+/// nodes of different components always leads from an earlier component
+/// to a later one.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (0) => [1]
+///     (1) => [2]
+///     (2) => [1, 3]
+///     (3) => []
+/// ];
+///
+/// let components = gdsl::algo::strongly_connected_components(&g);
+///
+/// assert!(components.len() == 3);
+/// assert!(components[0] == vec![g[0].clone()]);
+/// assert!(components[1].len() == 2);
+/// assert!(components[2] == vec![g[3].clone()]);
+/// ```
+pub fn strongly_connected_components<C, G>(graph: &C) -> Vec<Vec<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let mut tarjan = Tarjan {
+        links: HashMap::default(),
+        stack: vec![],
+        on_stack: HashSet::default(),
+    };
+    let mut components = vec![];
+
+    for root in graph.nodes() {
+        if tarjan.links.contains_key(root.key()) {
+            continue;
+        }
+        // Each frame holds a node on the current search path along with
+        // its remaining outbound edges, like the call stack of the
+        // recursive algorithm.
+        let mut frames = vec![tarjan.discover(root)];
+
+        while let Some((node, adjacent)) = frames.last_mut() {
+            match adjacent.next() {
+                Some(edge) => {
+                    let v = edge.target();
+                    if !graph.contains(v.key()) {
+                        continue;
+                    }
+                    match tarjan.links.get(v.key()) {
+                        Some(&(index, _)) => {
+                            if tarjan.on_stack.contains(v.key()) {
+                                tarjan.lower(node.key(), index);
+                            }
+                        }
+                        None => {
+                            let frame = tarjan.discover(v);
+                            frames.push(frame);
+                        }
+                    }
+                }
+                None => {
+                    let (node, _) = frames.pop().unwrap();
+                    let (index, low) = tarjan.links[node.key()];
+                    if let Some((parent, _)) = frames.last() {
+                        tarjan.lower(parent.key(), low);
+                    }
+                    // A node that can not reach an earlier node on the
+                    // stack is the root of a component made up of all
+                    // nodes pushed after it.
+                    if index == low {
+                        components.push(tarjan.pop_component(&node));
+                    }
+                }
+            }
+        }
+    }
+    // Tarjan's algorithm completes a component only after all components
+    // reachable from it, i.e. in reverse topological order.
+    components.reverse();
+    components
+}
+
+// The state of Tarjan's algorithm: the discovery index and the lowest
+// index reachable from each discovered node, and the stack of nodes not
+// yet assigned to a component.
+struct Tarjan<G>
+where
+    G: Neighbors,
+{
+    links: HashMap<G::Key, (usize, usize)>,
+    stack: Vec<G>,
+    on_stack: HashSet<G::Key>,
+}
+
+impl<G> Tarjan<G>
+where
+    G: Neighbors,
+{
+    fn discover(&mut self, node: &G) -> (G, G::Outbound) {
+        let index = self.links.len();
+        self.links.insert(node.key().clone(), (index, index));
+        self.on_stack.insert(node.key().clone());
+        self.stack.push(node.clone());
+        (node.clone(), node.outbound())
+    }
+
+    fn lower(&mut self, key: &G::Key, index: usize) {
+        let low = &mut self.links.get_mut(key).unwrap().1;
+        *low = (*low).min(index);
+    }
+
+    fn pop_component(&mut self, root: &G) -> Vec<G> {
+        let mut component = vec![];
+        loop {
+            let node = self.stack.pop().unwrap();
+            self.on_stack.remove(node.key());
+            let done = node.key() == root.key();
+            component.push(node);
+            if done {
+                break;
+            }
+        }
+        component.reverse();
+        component
+    }
+}
@@ -0,0 +1,413 @@
+use super::{path::*, *};
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    fmt::{Debug, Display},
+};
+
+/// The result of a single-source shortest path search.
+///
+/// Distances are kept in a side table keyed by node key, so the node values
+/// are left untouched. Every reached node other than the source has a
+/// predecessor: the last edge on a shortest path from the source to it.
+/// Together these edges form the shortest path tree.
+pub struct ShortestPaths<G, W>
+where
+    G: GraphNode,
+{
+    source: G,
+    distances: HashMap<G::Key, W>,
+    predecessors: HashMap<G::Key, G::Edge>,
+}
+
+impl<G, W> ShortestPaths<G, W>
+where
+    G: GraphNode,
+    W: Weight,
+{
+    pub(crate) fn new(source: &G) -> Self {
+        let mut distances = HashMap::default();
+        distances.insert(source.key().clone(), W::default());
+        ShortestPaths {
+            source: source.clone(),
+            distances,
+            predecessors: HashMap::default(),
+        }
+    }
+
+    // Records `edge` as the predecessor of its target if it improves the
+    // target's distance. Returns true if the distance was improved.
+    pub(crate) fn relax(&mut self, edge: &G::Edge, weight: W) -> bool {
+        let distance = match self.distances.get(edge.source().key()) {
+            Some(&distance) => distance + weight,
+            None => return false,
+        };
+        let key = edge.target().key();
+        match self.distances.get(key) {
+            Some(&current) if distance.partial_cmp(&current) != Some(Ordering::Less) => false,
+            _ => {
+                self.distances.insert(key.clone(), distance);
+                self.predecessors.insert(key.clone(), edge.clone());
+                true
+            }
+        }
+    }
+
+    // Finds a cycle in the predecessor edges. The predecessors form a tree
+    // rooted at the source unless a negative cycle has been relaxed, in
+    // which case every cycle among them has a negative length.
+    pub(crate) fn predecessor_cycle(&self) -> Option<Path<G>> {
+        let mut walked = HashMap::default();
+        for (walk, start) in self.predecessors.keys().enumerate() {
+            let mut key = start;
+            while let Some(edge) = self.predecessors.get(key) {
+                match walked.get(key) {
+                    Some(&w) if w == walk => return Some(self.cycle_through(key)),
+                    Some(_) => break,
+                    None => {
+                        walked.insert(key.clone(), walk);
+                    }
+                }
+                key = edge.source().key();
+            }
+        }
+        None
+    }
+
+    // Collects the predecessor cycle passing through the node with the
+    // given key.
+    fn cycle_through(&self, key: &G::Key) -> Path<G> {
+        let mut edges = vec![];
+        let mut current = key;
+        loop {
+            let edge = &self.predecessors[current];
+            edges.push(edge.clone());
+            current = edge.source().key();
+            if current == key {
+                break;
+            }
+        }
+        edges.reverse();
+        Path { edges }
+    }
+
+    /// Returns the source node of the search.
+    pub fn source(&self) -> &G {
+        &self.source
+    }
+
+    /// Returns true if the node with the given key was reached.
+    pub fn is_reachable(&self, key: &G::Key) -> bool {
+        self.distances.contains_key(key)
+    }
+
+    /// Returns the length of a shortest path from the source to the node
+    /// with the given key, or `None` if the node was not reached.
+    pub fn distance_to(&self, key: &G::Key) -> Option<W> {
+        self.distances.get(key).copied()
+    }
+
+    /// Returns a shortest path from the source to the node with the given
+    /// key, or `None` if the node was not reached. The path to the source
+    /// itself contains no edges.
+    pub fn path_to(&self, key: &G::Key) -> Option<Path<G>> {
+        if !self.is_reachable(key) {
+            return None;
+        }
+        let mut edges = vec![];
+        let mut key = key;
+        while let Some(edge) = self.predecessors.get(key) {
+            edges.push(edge.clone());
+            key = edge.source().key();
+        }
+        edges.reverse();
+        Some(Path { edges })
+    }
+
+    /// Returns the last edge on a shortest path from the source to the node
+    /// with the given key. The source and unreached nodes have none.
+    pub fn predecessor(&self, key: &G::Key) -> Option<&G::Edge> {
+        self.predecessors.get(key)
+    }
+
+    /// Returns an iterator over the edges of the shortest path tree.
+    pub fn tree_edges(&self) -> impl Iterator<Item = &G::Edge> {
+        self.predecessors.values()
+    }
+
+    /// Returns an iterator over the keys of the reached nodes and their
+    /// distances from the source.
+    pub fn distances(&self) -> impl Iterator<Item = (&G::Key, &W)> {
+        self.distances.iter()
+    }
+}
+
+impl<G, W> Debug for ShortestPaths<G, W>
+where
+    G: GraphNode,
+    W: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let distances = self
+            .distances
+            .iter()
+            .map(|(key, distance)| (key.to_string(), distance));
+        f.debug_struct("ShortestPaths")
+            .field("source", &self.source.key().to_string())
+            .field("distances", &distances.collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Computes the shortest paths from `source` to every node reachable from
+/// it with Dijkstra's algorithm. The `weight` closure returns the length of
+/// an edge and must not return negative values.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (char) => [u64]
+///     ('A') => [ ('B', 4), ('C', 1) ]
+///     ('B') => [ ('D', 1) ]
+///     ('C') => [ ('B', 2), ('D', 5) ]
+///     ('D') => []
+/// ];
+///
+/// let paths = gdsl::algo::dijkstra(&g['A'], |Edge(_, _, e)| *e);
+///
+/// assert!(paths.distance_to(&'D') == Some(4));
+///
+/// let keys = paths
+///     .path_to(&'D')
+///     .unwrap()
+///     .iter_nodes()
+///     .map(|node| *node.key())
+///     .collect::<Vec<_>>();
+///
+/// assert!(keys == vec!['A', 'C', 'B', 'D']);
+/// ```
+pub fn dijkstra<G, W, F>(source: &G, mut weight: F) -> ShortestPaths<G, W>
+where
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let mut paths = ShortestPaths::new(source);
+    let mut settled = HashSet::default();
+    let mut queue = BinaryHeap::new();
+
+    queue.push(MinScored(W::default(), source.clone()));
+
+    while let Some(MinScored(_, u)) = queue.pop() {
+        if !settled.insert(u.key().clone()) {
+            continue;
+        }
+        for edge in u.outbound() {
+            let v = edge.target();
+            if !settled.contains(v.key()) && paths.relax(&edge, weight(&edge)) {
+                let distance = paths.distance_to(v.key()).unwrap();
+                queue.push(MinScored(distance, v.clone()));
+            }
+        }
+    }
+    paths
+}
+
+/// A negative cycle reachable from the source of a shortest path search.
+/// Shortest paths are undefined in its presence, so the search returns the
+/// cycle instead.
+pub struct NegativeCycle<G>
+where
+    G: GraphNode,
+{
+    cycle: Path<G>,
+}
+
+impl<G> NegativeCycle<G>
+where
+    G: GraphNode,
+{
+    pub(crate) fn new(cycle: Path<G>) -> Self {
+        NegativeCycle { cycle }
+    }
+
+    /// Returns the cycle. The path starts and ends at the same node.
+    pub fn cycle(&self) -> &Path<G> {
+        &self.cycle
+    }
+
+    /// Consumes the error and returns the cycle.
+    pub fn into_cycle(self) -> Path<G> {
+        self.cycle
+    }
+}
+
+impl<G> Display for NegativeCycle<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Negative cycle: {}", self.cycle)
+    }
+}
+
+impl<G> Debug for NegativeCycle<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<G> std::error::Error for NegativeCycle<G> where G: GraphNode {}
+
+/// Computes the shortest paths from `source` to every node reachable from
+/// it with the Bellman-Ford algorithm. Unlike [`dijkstra`], the `weight`
+/// closure may return negative values. If a negative cycle is reachable
+/// from the source, it is returned as the error.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (char) => [i64]
+///     ('A') => [ ('B', 4), ('C', 2) ]
+///     ('B') => [ ('D', -3) ]
+///     ('C') => [ ('B', -1), ('D', 3) ]
+///     ('D') => []
+/// ];
+///
+/// let paths = gdsl::algo::bellman_ford(&g['A'], |Edge(_, _, e)| *e).unwrap();
+///
+/// assert!(paths.distance_to(&'B') == Some(1));
+/// assert!(paths.distance_to(&'D') == Some(-2));
+///
+/// g['D'].connect(&g['C'], 1);
+///
+/// let cycle = gdsl::algo::bellman_ford(&g['A'], |Edge(_, _, e)| *e)
+///     .unwrap_err()
+///     .into_cycle();
+///
+/// let length = cycle.iter_edges().map(|Edge(_, _, e)| e).sum::<i64>();
+/// assert!(length == -3);
+/// assert!(cycle.first_edge().unwrap().source() == cycle.last_edge().unwrap().target());
+/// ```
+pub fn bellman_ford<G, W, F>(
+    source: &G,
+    mut weight: F,
+) -> Result<ShortestPaths<G, W>, NegativeCycle<G>>
+where
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let nodes = reachable(source);
+    let edges = nodes
+        .iter()
+        .flat_map(|node| node.outbound())
+        .map(|edge| {
+            let w = weight(&edge);
+            (edge, w)
+        })
+        .collect::<Vec<_>>();
+
+    let mut paths = ShortestPaths::new(source);
+
+    for _ in 1..nodes.len() {
+        let mut relaxed = false;
+        for (edge, w) in &edges {
+            relaxed |= paths.relax(edge, *w);
+        }
+        if !relaxed {
+            return Ok(paths);
+        }
+    }
+    for (edge, w) in &edges {
+        if paths.relax(edge, *w) {
+            let cycle = paths.predecessor_cycle().unwrap();
+            return Err(NegativeCycle { cycle });
+        }
+    }
+    Ok(paths)
+}
+
+/// Computes the shortest paths from `source` with the queue-based variant
+/// of Bellman-Ford known as the Shortest Path Faster Algorithm. Only the
+/// edges of nodes whose distance has changed are relaxed again, which is
+/// usually much faster on sparse graphs. Results are the same as for
+/// [`bellman_ford`], the `weight` closure may return negative values and a
+/// reachable negative cycle is returned as the error.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// // Exchange rates as negative logarithms, a negative cycle is an
+/// // arbitrage opportunity.
+/// let g = gdsl::digraph![
+///     (&str) => [f64]
+///     ("USD") => [ ("EUR", -(0.9f64).ln()) ]
+///     ("EUR") => [ ("GBP", -(0.9f64).ln()) ]
+///     ("GBP") => [ ("USD", -(1.3f64).ln()) ]
+/// ];
+///
+/// let cycle = gdsl::algo::spfa(&g["USD"], |Edge(_, _, e)| *e).unwrap_err();
+///
+/// assert!(cycle.cycle().len() == 4);
+/// ```
+pub fn spfa<G, W, F>(source: &G, mut weight: F) -> Result<ShortestPaths<G, W>, NegativeCycle<G>>
+where
+    G: Neighbors,
+    W: Weight,
+    F: FnMut(&G::Edge) -> W,
+{
+    let n = reachable(source).len();
+    let mut paths = ShortestPaths::new(source);
+    let mut queue = VecDeque::new();
+    let mut queued = HashSet::default();
+    let mut relaxations = 0;
+
+    queue.push_back(source.clone());
+    queued.insert(source.key().clone());
+
+    while let Some(u) = queue.pop_front() {
+        queued.remove(u.key());
+        for edge in u.outbound() {
+            if paths.relax(&edge, weight(&edge)) {
+                // A negative cycle keeps the queue from ever running empty,
+                // but it eventually shows up among the predecessor edges.
+                // Looking for it after every `n` relaxations keeps the
+                // amortized cost of the check constant.
+                relaxations += 1;
+                if relaxations % n == 0 {
+                    if let Some(cycle) = paths.predecessor_cycle() {
+                        return Err(NegativeCycle { cycle });
+                    }
+                }
+                let v = edge.target();
+                if queued.insert(v.key().clone()) {
+                    queue.push_back(v.clone());
+                }
+            }
+        }
+    }
+    Ok(paths)
+}
+
+// Returns the nodes reachable from `source`, including the source.
+fn reachable<G>(source: &G) -> Vec<G>
+where
+    G: Neighbors,
+{
+    let mut nodes = vec![source.clone()];
+    nodes.extend(Bfs::new(source).map(|edge| edge.target().clone()));
+    nodes
+}
@@ -0,0 +1,350 @@
+use super::{path::*, *};
+use ahash::AHashMap as HashMap;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    fmt::{Debug, Display},
+};
+
+/// A cycle that prevents a graph from being topologically ordered.
+pub struct CycleError<G>
+where
+    G: GraphNode,
+{
+    cycle: Path<G>,
+}
+
+impl<G> CycleError<G>
+where
+    G: GraphNode,
+{
+    pub(crate) fn new(cycle: Path<G>) -> Self {
+        CycleError { cycle }
+    }
+
+    /// Returns the cycle. The path starts and ends at the same node.
+    pub fn cycle(&self) -> &Path<G> {
+        &self.cycle
+    }
+
+    /// Consumes the error and returns the cycle.
+    pub fn into_cycle(self) -> Path<G> {
+        self.cycle
+    }
+}
+
+impl<G> Display for CycleError<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cycle: {}", self.cycle)
+    }
+}
+
+impl<G> Debug for CycleError<G>
+where
+    G: GraphNode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<G> std::error::Error for CycleError<G> where G: GraphNode {}
+
+// Counts the inbound edges of every node that come from nodes within the
+// graph.
+pub(crate) fn in_degrees<C, G>(graph: &C) -> HashMap<G::Key, usize>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    graph
+        .nodes()
+        .map(|node| {
+            let degree = node
+                .inbound()
+                .filter(|edge| graph.contains(edge.source().key()))
+                .count();
+            (node.key().clone(), degree)
+        })
+        .collect()
+}
+
+// Finds a cycle among the nodes with a remaining in-degree after Kahn's
+// algorithm has run out of nodes without inbound edges. Every such node
+// has an inbound edge from another such node, so walking those edges
+// backwards must eventually return to a node already seen.
+pub(crate) fn remaining_cycle<C, G>(graph: &C, degrees: &HashMap<G::Key, usize>) -> Path<G>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let remaining = |key: &G::Key| degrees.get(key).is_some_and(|&d| d > 0);
+
+    let mut node = graph
+        .nodes()
+        .find(|node| remaining(node.key()))
+        .unwrap()
+        .clone();
+    let mut seen = HashMap::default();
+    let mut walk = vec![];
+
+    loop {
+        seen.insert(node.key().clone(), walk.len());
+        let edge = node
+            .inbound()
+            .find(|edge| remaining(edge.source().key()))
+            .unwrap();
+        node = edge.source().clone();
+        walk.push(edge);
+        if let Some(&start) = seen.get(node.key()) {
+            let mut edges = walk.split_off(start);
+            edges.reverse();
+            return Path { edges };
+        }
+    }
+}
+
+/// Sorts the nodes of `graph` topologically with Kahn's algorithm, so that
+/// for every edge `(u, v)` the node `u` comes before `v`. Edges to nodes
+/// outside of the graph are ignored. Nodes without a mutual order appear
+/// in no particular order. If the graph contains a cycle, one is returned
+/// as the error.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (0) => [1, 2]
+///     (1) => [3]
+///     (2) => [3]
+///     (3) => []
+/// ];
+///
+/// let order = gdsl::algo::toposort(&g).unwrap();
+///
+/// assert!(order[0] == g[0]);
+/// assert!(order[3] == g[3]);
+///
+/// g[3].connect(&g[0], ());
+///
+/// let error = gdsl::algo::toposort(&g).err().unwrap();
+/// assert!(error.cycle().len() == 4);
+/// ```
+pub fn toposort<C, G>(graph: &C) -> Result<Vec<G>, CycleError<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let mut degrees = in_degrees(graph);
+    let mut queue = graph
+        .nodes()
+        .filter(|node| degrees[node.key()] == 0)
+        .cloned()
+        .collect::<VecDeque<_>>();
+    let mut order = Vec::with_capacity(graph.len());
+
+    while let Some(node) = queue.pop_front() {
+        for edge in node.outbound() {
+            let v = edge.target();
+            if let Some(degree) = degrees.get_mut(v.key()) {
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(v.clone());
+                }
+            }
+        }
+        order.push(node);
+    }
+    match order.len() == graph.len() {
+        true => Ok(order),
+        false => Err(CycleError::new(remaining_cycle(graph, &degrees))),
+    }
+}
+
+/// Sorts the nodes of `graph` topologically like [`toposort`], but breaks
+/// ties by key: of all nodes whose predecessors have been placed, the one
+/// with the smallest key comes first. The result is the lexicographically
+/// smallest topological order and does not depend on the iteration order
+/// of the graph.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (5) => [0, 2]
+///     (4) => [0, 1]
+///     (2) => [3]
+///     (3) => [1]
+///     (0) => []
+///     (1) => []
+/// ];
+///
+/// let keys = gdsl::algo::toposort_lexicographic(&g)
+///     .unwrap()
+///     .iter()
+///     .map(|node| *node.key())
+///     .collect::<Vec<_>>();
+///
+/// assert!(keys == vec![4, 5, 0, 2, 3, 1]);
+/// ```
+pub fn toposort_lexicographic<C, G>(graph: &C) -> Result<Vec<G>, CycleError<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+    G::Key: Ord,
+{
+    let mut degrees = in_degrees(graph);
+    let mut queue = graph
+        .nodes()
+        .filter(|node| degrees[node.key()] == 0)
+        .map(|node| Reverse(node.key().clone()))
+        .collect::<BinaryHeap<_>>();
+    let mut order = Vec::with_capacity(graph.len());
+
+    while let Some(Reverse(key)) = queue.pop() {
+        let node = graph.get(&key).unwrap();
+        for edge in node.outbound() {
+            let v = edge.target();
+            if let Some(degree) = degrees.get_mut(v.key()) {
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push(Reverse(v.key().clone()));
+                }
+            }
+        }
+        order.push(node);
+    }
+    match order.len() == graph.len() {
+        true => Ok(order),
+        false => Err(CycleError::new(remaining_cycle(graph, &degrees))),
+    }
+}
+
+/// Splits the nodes of `graph` into generations, so that every node only
+/// has inbound edges from nodes of earlier generations. Each node is placed
+/// in the earliest generation possible: the first one holds the nodes
+/// without inbound edges and a node's generation is the length of the
+/// longest path leading to it. The nodes of a generation appear in no
+/// particular order. If the graph contains a cycle, one is returned as the
+/// error.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (0) => [2]
+///     (1) => [2, 3]
+///     (2) => [4]
+///     (3) => []
+///     (4) => []
+/// ];
+///
+/// let mut generations = gdsl::algo::topological_generations(&g).unwrap();
+/// for generation in generations.iter_mut() {
+///     generation.sort_by_key(|node| *node.key());
+/// }
+///
+/// assert!(generations == vec![
+///     vec![g[0].clone(), g[1].clone()],
+///     vec![g[2].clone(), g[3].clone()],
+///     vec![g[4].clone()],
+/// ]);
+/// ```
+pub fn topological_generations<C, G>(graph: &C) -> Result<Vec<Vec<G>>, CycleError<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let mut degrees = in_degrees(graph);
+    let mut generation = graph
+        .nodes()
+        .filter(|node| degrees[node.key()] == 0)
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut generations = vec![];
+    let mut count = 0;
+
+    while !generation.is_empty() {
+        let mut next = vec![];
+        for node in &generation {
+            for edge in node.outbound() {
+                let v = edge.target();
+                if let Some(degree) = degrees.get_mut(v.key()) {
+                    *degree -= 1;
+                    if *degree == 0 {
+                        next.push(v.clone());
+                    }
+                }
+            }
+        }
+        count += generation.len();
+        generations.push(std::mem::replace(&mut generation, next));
+    }
+    match count == graph.len() {
+        true => Ok(generations),
+        false => Err(CycleError::new(remaining_cycle(graph, &degrees))),
+    }
+}
+
+/// Splits the nodes of `graph` into generations like
+/// [`topological_generations`], but places each node in the latest
+/// generation possible: the last one holds the nodes without outbound
+/// edges and a node is placed just before the earliest of its successors.
+/// Both variants produce the same number of generations.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::digraph::*;
+///
+/// let g = gdsl::digraph![
+///     (usize)
+///     (0) => [1]
+///     (1) => [2]
+///     (2) => []
+///     (3) => [2]
+/// ];
+///
+/// let earliest = gdsl::algo::topological_generations(&g).unwrap();
+/// let latest = gdsl::algo::topological_generations_latest(&g).unwrap();
+///
+/// // Node 3 is only needed right before node 2.
+/// assert!(earliest[0].contains(&g[3]));
+/// assert!(latest[1].contains(&g[3]));
+/// ```
+pub fn topological_generations_latest<C, G>(graph: &C) -> Result<Vec<Vec<G>>, CycleError<G>>
+where
+    C: GraphContainer<Node = G>,
+    G: Neighbors,
+{
+    let earliest = topological_generations(graph)?;
+    let last = earliest.len().saturating_sub(1);
+
+    // In reverse topological order the successors of a node are placed
+    // before the node itself.
+    let mut layers = HashMap::<G::Key, usize>::default();
+    let mut generations = vec![vec![]; earliest.len()];
+    for node in earliest.into_iter().flatten().rev() {
+        let layer = node
+            .outbound()
+            .filter_map(|edge| layers.get(edge.target().key()))
+            .map(|&layer| layer - 1)
+            .min()
+            .unwrap_or(last);
+        layers.insert(node.key().clone(), layer);
+        generations[layer].push(node);
+    }
+    Ok(generations)
+}
@@ -0,0 +1,89 @@
+/// A disjoint-set forest over the elements `0..n`.
+///
+/// Sets are merged by rank and paths are halved on every lookup, so any
+/// sequence of operations runs in nearly linear time.
+///
+/// # Example
+///
+/// ```
+/// use gdsl::algo::UnionFind;
+///
+/// let mut sets = UnionFind::new(4);
+///
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(2, 3));
+/// assert!(!sets.union(1, 0));
+///
+/// assert!(sets.equiv(0, 1));
+/// assert!(!sets.equiv(1, 2));
+/// assert!(sets.count() == 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Creates `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            count: n,
+        }
+    }
+
+    /// Returns the representative of the set containing `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of bounds.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing `x` and `y`. Returns false if they were
+    /// already the same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (x, y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        let (x, y) = match self.rank[x] < self.rank[y] {
+            true => (y, x),
+            false => (x, y),
+        };
+        self.parent[y] = x;
+        if self.rank[x] == self.rank[y] {
+            self.rank[x] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    /// Returns true if `x` and `y` are in the same set.
+    pub fn equiv(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Returns the amount of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the amount of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+}
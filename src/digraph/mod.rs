@@ -6,7 +6,7 @@
 //! - `Graph` is a container type for a directed graph.
 //! - `Node` is a node type for a directed graph.
 //! - An edge is denoted by a tuple struct `Edge(u, v, e)` where`u` and `v` are
-//! the source and target node and `e` is the edge parameter.
+//!   the source and target node and `e` is the edge parameter.
 //!
 //! # Example
 //!
@@ -45,7 +45,8 @@ mod graph_serde;
 mod node;
 
 pub use crate::digraph::node::*;
-use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use crate::{algo, traits::GraphContainer};
+use ahash::AHashMap as HashMap;
 use std::{
     fmt::{Display, Write},
     hash::Hash,
@@ -346,8 +347,803 @@ where
         self.nodes.iter()
     }
 
+    /// Computes the shortest paths from the node with the key `source` to
+    /// every node reachable from it using Dijkstra's algorithm, see
+    /// [`Node::dijkstra`]. Returns `None` if the source is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g.dijkstra(&'A', |Edge(_, _, e)| *e).unwrap();
+    ///
+    /// assert!(paths.distance_to(&'D') == Some(4));
+    /// assert!(paths.predecessor(&'D').unwrap().source() == &g['B']);
+    /// assert!(g.dijkstra(&'X', |Edge(_, _, e)| *e).is_none());
+    /// ```
+    pub fn dijkstra<W, F>(&self, source: &K, weight: F) -> Option<ShortestPaths<K, N, E, W>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        self.nodes.get(source).map(|node| node.dijkstra(weight))
+    }
+
+    /// Computes the shortest paths from the node with the key `source`
+    /// using the Bellman-Ford algorithm, see [`Node::bellman_ford`]. The
+    /// `weight` closure may return negative values. Returns `None` if the
+    /// source is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 4), ('C', 2) ]
+    ///     ('B') => [ ('D', -3) ]
+    ///     ('C') => [ ('B', -1) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g.bellman_ford(&'A', |Edge(_, _, e)| *e).unwrap().unwrap();
+    ///
+    /// assert!(paths.distance_to(&'D') == Some(-2));
+    ///
+    /// g['D'].connect(&g['C'], 1);
+    ///
+    /// assert!(g.bellman_ford(&'A', |Edge(_, _, e)| *e).unwrap().is_err());
+    /// ```
+    pub fn bellman_ford<W, F>(
+        &self,
+        source: &K,
+        weight: F,
+    ) -> Option<Result<ShortestPaths<K, N, E, W>, NegativeCycle<K, N, E>>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        self.nodes.get(source).map(|node| node.bellman_ford(weight))
+    }
+
+    /// Computes the shortest paths from the node with the key `source`
+    /// using the Shortest Path Faster Algorithm, see [`Node::spfa`].
+    /// Returns `None` if the source is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 4), ('C', 2) ]
+    ///     ('B') => [ ('D', -3) ]
+    ///     ('C') => [ ('B', -1) ]
+    ///     ('D') => [ ('C', 1) ]
+    /// ];
+    ///
+    /// let cycle = g.spfa(&'A', |Edge(_, _, e)| *e).unwrap().unwrap_err();
+    ///
+    /// assert!(cycle.cycle().len() == 4);
+    /// ```
+    pub fn spfa<W, F>(
+        &self,
+        source: &K,
+        weight: F,
+    ) -> Option<Result<ShortestPaths<K, N, E, W>, NegativeCycle<K, N, E>>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        self.nodes.get(source).map(|node| node.spfa(weight))
+    }
+
+    /// Computes the shortest paths between all pairs of nodes with the
+    /// Floyd-Warshall algorithm, which suits dense graphs. The `weight`
+    /// closure may return negative values. If the graph contains a negative
+    /// cycle, it is returned as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 3), ('C', 8) ]
+    ///     ('B') => [ ('C', -2) ]
+    ///     ('C') => [ ('A', 1) ]
+    ///     ('D') => [ ('A', 2) ]
+    /// ];
+    ///
+    /// let table = g.floyd_warshall(|Edge(_, _, e)| *e).unwrap();
+    ///
+    /// assert!(table.distance(&'A', &'C') == Some(1));
+    /// assert!(table.distance(&'C', &'B') == Some(4));
+    /// assert!(table.distance(&'A', &'D').is_none());
+    ///
+    /// let path = table.path(&'D', &'C').unwrap().to_vec_nodes();
+    /// assert!(path == vec![g['D'].clone(), g['A'].clone(), g['B'].clone(), g['C'].clone()]);
+    /// ```
+    pub fn floyd_warshall<W, F>(
+        &self,
+        weight: F,
+    ) -> Result<DistanceTable<K, N, E, W>, NegativeCycle<K, N, E>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::floyd_warshall(self, weight)
+    }
+
+    /// Computes the shortest paths between all pairs of nodes with
+    /// Johnson's algorithm, which is faster than Floyd-Warshall on sparse
+    /// graphs. The `weight` closure may return negative values. If the
+    /// graph contains a negative cycle, it is returned as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 3), ('C', 8) ]
+    ///     ('B') => [ ('C', -2) ]
+    ///     ('C') => [ ('A', 1) ]
+    ///     ('D') => [ ('A', 2) ]
+    /// ];
+    ///
+    /// let table = g.johnson(|Edge(_, _, e)| *e).unwrap();
+    ///
+    /// assert!(table.distance(&'D', &'C') == Some(3));
+    ///
+    /// // The same distances as running Bellman-Ford from every node.
+    /// for (u, v, distance) in table.iter() {
+    ///     let paths = g.bellman_ford(u, |Edge(_, _, e)| *e).unwrap().unwrap();
+    ///     assert!(paths.distance_to(v) == Some(distance));
+    /// }
+    /// ```
+    pub fn johnson<W, F>(
+        &self,
+        weight: F,
+    ) -> Result<DistanceTable<K, N, E, W>, NegativeCycle<K, N, E>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::johnson(self, weight)
+    }
+
+    /// Computes a maximum flow from the node with the key `source` to the
+    /// node with the key `sink` with the Edmonds-Karp algorithm, see
+    /// [`algo::edmonds_karp`]. `capacity` returns the capacity of an edge.
+    /// The result holds the flow on every edge and a minimum cut. Returns
+    /// `None` if the source or the sink is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize) => [u64]
+    ///     (0) => [ (1, 16), (2, 13) ]
+    ///     (1) => [ (2, 10), (3, 12) ]
+    ///     (2) => [ (1, 4), (4, 14) ]
+    ///     (3) => [ (2, 9), (5, 20) ]
+    ///     (4) => [ (3, 7), (5, 4) ]
+    ///     (5) => []
+    /// ];
+    ///
+    /// let flow = g.edmonds_karp(&0, &5, |Edge(_, _, c)| *c).unwrap();
+    ///
+    /// assert!(flow.value() == 23);
+    ///
+    /// // The saturated edges of the minimum cut.
+    /// let cut = flow.cut_edges();
+    /// assert!(cut.iter().map(|Edge(_, _, c)| c).sum::<u64>() == 23);
+    /// assert!(flow.is_source_side(&4) && !flow.is_source_side(&3));
+    /// ```
+    pub fn edmonds_karp<W, F>(
+        &self,
+        source: &K,
+        sink: &K,
+        capacity: F,
+    ) -> Option<MaxFlow<K, N, E, W>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::edmonds_karp(self, source, sink, capacity)
+    }
+
+    /// Computes a maximum flow from the node with the key `source` to the
+    /// node with the key `sink` with Dinic's algorithm, see [`algo::dinic`].
+    /// `capacity` returns the capacity of an edge. Returns `None` if the
+    /// source or the sink is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [u32]
+    ///     ('s') => [ ('a', 10), ('b', 10) ]
+    ///     ('a') => [ ('b', 2), ('c', 4), ('d', 8) ]
+    ///     ('b') => [ ('d', 9) ]
+    ///     ('c') => [ ('t', 10) ]
+    ///     ('d') => [ ('c', 6), ('t', 10) ]
+    ///     ('t') => []
+    /// ];
+    ///
+    /// let flow = g.dinic(&'s', &'t', |Edge(_, _, c)| *c).unwrap();
+    ///
+    /// assert!(flow.value() == 19);
+    /// assert!(flow.flow(&'s', &'a') == Some(10));
+    /// ```
+    pub fn dinic<W, F>(&self, source: &K, sink: &K, capacity: F) -> Option<MaxFlow<K, N, E, W>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::dinic(self, source, sink, capacity)
+    }
+
+    /// Computes a maximum flow from the node with the key `source` to the
+    /// node with the key `sink` with the push-relabel algorithm, see
+    /// [`algo::push_relabel`]. `capacity` returns the capacity of an edge.
+    /// Returns `None` if the source or the sink is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [u32]
+    ///     ('s') => [ ('a', 10), ('b', 10) ]
+    ///     ('a') => [ ('b', 2), ('c', 4), ('d', 8) ]
+    ///     ('b') => [ ('d', 9) ]
+    ///     ('c') => [ ('t', 10) ]
+    ///     ('d') => [ ('c', 6), ('t', 10) ]
+    ///     ('t') => []
+    /// ];
+    ///
+    /// let flow = g.push_relabel(&'s', &'t', |Edge(_, _, c)| *c).unwrap();
+    /// let (source_side, sink_side) = flow.min_cut();
+    ///
+    /// assert!(flow.value() == 19);
+    /// assert!(source_side.len() + sink_side.len() == g.len());
+    /// ```
+    pub fn push_relabel<W, F>(
+        &self,
+        source: &K,
+        sink: &K,
+        capacity: F,
+    ) -> Option<MaxFlow<K, N, E, W>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::push_relabel(self, source, sink, capacity)
+    }
+
+    /// Computes a maximum flow of minimum cost from the node with the key
+    /// `source` to the node with the key `sink`, see
+    /// [`algo::min_cost_max_flow`]. `capacity` and `cost` return the
+    /// capacity of an edge and the cost per unit of flow on it. Returns
+    /// `None` if the source or the sink is not in the graph, and a cycle of
+    /// negative cost if one is reachable from the source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// // Ship goods from a depot to a store over roads with a limited
+    /// // amount of trucks per day and a cost per truck.
+    /// let g = gdsl::digraph![
+    ///     (&str) => [(i64, i64)]
+    ///     ("depot") => [ ("a", (4, 2)), ("b", (2, 2)) ]
+    ///     ("a") => [ ("b", (2, 1)), ("store", (2, 6)) ]
+    ///     ("b") => [ ("store", (5, 1)) ]
+    ///     ("store") => []
+    /// ];
+    ///
+    /// let flow = g
+    ///     .min_cost_max_flow(&"depot", &"store", |Edge(_, _, e)| e.0, |Edge(_, _, e)| e.1)
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// assert!(flow.value() == 6);
+    /// assert!(flow.cost() == 30);
+    /// assert!(flow.flow(&"a", &"b") == Some(2));
+    /// ```
+    pub fn min_cost_max_flow<W, F, H>(
+        &self,
+        source: &K,
+        sink: &K,
+        capacity: F,
+        cost: H,
+    ) -> Option<Result<MinCostFlow<K, N, E, W>, NegativeCycle<K, N, E>>>
+    where
+        W: Weight + std::ops::Mul<Output = W>,
+        F: FnMut(&Edge<K, N, E>) -> W,
+        H: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::min_cost_max_flow(self, source, sink, capacity, cost)
+    }
+
+    /// Computes a circulation of minimum cost in which every edge carries
+    /// at least `lower` and at most `capacity` units, see
+    /// [`algo::min_cost_circulation`]. `cost` returns the cost per unit of
+    /// flow on an edge. Returns `None` if the bounds can not be met.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// // A daily route that must be driven at least once, with an optional
+    /// // shortcut back.
+    /// let g = gdsl::digraph![
+    ///     (char) => [(i64, i64, i64)]
+    ///     ('A') => [ ('B', (1, 3, 4)) ]
+    ///     ('B') => [ ('C', (0, 3, 1)), ('A', (0, 3, 3)) ]
+    ///     ('C') => [ ('A', (0, 3, 1)) ]
+    /// ];
+    ///
+    /// let flow = g
+    ///     .min_cost_circulation(|Edge(_, _, e)| e.0, |Edge(_, _, e)| e.1, |Edge(_, _, e)| e.2)
+    ///     .unwrap();
+    ///
+    /// assert!(flow.cost() == 6);
+    /// assert!(flow.flow(&'B', &'C') == Some(1));
+    /// assert!(flow.flow(&'B', &'A') == Some(0));
+    ///
+    /// // A lower bound that can not be sent around.
+    /// let g = gdsl::digraph![
+    ///     (char) => [(i64, i64, i64)]
+    ///     ('A') => [ ('B', (2, 3, 1)) ]
+    ///     ('B') => [ ('A', (0, 1, 1)) ]
+    /// ];
+    ///
+    /// assert!(g
+    ///     .min_cost_circulation(|Edge(_, _, e)| e.0, |Edge(_, _, e)| e.1, |Edge(_, _, e)| e.2)
+    ///     .is_none());
+    /// ```
+    pub fn min_cost_circulation<W, L, F, H>(
+        &self,
+        lower: L,
+        capacity: F,
+        cost: H,
+    ) -> Option<MinCostFlow<K, N, E, W>>
+    where
+        W: Weight + std::ops::Mul<Output = W>,
+        L: FnMut(&Edge<K, N, E>) -> W,
+        F: FnMut(&Edge<K, N, E>) -> W,
+        H: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::min_cost_circulation(self, lower, capacity, cost)
+    }
+
+    /// Solves the assignment problem with the Hungarian algorithm: assigns
+    /// as many of the nodes for which `left` returns true as possible to
+    /// distinct other nodes they have an edge to, at the smallest total
+    /// `weight`. Edges must point from left to right. See
+    /// [`algo::hungarian`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (&str) => [i32]
+    ///     ("job1") => [ ("m1", 7), ("m2", 3) ]
+    ///     ("job2") => [ ("m1", 4) ]
+    ///     ("job3") => [ ("m2", 1) ]
+    ///     ("m1") => []
+    ///     ("m2") => []
+    /// ];
+    ///
+    /// let assignment = g.hungarian(|node| node.key().starts_with("job"), |Edge(_, _, e)| *e);
+    ///
+    /// assert!(assignment.len() == 2);
+    /// assert!(assignment.cost() == 5);
+    /// ```
+    pub fn hungarian<W, P, F>(&self, left: P, weight: F) -> Assignment<K, N, E, W>
+    where
+        W: Weight,
+        P: FnMut(&Node<K, N, E>) -> bool,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::hungarian(self, left, weight)
+    }
+
+    /// Computes the PageRank of every node: the probability that a surfer
+    /// who follows a random outbound edge with the probability `damping`,
+    /// and jumps to a random node otherwise, is found at the node. Iterates
+    /// until the scores change by less than `tolerance` in total, or at
+    /// most `max_iter` times. See [`algo::page_rank`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize)
+    ///     (0) => [1, 2]
+    ///     (1) => [2, 3]
+    ///     (2) => [0]
+    ///     (3) => []
+    /// ];
+    ///
+    /// let rank = g.page_rank(0.85, 1e-9, 100);
+    ///
+    /// assert!(rank[&0] > rank[&2] && rank[&2] > rank[&1] && rank[&1] > rank[&3]);
+    /// assert!((rank.values().sum::<f64>() - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn page_rank(&self, damping: f64, tolerance: f64, max_iter: usize) -> HashMap<K, f64> {
+        algo::page_rank(self, damping, tolerance, max_iter)
+    }
+
+    /// Computes the personalized PageRank of every node, where random jumps
+    /// land on a node with a probability proportional to its `teleport`
+    /// weight, zero for nodes missing from it. See [`algo::personalized_page_rank`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize)
+    ///     (0) => [1]
+    ///     (1) => [2]
+    ///     (2) => [0]
+    ///     (3) => [0]
+    /// ];
+    ///
+    /// let rank = g.personalized_page_rank(0.85, &[(1, 1.0)].into(), 1e-9, 100);
+    ///
+    /// assert!(rank[&1] > rank[&2] && rank[&2] > rank[&0]);
+    /// assert!(rank[&3] == 0.0);
+    /// ```
+    pub fn personalized_page_rank(
+        &self,
+        damping: f64,
+        teleport: &HashMap<K, f64>,
+        tolerance: f64,
+        max_iter: usize,
+    ) -> HashMap<K, f64> {
+        algo::personalized_page_rank(self, damping, teleport, tolerance, max_iter)
+    }
+
+    /// Computes the hub and authority scores of every node with the HITS
+    /// algorithm, iterating until the hub scores change by less than
+    /// `tolerance` in total, or at most `max_iter` times. Returns the hub
+    /// and the authority scores. See [`algo::hits`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize)
+    ///     (0) => [2, 3]
+    ///     (1) => [2]
+    ///     (2) => []
+    ///     (3) => []
+    /// ];
+    ///
+    /// let (hubs, authorities) = g.hits(1e-9, 100);
+    ///
+    /// assert!(hubs[&0] > hubs[&1] && hubs[&2] == 0.0);
+    /// assert!(authorities[&2] > authorities[&3] && authorities[&0] == 0.0);
+    /// ```
+    pub fn hits(&self, tolerance: f64, max_iter: usize) -> (HashMap<K, f64>, HashMap<K, f64>) {
+        algo::hits(self, tolerance, max_iter)
+    }
+
+    /// Prepares the computation of the betweenness centrality of every
+    /// node and edge with Brandes' algorithm, to be run with
+    /// [`Brandes::unweighted`] or [`Brandes::weighted`]. See
+    /// [`algo::Brandes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize) => [u32]
+    ///     (0) => [ (1, 1), (2, 4) ]
+    ///     (1) => [ (2, 1) ]
+    ///     (2) => [ (3, 1) ]
+    ///     (3) => []
+    /// ];
+    ///
+    /// let betweenness = g.brandes().weighted(|Edge(_, _, e)| *e);
+    ///
+    /// assert!(betweenness.node(&1) == Some(2.0));
+    /// assert!(betweenness.edge(&0, &2) == Some(0.0));
+    /// ```
+    pub fn brandes(&self) -> Brandes<K, N, E> {
+        algo::Brandes::new(self)
+    }
+
+    /// Computes the closeness centrality of every node, the inverse of the
+    /// average distance to the nodes it reaches scaled by the fraction of
+    /// nodes reached. See [`algo::closeness_centrality`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize)
+    ///     (0) => [1]
+    ///     (1) => [2]
+    ///     (2) => [0]
+    /// ];
+    ///
+    /// let closeness = g.closeness_centrality();
+    ///
+    /// assert!(closeness.values().all(|&c| c == closeness[&0]));
+    /// ```
+    pub fn closeness_centrality(&self) -> HashMap<K, f64> {
+        algo::closeness_centrality(self)
+    }
+
+    /// Computes the harmonic centrality of every node, the sum of the
+    /// inverse distances to all other nodes. See
+    /// [`algo::harmonic_centrality`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize)
+    ///     (0) => [1, 2]
+    ///     (1) => []
+    ///     (2) => []
+    /// ];
+    ///
+    /// let harmonic = g.harmonic_centrality();
+    ///
+    /// assert!(harmonic[&0] == 2.0);
+    /// ```
+    pub fn harmonic_centrality(&self) -> HashMap<K, f64> {
+        algo::harmonic_centrality(self)
+    }
+
+    /// Computes the eigenvector centrality of every node, weighting the
+    /// edges by `weight`. Iterates until the scores change by less than
+    /// `tolerance` in total, and fails if this takes more than `max_iter`
+    /// iterations. See [`algo::eigenvector_centrality`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize) => [f64]
+    ///     (0) => [ (1, 1.0), (2, 1.0) ]
+    ///     (1) => [ (2, 1.0) ]
+    ///     (2) => [ (0, 1.0) ]
+    /// ];
+    ///
+    /// let scores = g.eigenvector_centrality(|Edge(_, _, e)| *e, 1e-12, 1000).unwrap();
+    ///
+    /// assert!(scores[&2] > scores[&1]);
+    /// ```
+    pub fn eigenvector_centrality<F>(
+        &self,
+        weight: F,
+        tolerance: f64,
+        max_iter: usize,
+    ) -> Result<HashMap<K, f64>, NotConverged<K, N, E>>
+    where
+        F: FnMut(&Edge<K, N, E>) -> f64,
+    {
+        algo::eigenvector_centrality(self, weight, tolerance, max_iter)
+    }
+
+    /// Computes the Katz centrality of every node, the score of a node
+    /// being `beta` plus `alpha` times the weighted scores of the nodes
+    /// linking to it. Iterates until the scores change by less than
+    /// `tolerance` in total, and fails if this takes more than `max_iter`
+    /// iterations. See [`algo::katz_centrality`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (usize) => [f64]
+    ///     (0) => [ (1, 1.0) ]
+    ///     (1) => [ (2, 1.0) ]
+    ///     (2) => [ (0, 1.0) ]
+    /// ];
+    ///
+    /// let scores = g.katz_centrality(0.1, 1.0, |Edge(_, _, e)| *e, 1e-12, 100).unwrap();
+    ///
+    /// assert!(scores[&0] == scores[&1] && scores[&1] == scores[&2]);
+    /// assert!(g.katz_centrality(2.0, 1.0, |Edge(_, _, e)| *e, 1e-12, 100).is_err());
+    /// ```
+    pub fn katz_centrality<F>(
+        &self,
+        alpha: f64,
+        beta: f64,
+        weight: F,
+        tolerance: f64,
+        max_iter: usize,
+    ) -> Result<HashMap<K, f64>, NotConverged<K, N, E>>
+    where
+        F: FnMut(&Edge<K, N, E>) -> f64,
+    {
+        algo::katz_centrality(self, alpha, beta, weight, tolerance, max_iter)
+    }
+
+    /// Returns the nodes of the graph in topological order, so that every
+    /// node comes before the nodes it is connected to. Nodes without a
+    /// mutual order appear in no particular order. If the graph contains a
+    /// cycle, one is returned as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (&str)
+    ///     ("shirt") => ["tie", "belt"]
+    ///     ("tie") => ["jacket"]
+    ///     ("trousers") => ["belt", "shoes"]
+    ///     ("belt") => ["jacket"]
+    ///     ("socks") => ["shoes"]
+    ///     ("shoes") => []
+    ///     ("jacket") => []
+    /// ];
+    ///
+    /// let order = g.toposort().unwrap();
+    /// let position = |key| order.iter().position(|node| node.key() == &key);
+    ///
+    /// assert!(position("shirt") < position("tie"));
+    /// assert!(position("belt") < position("jacket"));
+    /// assert!(position("socks") < position("shoes"));
+    ///
+    /// g["jacket"].connect(&g["shirt"], ());
+    ///
+    /// let error = g.toposort().err().unwrap();
+    /// let cycle = error.cycle();
+    /// assert!(cycle.first_edge().unwrap().source() == cycle.last_edge().unwrap().target());
+    /// ```
+    pub fn toposort(&self) -> Result<Vec<Node<K, N, E>>, CycleError<K, N, E>> {
+        algo::toposort(self)
+    }
+
+    /// Returns the nodes of the graph in topological order like
+    /// [`Graph::toposort`], breaking ties by key. The order is the
+    /// lexicographically smallest one and is the same on every run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char)
+    ///     ('D') => ['B']
+    ///     ('C') => ['A']
+    ///     ('B') => []
+    ///     ('A') => []
+    /// ];
+    ///
+    /// let keys = g
+    ///     .toposort_lexicographic()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|node| *node.key())
+    ///     .collect::<String>();
+    ///
+    /// assert!(keys == "CADB");
+    /// ```
+    pub fn toposort_lexicographic(&self) -> Result<Vec<Node<K, N, E>>, CycleError<K, N, E>>
+    where
+        K: Ord,
+    {
+        algo::toposort_lexicographic(self)
+    }
+
+    /// Splits the nodes of the graph into generations that only depend on
+    /// earlier ones, so the nodes of a generation can be processed in
+    /// parallel once all earlier generations are done. Every node is placed
+    /// in the earliest generation possible. The nodes of a generation
+    /// appear in no particular order. If the graph contains a cycle, one is
+    /// returned as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (&str)
+    ///     ("fetch") => ["compile"]
+    ///     ("configure") => ["compile"]
+    ///     ("compile") => ["test", "package"]
+    ///     ("docs") => ["package"]
+    ///     ("test") => []
+    ///     ("package") => []
+    /// ];
+    ///
+    /// let generations = g.topological_generations().unwrap();
+    /// assert!(generations.len() == 3);
+    /// assert!(generations[0].len() == 3);
+    /// assert!(generations[0].contains(&g["docs"]));
+    ///
+    /// g["test"].connect(&g["fetch"], ());
+    /// assert!(g.topological_generations().is_err());
+    /// ```
+    pub fn topological_generations(&self) -> Result<Vec<Vec<Node<K, N, E>>>, CycleError<K, N, E>> {
+        algo::topological_generations(self)
+    }
+
+    /// Like `topological_generations`, but places every node in the latest
+    /// generation possible, i.e. right before it is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (&str)
+    ///     ("fetch") => ["compile"]
+    ///     ("configure") => ["compile"]
+    ///     ("compile") => ["test", "package"]
+    ///     ("docs") => ["package"]
+    ///     ("test") => []
+    ///     ("package") => []
+    /// ];
+    ///
+    /// let generations = g.topological_generations_latest().unwrap();
+    /// assert!(generations.len() == 3);
+    /// assert!(generations[0].len() == 2);
+    /// assert!(generations[1].contains(&g["docs"]));
+    /// ```
+    pub fn topological_generations_latest(
+        &self,
+    ) -> Result<Vec<Vec<Node<K, N, E>>>, CycleError<K, N, E>> {
+        algo::topological_generations_latest(self)
+    }
+
     /// Find the strongly connected components of the graph. Can be used to
-    /// find cycles in the graph and for topological sorting.
+    /// find cycles in the graph and for topological sorting. The components
+    /// are listed in topological order, see
+    /// [`algo::strongly_connected_components`](crate::algo::strongly_connected_components).
     ///
     /// # Examples
     ///
@@ -392,53 +1188,104 @@ where
     /// assert!(scc[3].len() == 3);
     /// ```
     pub fn scc(&self) -> Vec<Vec<Node<K, N, E>>> {
-        let mut invariant = HashSet::new();
-        let mut components = Vec::new();
-        let mut ordering = self.scc_ordering();
-
-        while let Some(node) = ordering.pop() {
-            if !invariant.contains(node.key()) {
-                let cycle = node
-                    .dfs()
-                    .transpose()
-                    .filter(&mut |Edge(_, v, _)| !invariant.contains(v.key()))
-                    .search_cycle();
-                match cycle {
-                    Some(cycle) => {
-                        let mut cycle = cycle.to_vec_nodes();
-                        cycle.pop();
-                        for node in &cycle {
-                            invariant.insert(node.key().clone());
+        algo::strongly_connected_components(self)
+    }
+
+    /// Builds the condensation of the graph: a new, acyclic graph with a
+    /// node for each strongly connected component. The node with key `i`
+    /// holds the members of the `i`-th component returned by
+    /// [`Graph::scc`], so the keys follow a topological order. Two
+    /// components are connected if an edge leads from a member of the one
+    /// to a member of the other; the edge holds all such edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char)
+    ///     ('A') => ['B']
+    ///     ('B') => ['A', 'C', 'D']
+    ///     ('C') => ['D']
+    ///     ('D') => ['C']
+    /// ];
+    ///
+    /// let c = g.condensation();
+    ///
+    /// assert!(c.len() == 2);
+    /// assert!(c[0].value().contains(&g['A']));
+    /// assert!(c[1].value().contains(&g['C']));
+    ///
+    /// // Both edges from 'B' to the second component.
+    /// let Edge(_, _, edges) = c[0].iter_out().next().unwrap();
+    /// assert!(edges.len() == 2);
+    ///
+    /// // The condensation can always be sorted topologically.
+    /// assert!(c.topological_generations().unwrap().len() == 2);
+    /// ```
+    pub fn condensation(&self) -> Graph<usize, Vec<Node<K, N, E>>, Vec<Edge<K, N, E>>> {
+        let components = self.scc();
+        let mut ids = HashMap::default();
+        for (id, component) in components.iter().enumerate() {
+            for node in component {
+                ids.insert(node.key().clone(), id);
+            }
+        }
+
+        let mut condensation = Graph::new();
+        for (id, component) in components.iter().enumerate() {
+            condensation.insert(Node::new(id, component.clone()));
+        }
+        for (id, component) in components.into_iter().enumerate() {
+            let mut edges = HashMap::<usize, Vec<Edge<K, N, E>>>::new();
+            for node in component {
+                for edge in &node {
+                    if let Some(&target) = ids.get(edge.1.key()) {
+                        if target != id {
+                            edges.entry(target).or_default().push(edge);
                         }
-                        components.push(cycle);
-                    }
-                    None => {
-                        invariant.insert(node.key().clone());
-                        components.push(vec![node.clone()]);
                     }
                 }
             }
-        }
-        components
-    }
-
-    fn scc_ordering(&self) -> Vec<Node<K, N, E>> {
-        let mut visited = HashSet::new();
-        let mut ordering = Vec::new();
-
-        for (_, next) in self.iter() {
-            if !visited.contains(next.key()) {
-                let partition = next
-                    .postorder()
-                    .filter(&mut |Edge(_, v, _)| !visited.contains(v.key()))
-                    .search_nodes();
-                for node in &partition {
-                    visited.insert(node.key().clone());
-                    ordering.push(node.clone());
-                }
+            let mut edges = edges.into_iter().collect::<Vec<_>>();
+            edges.sort_by_key(|(target, _)| *target);
+            for (target, edges) in edges {
+                condensation[id].connect(&condensation[target], edges);
             }
         }
-        ordering
+        condensation
+    }
+
+    /// Splits the nodes of the graph into weakly connected components: two
+    /// nodes are in the same component if there is a path between them when
+    /// ignoring the direction of edges. The result holds the nodes of each
+    /// component and maps the key of every node to the id of its component,
+    /// see [`Components`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char)
+    ///     ('A') => ['B']
+    ///     ('B') => []
+    ///     ('C') => ['B']
+    ///     ('D') => ['E']
+    ///     ('E') => []
+    /// ];
+    ///
+    /// let components = g.weakly_connected_components();
+    ///
+    /// // 'A' and 'C' can not reach each other, but are weakly connected.
+    /// assert!(components.len() == 2);
+    /// assert!(components.same_component(&'A', &'C'));
+    /// assert!(!components.same_component(&'A', &'D'));
+    /// ```
+    pub fn weakly_connected_components(&self) -> Components<K, N, E> {
+        crate::algo::connected_components(self)
     }
 
     pub fn to_dot(&self) -> String {
@@ -541,3 +1388,32 @@ where
         Self::new()
     }
 }
+
+impl<K, N, E> GraphContainer for Graph<K, N, E>
+where
+    K: Clone + Hash + Display + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Node = Node<K, N, E>;
+
+    fn get(&self, key: &K) -> Option<Node<K, N, E>> {
+        self.nodes.get(key).cloned()
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.nodes.contains_key(key)
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn insert(&mut self, node: Node<K, N, E>) -> bool {
+        Graph::insert(self, node)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Node<K, N, E>> {
+        self.nodes.values()
+    }
+}
@@ -56,15 +56,15 @@ where
         })
     }
 
-    pub fn get_outbound(&self, idx: usize) -> Option<RefInnerEdge<K, N, E>> {
+    pub fn get_outbound(&self, idx: usize) -> Option<RefInnerEdge<'_, K, N, E>> {
         self.outbound.get(idx).map(|edge| (&edge.0, &edge.1))
     }
 
-    pub fn get_inbound(&self, idx: usize) -> Option<RefInnerEdge<K, N, E>> {
+    pub fn get_inbound(&self, idx: usize) -> Option<RefInnerEdge<'_, K, N, E>> {
         self.inbound.get(idx).map(|edge| (&edge.0, &edge.1))
     }
 
-    pub fn find_outbound(&self, node: &K) -> Option<RefInnerEdge<K, N, E>> {
+    pub fn find_outbound(&self, node: &K) -> Option<RefInnerEdge<'_, K, N, E>> {
         for edge in self.outbound.iter() {
             if edge.0.upgrade().unwrap().key() == node {
                 return Some((&edge.0, &edge.1));
@@ -73,7 +73,7 @@ where
         None
     }
 
-    pub fn find_inbound(&self, node: &K) -> Option<RefInnerEdge<K, N, E>> {
+    pub fn find_inbound(&self, node: &K) -> Option<RefInnerEdge<'_, K, N, E>> {
         for edge in self.inbound.iter() {
             if edge.0.upgrade().unwrap().key() == node {
                 return Some((&edge.0, &edge.1));
@@ -1,9 +0,0 @@
-use super::*;
-
-pub mod bfs;
-pub mod dfs;
-pub mod order;
-pub mod pfs;
-
-mod method;
-mod path;
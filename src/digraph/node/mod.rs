@@ -32,14 +32,11 @@
 //! This node uses `Rc` for reference counting, thus it is not thread-safe.
 
 mod adjacent;
-mod algo;
 
 use crate::error::Error;
 
-use self::{
-    adjacent::*,
-    algo::{bfs::*, dfs::*, order::*, pfs::*},
-};
+use self::adjacent::*;
+use crate::{algo, traits::*};
 // use anyhow::{anyhow, Result};
 use std::{
     cell::RefCell,
@@ -49,11 +46,72 @@ use std::{
     rc::{Rc, Weak},
 };
 
-#[derive(Clone, Copy)]
-enum Transposition {
-    Outbound,
-    Inbound,
-}
+/// A breadth-first search over `Node<K, N, E>`, see [`algo::Bfs`].
+pub type Bfs<'a, K, N, E> = algo::Bfs<'a, Node<K, N, E>>;
+
+/// A depth-first search over `Node<K, N, E>`, see [`algo::Dfs`].
+pub type Dfs<'a, K, N, E> = algo::Dfs<'a, Node<K, N, E>>;
+
+/// A priority-first search over `Node<K, N, E>`, see [`algo::Pfs`].
+pub type Pfs<'a, K, N, E> = algo::Pfs<'a, Node<K, N, E>>;
+
+/// A priority-first search over `Node<K, N, E>` with priorities of type
+/// `P`, see [`algo::PfsBy`].
+pub type PfsBy<'a, K, N, E, P> = algo::PfsBy<'a, Node<K, N, E>, P>;
+
+/// A pre- or postorder traversal over `Node<K, N, E>`, see [`algo::Order`].
+pub type Order<'a, K, N, E> = algo::Order<'a, Node<K, N, E>>;
+
+/// A path of edges between `Node<K, N, E>`s, see [`algo::Path`].
+pub type Path<K, N, E> = algo::Path<Node<K, N, E>>;
+
+/// Shortest paths from a `Node<K, N, E>`, see [`algo::ShortestPaths`].
+pub type ShortestPaths<K, N, E, W> = algo::ShortestPaths<Node<K, N, E>, W>;
+
+/// An A* search over `Node<K, N, E>` with path lengths of type `W`, see
+/// [`algo::AStar`].
+pub type AStar<'a, K, N, E, W> = algo::AStar<'a, Node<K, N, E>, W>;
+
+/// A negative cycle found by a shortest path search over `Node<K, N, E>`,
+/// see [`algo::NegativeCycle`].
+pub type NegativeCycle<K, N, E> = algo::NegativeCycle<Node<K, N, E>>;
+
+/// A cycle that prevents `Node<K, N, E>`s from being topologically
+/// ordered, see [`algo::CycleError`].
+pub type CycleError<K, N, E> = algo::CycleError<Node<K, N, E>>;
+
+/// The shortest path lengths between all pairs of `Node<K, N, E>`s, see
+/// [`algo::DistanceTable`].
+pub type DistanceTable<K, N, E, W> = algo::DistanceTable<Node<K, N, E>, W>;
+
+/// A partition of `Node<K, N, E>`s into components, see
+/// [`algo::Components`].
+pub type Components<K, N, E> = algo::Components<Node<K, N, E>>;
+
+/// A maximum flow through `Node<K, N, E>`s with capacities of type `W`,
+/// see [`algo::MaxFlow`].
+pub type MaxFlow<K, N, E, W> = algo::MaxFlow<Node<K, N, E>, W>;
+
+/// A minimum cost flow through `Node<K, N, E>`s with capacities and costs
+/// of type `W`, see [`algo::MinCostFlow`].
+pub type MinCostFlow<K, N, E, W> = algo::MinCostFlow<Node<K, N, E>, W>;
+
+/// An assignment between `Node<K, N, E>`s with costs of type `W`, see
+/// [`algo::Assignment`].
+pub type Assignment<K, N, E, W> = algo::Assignment<Node<K, N, E>, W>;
+
+/// Betweenness centrality over `Node<K, N, E>`s, see [`algo::Brandes`].
+pub type Brandes<K, N, E> = algo::Brandes<Node<K, N, E>>;
+
+/// The betweenness of `Node<K, N, E>`s and their edges, see
+/// [`algo::Betweenness`].
+pub type Betweenness<K, N, E> = algo::Betweenness<Node<K, N, E>>;
+
+/// A power iteration over `Node<K, N, E>`s that did not converge, see
+/// [`algo::NotConverged`].
+pub type NotConverged<K, N, E> = algo::NotConverged<Node<K, N, E>>;
+
+pub use crate::algo::{Control, DfsVisitor, Weight};
 
 /// An edge between nodes is a tuple struct `Edge(u, v, e)` where `u` is the
 /// source node, `v` is the target node, and `e` is the edge's value.
@@ -559,7 +617,7 @@ where
     /// assert!(order[1] == n2);
     /// assert!(order[2] == n3);
     /// ```
-    pub fn preorder(&self) -> Order<K, N, E> {
+    pub fn preorder<'a>(&self) -> Order<'a, K, N, E> {
         Order::preorder(self)
     }
 
@@ -586,8 +644,8 @@ where
     /// assert!(order[1] == n2);
     /// assert!(order[0] == n3);
     /// ```
-    pub fn postorder(&self) -> Order<K, N, E> {
-        Order::postorder(self)
+    pub fn postorder<'a>(&self) -> Order<'a, K, N, E> {
+        Order::preorder(self).transpose().root_last()
     }
 
     /// Returns an iterator-like object that can be used to map, filter,
@@ -618,7 +676,7 @@ where
     /// assert!(iter.next().unwrap() == n2);
     /// assert!(iter.next().unwrap() == n3);
     /// ```
-    pub fn dfs(&self) -> Dfs<K, N, E> {
+    pub fn dfs<'a>(&self) -> Dfs<'a, K, N, E> {
         Dfs::new(self)
     }
 
@@ -651,7 +709,7 @@ where
     /// assert!(iter.next().unwrap() == n2);
     /// assert!(iter.next().unwrap() == n3);
     /// ```
-    pub fn bfs(&self) -> Bfs<K, N, E> {
+    pub fn bfs<'a>(&self) -> Bfs<'a, K, N, E> {
         Bfs::new(self)
     }
 
@@ -683,13 +741,188 @@ where
     /// assert!(path[0] == Edge(n1, n3.clone(), ()));
     /// assert!(path[1] == Edge(n3, n4, ()));
     ///```
-    pub fn pfs(&self) -> Pfs<K, N, E>
+    pub fn pfs<'a>(&self) -> Pfs<'a, K, N, E>
     where
         N: Ord,
     {
         Pfs::new(self)
     }
 
+    /// Returns a priority-first search that orders nodes by priorities
+    /// computed from the edges instead of the node values, see
+    /// [`algo::PfsBy`]. The root starts with the priority `initial`, and
+    /// `f` computes the priority of an edge's target from the edge and the
+    /// priority of its source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let path = g['A']
+    ///     .pfs_by(0, |Edge(_, _, e), d| d + e)
+    ///     .target(&'D')
+    ///     .search_path()
+    ///     .unwrap();
+    ///
+    /// assert!(path.len() == 4);
+    ///
+    /// // Widest path: the priority is the smallest edge weight on the path,
+    /// // largest first.
+    /// let mut pfs = g['A'].pfs_by(u64::MAX, |Edge(_, _, e), w| *e.min(w)).max();
+    ///
+    /// assert!(pfs.by_ref().count() == 3);
+    /// assert!(pfs.priority(&'D') == Some(&1));
+    /// ```
+    pub fn pfs_by<'a, P, F>(&self, initial: P, f: F) -> PfsBy<'a, K, N, E, P>
+    where
+        P: Clone + PartialOrd,
+        F: FnMut(&Edge<K, N, E>, &P) -> P + 'a,
+    {
+        PfsBy::new(self, initial, f)
+    }
+
+    /// Computes the shortest paths from this node to every node reachable
+    /// from it using Dijkstra's algorithm. The `weight` closure returns the
+    /// length of an edge, which must not be negative. Distances are kept in
+    /// the returned `ShortestPaths` so the node values are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g['A'].dijkstra(|Edge(_, _, e)| *e);
+    ///
+    /// assert!(paths.distance_to(&'B') == Some(3));
+    /// assert!(paths.distance_to(&'D') == Some(4));
+    /// assert!(paths.path_to(&'D').unwrap().len() == 4);
+    /// ```
+    pub fn dijkstra<W, F>(&self, weight: F) -> ShortestPaths<K, N, E, W>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::dijkstra(self, weight)
+    }
+
+    /// Returns an A* search from this node. The `cost` closure returns the
+    /// length of an edge and the `heuristic` closure estimates the distance
+    /// from a node to the target, see [`algo::AStar`]. The search returns a
+    /// shortest path to the target along with its length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char, u64) => [u64]
+    ///     ('A', 3) => [ ('B', 1), ('C', 2) ]
+    ///     ('B', 4) => [ ('D', 4) ]
+    ///     ('C', 1) => [ ('D', 1) ]
+    ///     ('D', 0) => []
+    /// ];
+    ///
+    /// // The node values are the estimated distances to 'D'.
+    /// let (path, cost) = g['A']
+    ///     .astar(|Edge(_, _, e)| *e, |node| *node.value())
+    ///     .target(&'D')
+    ///     .search_path()
+    ///     .unwrap();
+    ///
+    /// assert!(cost == 3);
+    /// assert!(path[0] == Edge(g['A'].clone(), g['C'].clone(), 2));
+    /// ```
+    pub fn astar<'a, W, F, H>(&self, cost: F, heuristic: H) -> AStar<'a, K, N, E, W>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W + 'a,
+        H: FnMut(&Node<K, N, E>) -> W + 'a,
+    {
+        AStar::new(self, cost, heuristic)
+    }
+
+    /// Computes the shortest paths from this node to every node reachable
+    /// from it using the Bellman-Ford algorithm. The `weight` closure may
+    /// return negative values. If a negative cycle is reachable from this
+    /// node, it is returned as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 4), ('C', 2) ]
+    ///     ('B') => [ ('D', -3) ]
+    ///     ('C') => [ ('B', -1) ]
+    ///     ('D') => [ ('C', 1) ]
+    /// ];
+    ///
+    /// let cycle = g['A'].bellman_ford(|Edge(_, _, e)| *e).unwrap_err();
+    /// let length = cycle.cycle().iter_edges().map(|Edge(_, _, e)| e).sum::<i64>();
+    ///
+    /// assert!(length == -3);
+    /// ```
+    pub fn bellman_ford<W, F>(
+        &self,
+        weight: F,
+    ) -> Result<ShortestPaths<K, N, E, W>, NegativeCycle<K, N, E>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::bellman_ford(self, weight)
+    }
+
+    /// Computes the shortest paths from this node using the Shortest Path
+    /// Faster Algorithm, a queue-based variant of Bellman-Ford, see
+    /// [`algo::spfa`]. Results are the same as for
+    /// [`bellman_ford`](Node::bellman_ford).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::digraph::*;
+    ///
+    /// let g = gdsl::digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 4), ('C', 2) ]
+    ///     ('B') => [ ('D', -3) ]
+    ///     ('C') => [ ('B', -1) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g['A'].spfa(|Edge(_, _, e)| *e).unwrap();
+    ///
+    /// assert!(paths.distance_to(&'D') == Some(-2));
+    /// ```
+    pub fn spfa<W, F>(&self, weight: F) -> Result<ShortestPaths<K, N, E, W>, NegativeCycle<K, N, E>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::spfa(self, weight)
+    }
+
     /// Returns an iterator over the node's outbound edges.
     ///
     /// # Example
@@ -708,7 +941,7 @@ where
     /// assert!(iter.next().unwrap() == Edge(n1.clone(), n2.clone(), ()));
     /// assert!(iter.next().unwrap() == Edge(n1, n3, ()));
     /// ```
-    pub fn iter_out(&self) -> IterOut<K, N, E> {
+    pub fn iter_out(&self) -> IterOut<'_, K, N, E> {
         IterOut {
             node: self,
             position: 0,
@@ -734,7 +967,7 @@ where
     /// assert!(iter.next().unwrap() == Edge(n1.clone(), n2.clone(), ()));
     /// assert!(iter.next().is_none());
     /// ```
-    pub fn iter_in(&self) -> IterIn<K, N, E> {
+    pub fn iter_in(&self) -> IterIn<'_, K, N, E> {
         IterIn {
             node: self,
             position: 0,
@@ -782,6 +1015,7 @@ where
 {
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl<K, N, E> PartialOrd for Node<K, N, E>
 where
     K: Clone + Hash + PartialEq + Display + Eq,
@@ -804,6 +1038,77 @@ where
     }
 }
 
+impl<K, N, E> GraphNode for Node<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Key = K;
+    type Value = N;
+    type EdgeValue = E;
+    type Edge = Edge<K, N, E>;
+
+    fn key(&self) -> &K {
+        &self.inner.0
+    }
+
+    fn value(&self) -> &N {
+        &self.inner.1
+    }
+}
+
+impl<K, N, E> GraphEdge for Edge<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Node = Node<K, N, E>;
+
+    fn source(&self) -> &Node<K, N, E> {
+        &self.0
+    }
+
+    fn target(&self) -> &Node<K, N, E> {
+        &self.1
+    }
+
+    fn value(&self) -> &E {
+        &self.2
+    }
+
+    fn reverse(&self) -> Self {
+        Edge(self.1.clone(), self.0.clone(), self.2.clone())
+    }
+}
+
+impl<K, N, E> Neighbors for Node<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    const DIRECTED: bool = true;
+
+    type Outbound = Outbound<K, N, E>;
+    type Inbound = Inbound<K, N, E>;
+
+    fn outbound(&self) -> Self::Outbound {
+        Outbound {
+            node: self.clone(),
+            position: 0,
+        }
+    }
+
+    fn inbound(&self) -> Self::Inbound {
+        Inbound {
+            node: self.clone(),
+            position: 0,
+        }
+    }
+}
+
 pub struct IterOut<'a, K, N, E>
 where
     K: Clone + Hash + Display + PartialEq + Eq,
@@ -878,6 +1183,68 @@ where
     }
 }
 
+/// An iterator over the node's outbound edges that holds a handle to the
+/// node instead of borrowing it, as returned by `Neighbors::outbound`.
+pub struct Outbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    node: Node<K, N, E>,
+    position: usize,
+}
+
+impl<K, N, E> Iterator for Outbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Item = Edge<K, N, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut iter = IterOut {
+            node: &self.node,
+            position: self.position,
+        };
+        let edge = iter.next();
+        self.position = iter.position;
+        edge
+    }
+}
+
+/// An iterator over the node's inbound edges that holds a handle to the
+/// node instead of borrowing it, as returned by `Neighbors::inbound`.
+pub struct Inbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    node: Node<K, N, E>,
+    position: usize,
+}
+
+impl<K, N, E> Iterator for Inbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Item = Edge<K, N, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut iter = IterIn {
+            node: &self.node,
+            position: self.position,
+        };
+        let edge = iter.next();
+        self.position = iter.position;
+        edge
+    }
+}
+
 impl<'a, K, N, E> IntoIterator for &'a Node<K, N, E>
 where
     K: Clone + Hash + Display + PartialEq + Eq,
@@ -10,21 +10,24 @@
 //! - Directed and undirected graph and node types.
 //!
 //! - Normal and sync versions of the node and graph types. Normally a node is
-//! wrapped in a `Rc` pointer and adjacent edges in a `RefCell`. In the sync
-//! versions these are `Arc` and `RwLock` respectively.
+//!   wrapped in a `Rc` pointer and adjacent edges in a `RefCell`. In the sync
+//!   versions these are `Arc` and `RwLock` respectively.
 //!
 //! - Nodes implement building blocks for algorithms in the form of breadth-first,
-//! depth-firs and priority-first traversals as well as post- and preordering.
+//!   depth-firs and priority-first traversals as well as post- and preordering.
+//!
+//! - Ready-made graph algorithms such as Dijkstra's shortest paths, written
+//!   once against the traits in `traits` and available on all node types.
 //!
 //! - Macros for creating inline graphs in an easy-to-read style.
 //!
 //! - Graphs implement Serde's serialization and deserialization.
 //!
 //! - Removing or inserting connections or otherwise manipulating the graph
-//! or any of its nodes is stable. Any references to nodes or edges remain
-//! consistent. This is due to not relying on an underlying container where
-//! nodes and edges would be represented as separate lists and indexed into,
-//! in GDSL a node "owns" all it's incoming and outgoing connections.
+//!   or any of its nodes is stable. Any references to nodes or edges remain
+//!   consistent. This is due to not relying on an underlying container where
+//!   nodes and edges would be represented as separate lists and indexed into,
+//!   in GDSL a node "owns" all it's incoming and outgoing connections.
 //!
 //! Motivation for creating this library has been to explore the idea of graphs and
 //! connected nodes as more generic data-structures that store data without
@@ -50,7 +53,7 @@
 //! // In this example the node stores the distance to the source node of the
 //! // search. The edge stores the weight of the edge. The distance is wrapped
 //! // in a `Cell` to allow for mutable access. We initialize the distance to
-//! // `std::u64::MAX` to indicate that the node is not part of the shortest
+//! // `u64::MAX` to indicate that the node is not part of the shortest
 //! // path.
 //! let g = digraph![
 //!     (char, Cell<u64>) => [u64]
@@ -100,8 +103,10 @@
 //! ```
 #![allow(clippy::type_complexity)]
 
+pub mod algo;
 pub mod digraph;
 pub mod error;
 pub mod sync_digraph;
 pub mod sync_ungraph;
+pub mod traits;
 pub mod ungraph;
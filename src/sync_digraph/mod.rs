@@ -6,7 +6,7 @@
 //! - `Graph` is a container type for a directed graph.
 //! - `Node` is a node type for a directed graph.
 //! - An edge is denoted by a tuple struct `Edge(u, v, e)` where`u` and `v` are
-//! the source and target node and `e` is the edge parameter.
+//!   the source and target node and `e` is the edge parameter.
 //!
 //! # Example
 //!
@@ -45,7 +45,8 @@ mod graph_serde;
 mod node;
 
 pub use self::node::*;
-use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use crate::traits::GraphContainer;
+use ahash::AHashMap as HashMap;
 use std::{
     fmt::{Display, Write},
     hash::Hash,
@@ -331,8 +332,175 @@ where
         self.nodes.iter()
     }
 
+    /// Computes the shortest paths from the node with the key `source` to
+    /// every node reachable from it using Dijkstra's algorithm, see
+    /// [`Node::dijkstra`]. Returns `None` if the source is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g.dijkstra(&'A', |Edge(_, _, e)| *e).unwrap();
+    ///
+    /// assert!(paths.distance_to(&'D') == Some(4));
+    /// assert!(paths.predecessor(&'D').unwrap().source() == &g['B']);
+    /// assert!(g.dijkstra(&'X', |Edge(_, _, e)| *e).is_none());
+    /// ```
+    pub fn dijkstra<W, F>(&self, source: &K, weight: F) -> Option<ShortestPaths<K, N, E, W>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        self.nodes.get(source).map(|node| node.dijkstra(weight))
+    }
+
+    /// Computes the shortest paths from the node with the key `source`
+    /// using the Bellman-Ford algorithm, see [`Node::bellman_ford`]. The
+    /// `weight` closure may return negative values. Returns `None` if the
+    /// source is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 4), ('C', 2) ]
+    ///     ('B') => [ ('D', -3) ]
+    ///     ('C') => [ ('B', -1) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g.bellman_ford(&'A', |Edge(_, _, e)| *e).unwrap().unwrap();
+    ///
+    /// assert!(paths.distance_to(&'D') == Some(-2));
+    ///
+    /// g['D'].connect(&g['C'], 1);
+    ///
+    /// assert!(g.bellman_ford(&'A', |Edge(_, _, e)| *e).unwrap().is_err());
+    /// ```
+    pub fn bellman_ford<W, F>(
+        &self,
+        source: &K,
+        weight: F,
+    ) -> Option<Result<ShortestPaths<K, N, E, W>, NegativeCycle<K, N, E>>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        self.nodes.get(source).map(|node| node.bellman_ford(weight))
+    }
+
+    /// Computes the shortest paths from the node with the key `source`
+    /// using the Shortest Path Faster Algorithm, see [`Node::spfa`].
+    /// Returns `None` if the source is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 4), ('C', 2) ]
+    ///     ('B') => [ ('D', -3) ]
+    ///     ('C') => [ ('B', -1) ]
+    ///     ('D') => [ ('C', 1) ]
+    /// ];
+    ///
+    /// let cycle = g.spfa(&'A', |Edge(_, _, e)| *e).unwrap().unwrap_err();
+    ///
+    /// assert!(cycle.cycle().len() == 4);
+    /// ```
+    pub fn spfa<W, F>(
+        &self,
+        source: &K,
+        weight: F,
+    ) -> Option<Result<ShortestPaths<K, N, E, W>, NegativeCycle<K, N, E>>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        self.nodes.get(source).map(|node| node.spfa(weight))
+    }
+
+    /// Splits the nodes of the graph into generations that only depend on
+    /// earlier ones, so the nodes of a generation can be processed in
+    /// parallel once all earlier generations are done. Every node is placed
+    /// in the earliest generation possible. The nodes of a generation
+    /// appear in no particular order. If the graph contains a cycle, one is
+    /// returned as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (&str)
+    ///     ("fetch") => ["compile"]
+    ///     ("configure") => ["compile"]
+    ///     ("compile") => ["test", "package"]
+    ///     ("docs") => ["package"]
+    ///     ("test") => []
+    ///     ("package") => []
+    /// ];
+    ///
+    /// let generations = g.topological_generations().unwrap();
+    /// assert!(generations.len() == 3);
+    /// assert!(generations[0].len() == 3);
+    /// assert!(generations[0].contains(&g["docs"]));
+    ///
+    /// g["test"].connect(&g["fetch"], ());
+    /// assert!(g.topological_generations().is_err());
+    /// ```
+    pub fn topological_generations(&self) -> Result<Vec<Vec<Node<K, N, E>>>, CycleError<K, N, E>> {
+        crate::algo::topological_generations(self)
+    }
+
+    /// Like `topological_generations`, but places every node in the latest
+    /// generation possible, i.e. right before it is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (&str)
+    ///     ("fetch") => ["compile"]
+    ///     ("configure") => ["compile"]
+    ///     ("compile") => ["test", "package"]
+    ///     ("docs") => ["package"]
+    ///     ("test") => []
+    ///     ("package") => []
+    /// ];
+    ///
+    /// let generations = g.topological_generations_latest().unwrap();
+    /// assert!(generations.len() == 3);
+    /// assert!(generations[0].len() == 2);
+    /// assert!(generations[1].contains(&g["docs"]));
+    /// ```
+    pub fn topological_generations_latest(
+        &self,
+    ) -> Result<Vec<Vec<Node<K, N, E>>>, CycleError<K, N, E>> {
+        crate::algo::topological_generations_latest(self)
+    }
+
     /// Find the strongly connected components of the graph. Can be used to
-    /// find cycles in the graph and for topological sorting.
+    /// find cycles in the graph and for topological sorting. The components
+    /// are listed in topological order, see
+    /// [`algo::strongly_connected_components`](crate::algo::strongly_connected_components).
     ///
     /// # Examples
     ///
@@ -377,53 +545,191 @@ where
     /// assert!(scc[3].len() == 3);
     /// ```
     pub fn scc(&self) -> Vec<Vec<Node<K, N, E>>> {
-        let mut invariant = HashSet::new();
-        let mut components = Vec::new();
-        let mut ordering = self.scc_ordering();
-
-        while let Some(node) = ordering.pop() {
-            if !invariant.contains(node.key()) {
-                let cycle = node
-                    .dfs()
-                    .transpose()
-                    .filter(&mut |Edge(_, v, _)| !invariant.contains(v.key()))
-                    .search_cycle();
-                match cycle {
-                    Some(cycle) => {
-                        let mut cycle = cycle.to_vec_nodes();
-                        cycle.pop();
-                        for node in &cycle {
-                            invariant.insert(node.key().clone());
+        crate::algo::strongly_connected_components(self)
+    }
+
+    /// Builds the condensation of the graph: a new, acyclic graph with a
+    /// node for each strongly connected component. The node with key `i`
+    /// holds the members of the `i`-th component returned by
+    /// [`Graph::scc`], so the keys follow a topological order. Two
+    /// components are connected if an edge leads from a member of the one
+    /// to a member of the other; the edge holds all such edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char)
+    ///     ('A') => ['B']
+    ///     ('B') => ['A', 'C', 'D']
+    ///     ('C') => ['D']
+    ///     ('D') => ['C']
+    /// ];
+    ///
+    /// let c = g.condensation();
+    ///
+    /// assert!(c.len() == 2);
+    /// assert!(c[0].value().contains(&g['A']));
+    /// assert!(c[1].value().contains(&g['C']));
+    ///
+    /// // Both edges from 'B' to the second component.
+    /// let Edge(_, _, edges) = c[0].iter_out().next().unwrap();
+    /// assert!(edges.len() == 2);
+    ///
+    /// // The condensation can always be sorted topologically.
+    /// assert!(c.topological_generations().unwrap().len() == 2);
+    /// ```
+    pub fn condensation(&self) -> Graph<usize, Vec<Node<K, N, E>>, Vec<Edge<K, N, E>>> {
+        let components = self.scc();
+        let mut ids = HashMap::default();
+        for (id, component) in components.iter().enumerate() {
+            for node in component {
+                ids.insert(node.key().clone(), id);
+            }
+        }
+
+        let mut condensation = Graph::new();
+        for (id, component) in components.iter().enumerate() {
+            condensation.insert(Node::new(id, component.clone()));
+        }
+        for (id, component) in components.into_iter().enumerate() {
+            let mut edges = HashMap::<usize, Vec<Edge<K, N, E>>>::new();
+            for node in component {
+                for edge in &node {
+                    if let Some(&target) = ids.get(edge.1.key()) {
+                        if target != id {
+                            edges.entry(target).or_default().push(edge);
                         }
-                        components.push(cycle);
-                    }
-                    None => {
-                        invariant.insert(node.key().clone());
-                        components.push(vec![node.clone()]);
                     }
                 }
             }
-        }
-        components
-    }
-
-    fn scc_ordering(&self) -> Vec<Node<K, N, E>> {
-        let mut visited = HashSet::new();
-        let mut ordering = Vec::new();
-
-        for (_, next) in self.iter() {
-            if !visited.contains(next.key()) {
-                let partition = next
-                    .postorder()
-                    .filter(&mut |Edge(_, v, _)| !visited.contains(v.key()))
-                    .search_nodes();
-                for node in &partition {
-                    visited.insert(node.key().clone());
-                    ordering.push(node.clone());
-                }
+            let mut edges = edges.into_iter().collect::<Vec<_>>();
+            edges.sort_by_key(|(target, _)| *target);
+            for (target, edges) in edges {
+                condensation[id].connect(&condensation[target], edges);
             }
         }
-        ordering
+        condensation
+    }
+
+    /// Splits the nodes of the graph into weakly connected components: two
+    /// nodes are in the same component if there is a path between them when
+    /// ignoring the direction of edges. The result holds the nodes of each
+    /// component and maps the key of every node to the id of its component,
+    /// see [`Components`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char)
+    ///     ('A') => ['B']
+    ///     ('B') => []
+    ///     ('C') => ['B']
+    ///     ('D') => ['E']
+    ///     ('E') => []
+    /// ];
+    ///
+    /// let components = g.weakly_connected_components();
+    ///
+    /// // 'A' and 'C' can not reach each other, but are weakly connected.
+    /// assert!(components.len() == 2);
+    /// assert!(components.same_component(&'A', &'C'));
+    /// assert!(!components.same_component(&'A', &'D'));
+    /// ```
+    pub fn weakly_connected_components(&self) -> Components<K, N, E> {
+        crate::algo::connected_components(self)
+    }
+
+    /// Computes the PageRank of every node: the probability that a surfer
+    /// who follows a random outbound edge with the probability `damping`,
+    /// and jumps to a random node otherwise, is found at the node. Iterates
+    /// until the scores change by less than `tolerance` in total, or at
+    /// most `max_iter` times. See [`crate::algo::page_rank`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (usize)
+    ///     (0) => [1, 2]
+    ///     (1) => [2, 3]
+    ///     (2) => [0]
+    ///     (3) => []
+    /// ];
+    ///
+    /// let rank = g.page_rank(0.85, 1e-9, 100);
+    ///
+    /// assert!(rank[&0] > rank[&2] && rank[&2] > rank[&1] && rank[&1] > rank[&3]);
+    /// assert!((rank.values().sum::<f64>() - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn page_rank(&self, damping: f64, tolerance: f64, max_iter: usize) -> HashMap<K, f64> {
+        crate::algo::page_rank(self, damping, tolerance, max_iter)
+    }
+
+    /// Computes the personalized PageRank of every node, where random jumps
+    /// land on a node with a probability proportional to its `teleport`
+    /// weight, zero for nodes missing from it. See [`crate::algo::personalized_page_rank`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (usize)
+    ///     (0) => [1]
+    ///     (1) => [2]
+    ///     (2) => [0]
+    ///     (3) => [0]
+    /// ];
+    ///
+    /// let rank = g.personalized_page_rank(0.85, &[(1, 1.0)].into(), 1e-9, 100);
+    ///
+    /// assert!(rank[&1] > rank[&2] && rank[&2] > rank[&0]);
+    /// assert!(rank[&3] == 0.0);
+    /// ```
+    pub fn personalized_page_rank(
+        &self,
+        damping: f64,
+        teleport: &HashMap<K, f64>,
+        tolerance: f64,
+        max_iter: usize,
+    ) -> HashMap<K, f64> {
+        crate::algo::personalized_page_rank(self, damping, teleport, tolerance, max_iter)
+    }
+
+    /// Computes the hub and authority scores of every node with the HITS
+    /// algorithm, iterating until the hub scores change by less than
+    /// `tolerance` in total, or at most `max_iter` times. Returns the hub
+    /// and the authority scores. See [`crate::algo::hits`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (usize)
+    ///     (0) => [2, 3]
+    ///     (1) => [2]
+    ///     (2) => []
+    ///     (3) => []
+    /// ];
+    ///
+    /// let (hubs, authorities) = g.hits(1e-9, 100);
+    ///
+    /// assert!(hubs[&0] > hubs[&1] && hubs[&2] == 0.0);
+    /// assert!(authorities[&2] > authorities[&3] && authorities[&0] == 0.0);
+    /// ```
+    pub fn hits(&self, tolerance: f64, max_iter: usize) -> (HashMap<K, f64>, HashMap<K, f64>) {
+        crate::algo::hits(self, tolerance, max_iter)
     }
 
     pub fn to_dot(&self) -> String {
@@ -526,3 +832,32 @@ where
         Self::new()
     }
 }
+
+impl<K, N, E> GraphContainer for Graph<K, N, E>
+where
+    K: Clone + Hash + Display + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Node = Node<K, N, E>;
+
+    fn get(&self, key: &K) -> Option<Node<K, N, E>> {
+        self.nodes.get(key).cloned()
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.nodes.contains_key(key)
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn insert(&mut self, node: Node<K, N, E>) -> bool {
+        Graph::insert(self, node)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Node<K, N, E>> {
+        self.nodes.values()
+    }
+}
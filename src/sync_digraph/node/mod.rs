@@ -32,13 +32,10 @@
 //! This node uses `Arc` for reference counting, thus it is thread-safe.
 
 mod adjacent;
-mod algo;
 
-use self::{
-    adjacent::*,
-    algo::{bfs::*, dfs::*, order::*, pfs::*},
-};
+use self::adjacent::*;
 use crate::error::Error;
+use crate::{algo, traits::*};
 use std::{
     fmt::Display,
     hash::Hash,
@@ -46,10 +43,41 @@ use std::{
     sync::{Arc, RwLock, Weak},
 };
 
-enum Transposition {
-    Outbound,
-    Inbound,
-}
+/// A breadth-first search over `Node<K, N, E>`, see [`algo::Bfs`].
+pub type Bfs<'a, K, N, E> = algo::Bfs<'a, Node<K, N, E>>;
+
+/// A depth-first search over `Node<K, N, E>`, see [`algo::Dfs`].
+pub type Dfs<'a, K, N, E> = algo::Dfs<'a, Node<K, N, E>>;
+
+/// A priority-first search over `Node<K, N, E>`, see [`algo::Pfs`].
+pub type Pfs<'a, K, N, E> = algo::Pfs<'a, Node<K, N, E>>;
+
+/// A priority-first search over `Node<K, N, E>` with priorities of type
+/// `P`, see [`algo::PfsBy`].
+pub type PfsBy<'a, K, N, E, P> = algo::PfsBy<'a, Node<K, N, E>, P>;
+
+/// A pre- or postorder traversal over `Node<K, N, E>`, see [`algo::Order`].
+pub type Order<'a, K, N, E> = algo::Order<'a, Node<K, N, E>>;
+
+/// A path of edges between `Node<K, N, E>`s, see [`algo::Path`].
+pub type Path<K, N, E> = algo::Path<Node<K, N, E>>;
+
+/// Shortest paths from a `Node<K, N, E>`, see [`algo::ShortestPaths`].
+pub type ShortestPaths<K, N, E, W> = algo::ShortestPaths<Node<K, N, E>, W>;
+
+/// A negative cycle found by a shortest path search over `Node<K, N, E>`,
+/// see [`algo::NegativeCycle`].
+pub type NegativeCycle<K, N, E> = algo::NegativeCycle<Node<K, N, E>>;
+
+/// A cycle that prevents `Node<K, N, E>`s from being topologically
+/// ordered, see [`algo::CycleError`].
+pub type CycleError<K, N, E> = algo::CycleError<Node<K, N, E>>;
+
+/// A partition of `Node<K, N, E>`s into components, see
+/// [`algo::Components`].
+pub type Components<K, N, E> = algo::Components<Node<K, N, E>>;
+
+pub use crate::algo::{Control, DfsVisitor, Weight};
 
 /// An edge between nodes is a tuple struct `Edge(u, v, e)` where `u` is the
 /// source node, `v` is the target node, and `e` is the edge's value.
@@ -524,7 +552,7 @@ where
     /// assert!(order[1] == n2);
     /// assert!(order[2] == n3);
     /// ```
-    pub fn preorder(&self) -> Order<K, N, E> {
+    pub fn preorder<'a>(&self) -> Order<'a, K, N, E> {
         Order::preorder(self)
     }
 
@@ -551,8 +579,8 @@ where
     /// assert!(order[1] == n2);
     /// assert!(order[0] == n3);
     /// ```
-    pub fn postorder(&self) -> Order<K, N, E> {
-        Order::postroder(self)
+    pub fn postorder<'a>(&self) -> Order<'a, K, N, E> {
+        Order::postorder(self)
     }
 
     /// Returns an iterator-like object that can be used to map, filter,
@@ -583,7 +611,7 @@ where
     /// assert!(iter.next().unwrap() == n2);
     /// assert!(iter.next().unwrap() == n3);
     /// ```
-    pub fn dfs(&self) -> Dfs<K, N, E> {
+    pub fn dfs<'a>(&self) -> Dfs<'a, K, N, E> {
         Dfs::new(self)
     }
 
@@ -616,7 +644,7 @@ where
     /// assert!(iter.next().unwrap() == n2);
     /// assert!(iter.next().unwrap() == n3);
     /// ```
-    pub fn bfs(&self) -> Bfs<K, N, E> {
+    pub fn bfs<'a>(&self) -> Bfs<'a, K, N, E> {
         Bfs::new(self)
     }
 
@@ -648,13 +676,151 @@ where
     /// assert!(path[0] == Edge(n1, n3.clone(), ()));
     /// assert!(path[1] == Edge(n3, n4, ()));
     ///```
-    pub fn pfs(&self) -> Pfs<K, N, E>
+    pub fn pfs<'a>(&self) -> Pfs<'a, K, N, E>
     where
         N: Ord,
     {
         Pfs::new(self)
     }
 
+    /// Returns a priority-first search that orders nodes by priorities
+    /// computed from the edges instead of the node values, see
+    /// [`algo::PfsBy`]. The root starts with the priority `initial`, and
+    /// `f` computes the priority of an edge's target from the edge and the
+    /// priority of its source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let path = g['A']
+    ///     .pfs_by(0, |Edge(_, _, e), d| d + e)
+    ///     .target(&'D')
+    ///     .search_path()
+    ///     .unwrap();
+    ///
+    /// assert!(path.len() == 4);
+    ///
+    /// // Widest path: the priority is the smallest edge weight on the path,
+    /// // largest first.
+    /// let mut pfs = g['A'].pfs_by(u64::MAX, |Edge(_, _, e), w| *e.min(w)).max();
+    ///
+    /// assert!(pfs.by_ref().count() == 3);
+    /// assert!(pfs.priority(&'D') == Some(&1));
+    /// ```
+    pub fn pfs_by<'a, P, F>(&self, initial: P, f: F) -> PfsBy<'a, K, N, E, P>
+    where
+        P: Clone + PartialOrd,
+        F: FnMut(&Edge<K, N, E>, &P) -> P + 'a,
+    {
+        PfsBy::new(self, initial, f)
+    }
+
+    /// Computes the shortest paths from this node to every node reachable
+    /// from it using Dijkstra's algorithm. The `weight` closure returns the
+    /// length of an edge, which must not be negative. Distances are kept in
+    /// the returned `ShortestPaths` so the node values are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g['A'].dijkstra(|Edge(_, _, e)| *e);
+    ///
+    /// assert!(paths.distance_to(&'B') == Some(3));
+    /// assert!(paths.distance_to(&'D') == Some(4));
+    /// assert!(paths.path_to(&'D').unwrap().len() == 4);
+    /// ```
+    pub fn dijkstra<W, F>(&self, weight: F) -> ShortestPaths<K, N, E, W>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::dijkstra(self, weight)
+    }
+
+    /// Computes the shortest paths from this node to every node reachable
+    /// from it using the Bellman-Ford algorithm. The `weight` closure may
+    /// return negative values. If a negative cycle is reachable from this
+    /// node, it is returned as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 4), ('C', 2) ]
+    ///     ('B') => [ ('D', -3) ]
+    ///     ('C') => [ ('B', -1) ]
+    ///     ('D') => [ ('C', 1) ]
+    /// ];
+    ///
+    /// let cycle = g['A'].bellman_ford(|Edge(_, _, e)| *e).unwrap_err();
+    /// let length = cycle.cycle().iter_edges().map(|Edge(_, _, e)| e).sum::<i64>();
+    ///
+    /// assert!(length == -3);
+    /// ```
+    pub fn bellman_ford<W, F>(
+        &self,
+        weight: F,
+    ) -> Result<ShortestPaths<K, N, E, W>, NegativeCycle<K, N, E>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::bellman_ford(self, weight)
+    }
+
+    /// Computes the shortest paths from this node using the Shortest Path
+    /// Faster Algorithm, a queue-based variant of Bellman-Ford, see
+    /// [`algo::spfa`]. Results are the same as for
+    /// [`bellman_ford`](Node::bellman_ford).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_digraph::*;
+    ///
+    /// let g = gdsl::sync_digraph![
+    ///     (char) => [i64]
+    ///     ('A') => [ ('B', 4), ('C', 2) ]
+    ///     ('B') => [ ('D', -3) ]
+    ///     ('C') => [ ('B', -1) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g['A'].spfa(|Edge(_, _, e)| *e).unwrap();
+    ///
+    /// assert!(paths.distance_to(&'D') == Some(-2));
+    /// ```
+    pub fn spfa<W, F>(&self, weight: F) -> Result<ShortestPaths<K, N, E, W>, NegativeCycle<K, N, E>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::spfa(self, weight)
+    }
+
     /// Returns an iterator over the node's outbound edges.
     ///
     /// # Example
@@ -673,7 +839,7 @@ where
     /// assert!(iter.next().unwrap() == Edge(n1.clone(), n2.clone(), ()));
     /// assert!(iter.next().unwrap() == Edge(n1, n3, ()));
     /// ```
-    pub fn iter_out(&self) -> IterOut<K, N, E> {
+    pub fn iter_out(&self) -> IterOut<'_, K, N, E> {
         IterOut {
             node: self,
             position: 0,
@@ -699,7 +865,7 @@ where
     /// assert!(iter.next().unwrap() == Edge(n1.clone(), n2.clone(), ()));
     /// assert!(iter.next().is_none());
     /// ```
-    pub fn iter_in(&self) -> IterIn<K, N, E> {
+    pub fn iter_in(&self) -> IterIn<'_, K, N, E> {
         IterIn {
             node: self,
             position: 0,
@@ -747,6 +913,7 @@ where
 {
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl<K, N, E> PartialOrd for Node<K, N, E>
 where
     K: Clone + Hash + PartialEq + Display + Eq,
@@ -769,6 +936,77 @@ where
     }
 }
 
+impl<K, N, E> GraphNode for Node<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Key = K;
+    type Value = N;
+    type EdgeValue = E;
+    type Edge = Edge<K, N, E>;
+
+    fn key(&self) -> &K {
+        &self.inner.0
+    }
+
+    fn value(&self) -> &N {
+        &self.inner.1
+    }
+}
+
+impl<K, N, E> GraphEdge for Edge<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Node = Node<K, N, E>;
+
+    fn source(&self) -> &Node<K, N, E> {
+        &self.0
+    }
+
+    fn target(&self) -> &Node<K, N, E> {
+        &self.1
+    }
+
+    fn value(&self) -> &E {
+        &self.2
+    }
+
+    fn reverse(&self) -> Self {
+        Edge(self.1.clone(), self.0.clone(), self.2.clone())
+    }
+}
+
+impl<K, N, E> Neighbors for Node<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    const DIRECTED: bool = true;
+
+    type Outbound = Outbound<K, N, E>;
+    type Inbound = Inbound<K, N, E>;
+
+    fn outbound(&self) -> Self::Outbound {
+        Outbound {
+            node: self.clone(),
+            position: 0,
+        }
+    }
+
+    fn inbound(&self) -> Self::Inbound {
+        Inbound {
+            node: self.clone(),
+            position: 0,
+        }
+    }
+}
+
 pub struct IterOut<'a, K, N, E>
 where
     K: Clone + Hash + Display + PartialEq + Eq,
@@ -842,6 +1080,68 @@ where
     }
 }
 
+/// An iterator over the node's outbound edges that holds a handle to the
+/// node instead of borrowing it, as returned by `Neighbors::outbound`.
+pub struct Outbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    node: Node<K, N, E>,
+    position: usize,
+}
+
+impl<K, N, E> Iterator for Outbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Item = Edge<K, N, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut iter = IterOut {
+            node: &self.node,
+            position: self.position,
+        };
+        let edge = iter.next();
+        self.position = iter.position;
+        edge
+    }
+}
+
+/// An iterator over the node's inbound edges that holds a handle to the
+/// node instead of borrowing it, as returned by `Neighbors::inbound`.
+pub struct Inbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    node: Node<K, N, E>,
+    position: usize,
+}
+
+impl<K, N, E> Iterator for Inbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Item = Edge<K, N, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut iter = IterIn {
+            node: &self.node,
+            position: self.position,
+        };
+        let edge = iter.next();
+        self.position = iter.position;
+        edge
+    }
+}
+
 impl<'a, K, N, E> IntoIterator for &'a Node<K, N, E>
 where
     K: Clone + Hash + Display + PartialEq + Eq,
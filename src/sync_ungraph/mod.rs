@@ -11,6 +11,8 @@ use std::{
 
 use ahash::HashMap;
 
+use crate::traits::GraphContainer;
+
 pub use self::node::*;
 
 pub struct Graph<K, N, E>
@@ -231,6 +233,206 @@ where
         self.nodes.iter()
     }
 
+    /// Computes the shortest paths from the node with the key `source` to
+    /// every node reachable from it using Dijkstra's algorithm, see
+    /// [`Node::dijkstra`]. Returns `None` if the source is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// let g = gdsl::sync_ungraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g.dijkstra(&'A', |Edge(_, _, e)| *e).unwrap();
+    ///
+    /// assert!(paths.distance_to(&'D') == Some(4));
+    /// assert!(paths.predecessor(&'D').unwrap().source() == &g['B']);
+    /// assert!(g.dijkstra(&'X', |Edge(_, _, e)| *e).is_none());
+    /// ```
+    pub fn dijkstra<W, F>(&self, source: &K, weight: F) -> Option<ShortestPaths<K, N, E, W>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        self.nodes.get(source).map(|node| node.dijkstra(weight))
+    }
+
+    /// Splits the nodes of the graph into connected components: two nodes
+    /// are in the same component if there is a path between them. The
+    /// result holds the nodes of each component and maps the key of every
+    /// node to the id of its component, see [`Components`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// let g = gdsl::sync_ungraph![
+    ///     (char)
+    ///     ('A') => ['B', 'C']
+    ///     ('B') => []
+    ///     ('C') => []
+    ///     ('D') => ['E']
+    ///     ('E') => []
+    /// ];
+    ///
+    /// let components = g.connected_components();
+    ///
+    /// assert!(components.len() == 2);
+    /// assert!(components.component(&'B') == components.component(&'C'));
+    /// assert!(components.component(&'B') != components.component(&'D'));
+    /// ```
+    pub fn connected_components(&self) -> Components<K, N, E> {
+        crate::algo::connected_components(self)
+    }
+
+    /// Returns the articulation points of the graph: the nodes whose
+    /// removal would disconnect the graph, or a component of it. The nodes
+    /// are returned in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// // Two rings of routers joined by the router 'C'.
+    /// let g = gdsl::sync_ungraph![
+    ///     (char)
+    ///     ('A') => ['B', 'C']
+    ///     ('B') => ['C']
+    ///     ('C') => ['D', 'E']
+    ///     ('D') => ['E']
+    ///     ('E') => []
+    /// ];
+    ///
+    /// assert!(g.articulation_points() == vec![g['C'].clone()]);
+    /// ```
+    pub fn articulation_points(&self) -> Vec<Node<K, N, E>> {
+        crate::algo::articulation_points(self)
+    }
+
+    /// Returns the bridges of the graph: the edges whose removal would
+    /// disconnect their endpoints. Each bridge is returned once, in either
+    /// orientation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// let g = gdsl::sync_ungraph![
+    ///     (char) => [u32]
+    ///     ('A') => [('B', 1), ('C', 2)]
+    ///     ('B') => [('C', 3)]
+    ///     ('C') => [('D', 4)]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let bridges = g.bridges();
+    ///
+    /// assert!(bridges.len() == 1);
+    /// assert!(bridges[0].2 == 4);
+    /// ```
+    pub fn bridges(&self) -> Vec<Edge<K, N, E>> {
+        crate::algo::bridges(self)
+    }
+
+    /// Splits the edges of the graph into biconnected components: groups
+    /// of edges that stay connected when any single node is removed. A
+    /// bridge forms a component on its own. Loops are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// let g = gdsl::sync_ungraph![
+    ///     (char)
+    ///     ('A') => ['B', 'C']
+    ///     ('B') => ['C']
+    ///     ('C') => ['D']
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let mut components = g.biconnected_components();
+    /// components.sort_by_key(|edges| edges.len());
+    ///
+    /// assert!(components.len() == 2);
+    /// assert!(components[0].len() == 1);
+    /// assert!(components[1].len() == 3);
+    /// ```
+    pub fn biconnected_components(&self) -> Vec<Vec<Edge<K, N, E>>> {
+        crate::algo::biconnected_components(self)
+    }
+
+    /// Finds a maximum cardinality matching of the graph with Edmonds'
+    /// blossom algorithm: a largest set of edges of which no two share a
+    /// node. The graph does not need to be bipartite. Each matched pair is
+    /// returned as one edge between the two nodes. See
+    /// [`crate::algo::maximum_matching`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// // Peers in a mesh, paired up for an exchange.
+    /// let g = gdsl::sync_ungraph![
+    ///     (char)
+    ///     ('A') => ['B', 'C']
+    ///     ('B') => ['C', 'D']
+    ///     ('C') => ['E']
+    ///     ('D') => ['E', 'F']
+    ///     ('E') => []
+    ///     ('F') => []
+    /// ];
+    ///
+    /// let matching = g.maximum_matching();
+    ///
+    /// assert!(matching.len() == 3);
+    /// ```
+    pub fn maximum_matching(&self) -> Vec<Edge<K, N, E>> {
+        crate::algo::maximum_matching(self)
+    }
+
+    /// Finds a matching of maximum total `weight` with the weighted version
+    /// of Edmonds' blossom algorithm. The matching does not need to be of
+    /// maximum cardinality, edges of negative weight are never chosen. See
+    /// [`crate::algo::maximum_weight_matching`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// let g = gdsl::sync_ungraph![
+    ///     (char) => [i32]
+    ///     ('A') => [('B', 6), ('C', 4)]
+    ///     ('B') => [('C', 5), ('D', 4)]
+    ///     ('C') => []
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let matching = g.maximum_weight_matching(|Edge(_, _, e)| *e);
+    ///
+    /// assert!(matching.len() == 2);
+    /// assert!(matching.iter().map(|Edge(_, _, e)| e).sum::<i32>() == 8);
+    /// ```
+    pub fn maximum_weight_matching<W, F>(&self, weight: F) -> Vec<Edge<K, N, E>>
+    where
+        W: Weight + std::ops::Div<Output = W> + From<u8>,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        crate::algo::maximum_weight_matching(self, weight)
+    }
+
     pub fn to_dot(&self) -> String
     where
         N: Display,
@@ -273,3 +475,32 @@ where
         Self::new()
     }
 }
+
+impl<K, N, E> GraphContainer for Graph<K, N, E>
+where
+    K: Clone + Hash + Display + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Node = Node<K, N, E>;
+
+    fn get(&self, key: &K) -> Option<Node<K, N, E>> {
+        self.nodes.get(key).cloned()
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.nodes.contains_key(key)
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn insert(&mut self, node: Node<K, N, E>) -> bool {
+        Graph::insert(self, node)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Node<K, N, E>> {
+        self.nodes.values()
+    }
+}
@@ -32,20 +32,46 @@
 //! This node uses `Arc` for reference counting, thus it is thread-safe.
 
 mod adjacent;
-mod algo;
 
 use crate::error::Error;
 use std::{
     fmt::Display,
     hash::Hash,
+    iter::Map,
     ops::Deref,
     sync::{Arc, RwLock, Weak},
 };
 
-use self::{
-    adjacent::*,
-    algo::{bfs::*, dfs::*, order::*, pfs::*},
-};
+use self::adjacent::*;
+use crate::{algo, traits::*};
+
+/// A breadth-first search over `Node<K, N, E>`, see [`algo::Bfs`].
+pub type Bfs<'a, K, N, E> = algo::Bfs<'a, Node<K, N, E>>;
+
+/// A depth-first search over `Node<K, N, E>`, see [`algo::Dfs`].
+pub type Dfs<'a, K, N, E> = algo::Dfs<'a, Node<K, N, E>>;
+
+/// A priority-first search over `Node<K, N, E>`, see [`algo::Pfs`].
+pub type Pfs<'a, K, N, E> = algo::Pfs<'a, Node<K, N, E>>;
+
+/// A priority-first search over `Node<K, N, E>` with priorities of type
+/// `P`, see [`algo::PfsBy`].
+pub type PfsBy<'a, K, N, E, P> = algo::PfsBy<'a, Node<K, N, E>, P>;
+
+/// A pre- or postorder traversal over `Node<K, N, E>`, see [`algo::Order`].
+pub type Order<'a, K, N, E> = algo::Order<'a, Node<K, N, E>>;
+
+/// A path of edges between `Node<K, N, E>`s, see [`algo::Path`].
+pub type Path<K, N, E> = algo::Path<Node<K, N, E>>;
+
+/// Shortest paths from a `Node<K, N, E>`, see [`algo::ShortestPaths`].
+pub type ShortestPaths<K, N, E, W> = algo::ShortestPaths<Node<K, N, E>, W>;
+
+/// A partition of `Node<K, N, E>`s into components, see
+/// [`algo::Components`].
+pub type Components<K, N, E> = algo::Components<Node<K, N, E>>;
+
+pub use crate::algo::{Control, DfsVisitor, Weight};
 
 /// An edge between nodes is a tuple struct `Edge(u, v, e)` where `u` is the
 /// source node, `v` is the target node, and `e` is the edge's value.
@@ -404,34 +430,108 @@ where
     /// Returns an iterator-like object that can be used to map, filter and
     /// collect reachable nodes or edges in different orderings such as
     /// postorder or preorder.
-    pub fn order(&self) -> Order<K, N, E> {
-        Order::new(self)
+    pub fn order<'a>(&self) -> Order<'a, K, N, E> {
+        Order::new(self).reverse_edges()
     }
 
     /// Returns an iterator-like object that can be used to map, filter,
     /// search and collect nodes or edges resulting from a depth-first search.
-    pub fn dfs(&self) -> Dfs<K, N, E> {
+    pub fn dfs<'a>(&self) -> Dfs<'a, K, N, E> {
         Dfs::new(self)
     }
 
     /// Returns an iterator-like object that can be used to map, filter,
     /// search and collect nodes or edges resulting from a breadth-first search.
-    pub fn bfs(&self) -> Bfs<K, N, E> {
+    pub fn bfs<'a>(&self) -> Bfs<'a, K, N, E> {
         Bfs::new(self)
     }
 
     /// Returns an iterator-like object that can be used to map, filter,
     /// search and collect nodes or edges resulting from a
     /// priotity-first search.
-    pub fn pfs(&self) -> Pfs<K, N, E>
+    pub fn pfs<'a>(&self) -> Pfs<'a, K, N, E>
     where
         N: Ord,
     {
         Pfs::new(self)
     }
 
+    /// Returns a priority-first search that orders nodes by priorities
+    /// computed from the edges instead of the node values, see
+    /// [`algo::PfsBy`]. The root starts with the priority `initial`, and
+    /// `f` computes the priority of an edge's target from the edge and the
+    /// priority of its source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// let g = gdsl::sync_ungraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let path = g['A']
+    ///     .pfs_by(0, |Edge(_, _, e), d| d + e)
+    ///     .target(&'D')
+    ///     .search_path()
+    ///     .unwrap();
+    ///
+    /// assert!(path.len() == 4);
+    ///
+    /// // Widest path: the priority is the smallest edge weight on the path,
+    /// // largest first.
+    /// let mut pfs = g['A'].pfs_by(u64::MAX, |Edge(_, _, e), w| *e.min(w)).max();
+    ///
+    /// assert!(pfs.by_ref().count() == 3);
+    /// assert!(pfs.priority(&'D') == Some(&2));
+    /// ```
+    pub fn pfs_by<'a, P, F>(&self, initial: P, f: F) -> PfsBy<'a, K, N, E, P>
+    where
+        P: Clone + PartialOrd,
+        F: FnMut(&Edge<K, N, E>, &P) -> P + 'a,
+    {
+        PfsBy::new(self, initial, f)
+    }
+
+    /// Computes the shortest paths from this node to every node reachable
+    /// from it using Dijkstra's algorithm. The `weight` closure returns the
+    /// length of an edge, which must not be negative. Distances are kept in
+    /// the returned `ShortestPaths` so the node values are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::sync_ungraph::*;
+    ///
+    /// let g = gdsl::sync_ungraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g['A'].dijkstra(|Edge(_, _, e)| *e);
+    ///
+    /// assert!(paths.distance_to(&'B') == Some(3));
+    /// assert!(paths.distance_to(&'D') == Some(4));
+    /// assert!(paths.path_to(&'D').unwrap().len() == 4);
+    /// ```
+    pub fn dijkstra<W, F>(&self, weight: F) -> ShortestPaths<K, N, E, W>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        algo::dijkstra(self, weight)
+    }
+
     /// Returns an iterator over the node's adjacent edges.
-    pub fn iter(&self) -> NodeIterator<K, N, E> {
+    pub fn iter(&self) -> NodeIterator<'_, K, N, E> {
         NodeIterator {
             node: self,
             position: 0,
@@ -480,6 +580,7 @@ where
 {
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl<K, N, E> PartialOrd for Node<K, N, E>
 where
     K: Clone + Hash + PartialEq + Display + Eq,
@@ -502,6 +603,76 @@ where
     }
 }
 
+impl<K, N, E> GraphNode for Node<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Key = K;
+    type Value = N;
+    type EdgeValue = E;
+    type Edge = Edge<K, N, E>;
+
+    fn key(&self) -> &K {
+        &self.inner.0
+    }
+
+    fn value(&self) -> &N {
+        &self.inner.1
+    }
+}
+
+impl<K, N, E> GraphEdge for Edge<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Node = Node<K, N, E>;
+
+    fn source(&self) -> &Node<K, N, E> {
+        &self.0
+    }
+
+    fn target(&self) -> &Node<K, N, E> {
+        &self.1
+    }
+
+    fn value(&self) -> &E {
+        &self.2
+    }
+
+    fn reverse(&self) -> Self {
+        Edge(self.1.clone(), self.0.clone(), self.2.clone())
+    }
+}
+
+impl<K, N, E> Neighbors for Node<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    const DIRECTED: bool = false;
+
+    type Outbound = Outbound<K, N, E>;
+    type Inbound = Map<Outbound<K, N, E>, fn(Edge<K, N, E>) -> Edge<K, N, E>>;
+
+    fn outbound(&self) -> Self::Outbound {
+        Outbound {
+            node: self.clone(),
+            position: 0,
+        }
+    }
+
+    fn inbound(&self) -> Self::Inbound {
+        // Every adjacent edge of an undirected node is also an inbound edge.
+        let reverse: fn(Edge<K, N, E>) -> Edge<K, N, E> = |edge| edge.reverse();
+        self.outbound().map(reverse)
+    }
+}
+
 pub struct NodeIterator<'a, K, N, E>
 where
     K: Clone + Hash + Display + PartialEq + Eq,
@@ -532,6 +703,37 @@ where
     }
 }
 
+/// An iterator over the node's adjacent edges that holds a handle to the
+/// node instead of borrowing it, as returned by `Neighbors::outbound`.
+pub struct Outbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    node: Node<K, N, E>,
+    position: usize,
+}
+
+impl<K, N, E> Iterator for Outbound<K, N, E>
+where
+    K: Clone + Hash + Display + PartialEq + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Item = Edge<K, N, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut iter = NodeIterator {
+            node: &self.node,
+            position: self.position,
+        };
+        let edge = iter.next();
+        self.position = iter.position;
+        edge
+    }
+}
+
 impl<'a, K, N, E> IntoIterator for &'a Node<K, N, E>
 where
     K: Clone + Hash + Display + PartialEq + Eq,
@@ -0,0 +1,137 @@
+//! # Graph Traits
+//!
+//! Traits shared by the node, edge and graph types of the `digraph`,
+//! `ungraph`, `sync_digraph` and `sync_ungraph` modules. The traversal and
+//! path machinery in [`crate::algo`] is written against these traits, so an
+//! algorithm written once works on both `Rc` and `Arc` based graphs.
+//!
+//! - `GraphNode` is a handle to a node: its key and value.
+//! - `GraphEdge` gives access to the source, target and value of an edge.
+//! - `Neighbors` iterates over the edges adjacent to a node.
+//! - `GraphContainer` is a keyed collection of nodes.
+//!
+//! # Example
+//!
+//! ```
+//! use gdsl::traits::*;
+//!
+//! // Count the nodes reachable from `root` in any of the graph flavours.
+//! fn reachable<G: Neighbors>(root: &G) -> usize {
+//!     let mut visited = vec![root.key().clone()];
+//!     let mut stack = vec![root.clone()];
+//!     while let Some(node) = stack.pop() {
+//!         for edge in node.outbound() {
+//!             let v = edge.target();
+//!             if !visited.contains(v.key()) {
+//!                 visited.push(v.key().clone());
+//!                 stack.push(v.clone());
+//!             }
+//!         }
+//!     }
+//!     visited.len()
+//! }
+//!
+//! let a = gdsl::digraph::Node::new(1, ());
+//! let b = gdsl::digraph::Node::new(2, ());
+//! a.connect(&b, ());
+//!
+//! let c = gdsl::sync_ungraph::Node::new(1, ());
+//! let d = gdsl::sync_ungraph::Node::new(2, ());
+//! c.connect(&d, ());
+//!
+//! assert!(reachable(&a) == 2);
+//! assert!(reachable(&b) == 1);
+//! assert!(reachable(&d) == 2);
+//! ```
+
+use std::{fmt::Display, hash::Hash};
+
+/// A handle to a node. Handles are cheap to clone and two handles are equal
+/// if they point to nodes with the same key.
+pub trait GraphNode: Clone + PartialEq {
+    /// The key identifying the node.
+    type Key: Clone + Hash + Display + Eq;
+
+    /// The value stored in the node.
+    type Value: Clone;
+
+    /// The value stored in the node's edges.
+    type EdgeValue: Clone;
+
+    /// The edge type connecting nodes of this type.
+    type Edge: GraphEdge<Node = Self>;
+
+    /// Returns a reference to the node's key.
+    fn key(&self) -> &Self::Key;
+
+    /// Returns a reference to the node's value.
+    fn value(&self) -> &Self::Value;
+}
+
+/// Access to the endpoints and the value of an edge.
+pub trait GraphEdge: Clone {
+    /// The node type at either end of the edge.
+    type Node: GraphNode<Edge = Self>;
+
+    /// Returns the source node of the edge.
+    fn source(&self) -> &Self::Node;
+
+    /// Returns the target node of the edge.
+    fn target(&self) -> &Self::Node;
+
+    /// Returns the edge's value.
+    fn value(&self) -> &<Self::Node as GraphNode>::EdgeValue;
+
+    /// Returns the edge with its direction reversed.
+    fn reverse(&self) -> Self;
+}
+
+/// Iteration over the edges adjacent to a node.
+///
+/// Outbound edges have the node as their source and inbound edges have the
+/// node as their target. In an undirected graph every adjacent edge is both,
+/// so `outbound` and `inbound` yield the same edges in opposite orientation.
+pub trait Neighbors: GraphNode {
+    /// Whether the node belongs to a directed graph. If `false`, every edge
+    /// is reachable from both of its endpoints.
+    const DIRECTED: bool;
+
+    /// Iterator over the node's outbound edges.
+    type Outbound: Iterator<Item = Self::Edge>;
+
+    /// Iterator over the node's inbound edges.
+    type Inbound: Iterator<Item = Self::Edge>;
+
+    /// Returns an iterator over the node's outbound edges `Edge(self, v, e)`.
+    fn outbound(&self) -> Self::Outbound;
+
+    /// Returns an iterator over the node's inbound edges `Edge(u, self, e)`.
+    fn inbound(&self) -> Self::Inbound;
+}
+
+/// A graph container holding nodes by key.
+pub trait GraphContainer {
+    /// The node type stored in the graph.
+    type Node: GraphNode;
+
+    /// Returns the node with the given key.
+    fn get(&self, key: &<Self::Node as GraphNode>::Key) -> Option<Self::Node>;
+
+    /// Returns true if the graph contains a node with the given key.
+    fn contains(&self, key: &<Self::Node as GraphNode>::Key) -> bool;
+
+    /// Returns the amount of nodes in the graph.
+    fn len(&self) -> usize;
+
+    /// Returns true if the graph contains no nodes.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts a node into the graph. Returns false if a node with the same
+    /// key already exists.
+    fn insert(&mut self, node: Self::Node) -> bool;
+
+    /// Returns an iterator over the nodes of the graph in arbitrary order.
+    fn nodes(&self) -> impl Iterator<Item = &Self::Node>;
+}
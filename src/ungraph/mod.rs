@@ -11,6 +11,8 @@ use std::{
 
 use ahash::HashMap;
 
+use crate::traits::GraphContainer;
+
 pub use self::node::*;
 
 pub struct Graph<K, N, E>
@@ -231,6 +233,709 @@ where
         self.nodes.iter()
     }
 
+    /// Computes the shortest paths from the node with the key `source` to
+    /// every node reachable from it using Dijkstra's algorithm, see
+    /// [`Node::dijkstra`]. Returns `None` if the source is not in the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (char) => [u64]
+    ///     ('A') => [ ('B', 4), ('C', 1) ]
+    ///     ('B') => [ ('D', 1) ]
+    ///     ('C') => [ ('B', 2), ('D', 5) ]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let paths = g.dijkstra(&'A', |Edge(_, _, e)| *e).unwrap();
+    ///
+    /// assert!(paths.distance_to(&'D') == Some(4));
+    /// assert!(paths.predecessor(&'D').unwrap().source() == &g['B']);
+    /// assert!(g.dijkstra(&'X', |Edge(_, _, e)| *e).is_none());
+    /// ```
+    pub fn dijkstra<W, F>(&self, source: &K, weight: F) -> Option<ShortestPaths<K, N, E, W>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        self.nodes.get(source).map(|node| node.dijkstra(weight))
+    }
+
+    /// Splits the nodes of the graph into connected components: two nodes
+    /// are in the same component if there is a path between them. The
+    /// result holds the nodes of each component and maps the key of every
+    /// node to the id of its component, see [`Components`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (char)
+    ///     ('A') => ['B', 'C']
+    ///     ('B') => []
+    ///     ('C') => []
+    ///     ('D') => ['E']
+    ///     ('E') => []
+    /// ];
+    ///
+    /// let components = g.connected_components();
+    ///
+    /// assert!(components.len() == 2);
+    /// assert!(components.component(&'B') == components.component(&'C'));
+    /// assert!(components.component(&'B') != components.component(&'D'));
+    /// ```
+    pub fn connected_components(&self) -> Components<K, N, E> {
+        crate::algo::connected_components(self)
+    }
+
+    /// Returns the articulation points of the graph: the nodes whose
+    /// removal would disconnect the graph, or a component of it. The nodes
+    /// are returned in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// // Two rings of routers joined by the router 'C'.
+    /// let g = gdsl::ungraph![
+    ///     (char)
+    ///     ('A') => ['B', 'C']
+    ///     ('B') => ['C']
+    ///     ('C') => ['D', 'E']
+    ///     ('D') => ['E']
+    ///     ('E') => []
+    /// ];
+    ///
+    /// assert!(g.articulation_points() == vec![g['C'].clone()]);
+    /// ```
+    pub fn articulation_points(&self) -> Vec<Node<K, N, E>> {
+        crate::algo::articulation_points(self)
+    }
+
+    /// Returns the bridges of the graph: the edges whose removal would
+    /// disconnect their endpoints. Each bridge is returned once, in either
+    /// orientation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (char) => [u32]
+    ///     ('A') => [('B', 1), ('C', 2)]
+    ///     ('B') => [('C', 3)]
+    ///     ('C') => [('D', 4)]
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let bridges = g.bridges();
+    ///
+    /// assert!(bridges.len() == 1);
+    /// assert!(bridges[0].2 == 4);
+    /// ```
+    pub fn bridges(&self) -> Vec<Edge<K, N, E>> {
+        crate::algo::bridges(self)
+    }
+
+    /// Splits the edges of the graph into biconnected components: groups
+    /// of edges that stay connected when any single node is removed. A
+    /// bridge forms a component on its own. Loops are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (char)
+    ///     ('A') => ['B', 'C']
+    ///     ('B') => ['C']
+    ///     ('C') => ['D']
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let mut components = g.biconnected_components();
+    /// components.sort_by_key(|edges| edges.len());
+    ///
+    /// assert!(components.len() == 2);
+    /// assert!(components[0].len() == 1);
+    /// assert!(components[1].len() == 3);
+    /// ```
+    pub fn biconnected_components(&self) -> Vec<Vec<Edge<K, N, E>>> {
+        crate::algo::biconnected_components(self)
+    }
+
+    /// Finds a minimum spanning forest of the graph with Kruskal's
+    /// algorithm, using `weight` as the weight of each edge. The forest
+    /// holds a minimum spanning tree of every connected component, so it
+    /// has one edge less than the graph has nodes for every component. See
+    /// [`Graph::edge_subgraph`] to turn the edges into a graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize) => [u64]
+    ///     (0) => [ (1, 1), (3, 4), (4, 3) ]
+    ///     (1) => [ (3, 4), (4, 2) ]
+    ///     (2) => [ (4, 4), (5, 5) ]
+    ///     (3) => [ (4, 4) ]
+    ///     (4) => [ (5, 7) ]
+    ///     (5) => []
+    /// ];
+    ///
+    /// let forest = g.kruskal(|Edge(_, _, e)| *e);
+    ///
+    /// assert!(forest.len() == 5);
+    /// assert!(forest.iter().map(|Edge(_, _, e)| e).sum::<u64>() == 16);
+    /// ```
+    pub fn kruskal<W, F>(&self, weight: F) -> Vec<Edge<K, N, E>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        crate::algo::kruskal(self, weight)
+    }
+
+    /// Finds a minimum spanning forest of the graph with Prim's algorithm,
+    /// using `weight` as the weight of each edge. The result is the same as
+    /// [`Graph::kruskal`] up to ties, but edges are oriented away from the
+    /// root of their tree. See [`Graph::edge_subgraph`] to turn the edges
+    /// into a graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize) => [u64]
+    ///     (0) => [ (1, 8), (2, 5) ]
+    ///     (1) => [ (2, 10), (3, 2), (4, 18) ]
+    ///     (2) => [ (3, 3), (5, 16) ]
+    ///     (3) => [ (4, 12), (5, 30) ]
+    ///     (4) => [ (6, 4) ]
+    ///     (5) => [ (6, 26) ]
+    ///     (6) => []
+    /// ];
+    ///
+    /// let tree = g.edge_subgraph(&g.prim(|Edge(_, _, e)| *e));
+    ///
+    /// assert!(tree.len() == 7);
+    /// assert!(tree.connected_components().len() == 1);
+    /// assert!(tree[1].is_connected(&3) && !tree[1].is_connected(&0));
+    /// ```
+    pub fn prim<W, F>(&self, weight: F) -> Vec<Edge<K, N, E>>
+    where
+        W: Weight,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        crate::algo::prim(self, weight)
+    }
+
+    /// Checks whether the graph is bipartite, i.e. whether its nodes can be
+    /// split into two sides such that every edge connects the two sides.
+    /// Returns the two-colouring, or a cycle of odd length proving that no
+    /// such split exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let mut g = gdsl::ungraph![
+    ///     (&str)
+    ///     ("ann") => ["mon", "tue"]
+    ///     ("bob") => ["tue"]
+    ///     ("mon") => []
+    ///     ("tue") => []
+    /// ];
+    ///
+    /// let bipartition = g.bipartition().unwrap();
+    /// assert!(bipartition.colour(&"ann") == bipartition.colour(&"bob"));
+    /// assert!(bipartition.colour(&"ann") != bipartition.colour(&"tue"));
+    ///
+    /// g["mon"].connect(&g["tue"], ());
+    ///
+    /// let cycle = g.bipartition().unwrap_err().into_cycle();
+    /// assert!(cycle.len() == 4);
+    /// assert!(cycle.first_edge().unwrap().source() == cycle.last_edge().unwrap().target());
+    /// ```
+    pub fn bipartition(&self) -> Result<Bipartition<K, N, E>, OddCycle<K, N, E>> {
+        crate::algo::bipartition(self)
+    }
+
+    /// Finds a maximum cardinality matching of the bipartite graph with the
+    /// Hopcroft-Karp algorithm: a largest set of edges of which no two share
+    /// a node. The edges are oriented from the left side of
+    /// [`Graph::bipartition`] to its right side. Fails with an odd cycle if
+    /// the graph is not bipartite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// // Workers connected to the shifts they can take.
+    /// let g = gdsl::ungraph![
+    ///     (&str)
+    ///     ("ann") => ["mon", "tue"]
+    ///     ("bob") => ["mon"]
+    ///     ("cid") => ["mon", "wed"]
+    ///     ("mon") => []
+    ///     ("tue") => []
+    ///     ("wed") => []
+    /// ];
+    ///
+    /// let matching = g.hopcroft_karp().unwrap();
+    ///
+    /// assert!(matching.len() == 3);
+    /// assert!(matching.iter().any(|Edge(u, v, _)| {
+    ///     [u.key(), v.key()] == [&"bob", &"mon"] || [v.key(), u.key()] == [&"bob", &"mon"]
+    /// }));
+    /// ```
+    pub fn hopcroft_karp(&self) -> Result<Vec<Edge<K, N, E>>, OddCycle<K, N, E>> {
+        crate::algo::hopcroft_karp(self)
+    }
+
+    /// Solves the assignment problem with the Hungarian algorithm: assigns
+    /// as many of the nodes for which `left` returns true as possible to
+    /// distinct other nodes they are connected to, at the smallest total
+    /// `weight`. Unlike [`Graph::hopcroft_karp`], the graph does not need
+    /// to be bipartite, edges within a side are ignored. See
+    /// [`crate::algo::hungarian`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// // Workers connected to the shifts they can take, at a cost.
+    /// let g = gdsl::ungraph![
+    ///     (&str) => [u32]
+    ///     ("ann") => [ ("mon", 5), ("tue", 1) ]
+    ///     ("bob") => [ ("mon", 3), ("tue", 2) ]
+    ///     ("mon") => []
+    ///     ("tue") => []
+    /// ];
+    ///
+    /// let workers = ["ann", "bob"];
+    /// let assignment = g.hungarian(|node| workers.contains(node.key()), |Edge(_, _, e)| *e);
+    ///
+    /// assert!(assignment.cost() == 4);
+    /// assert!(assignment.edges().iter().all(|Edge(u, _, _)| workers.contains(u.key())));
+    /// ```
+    pub fn hungarian<W, P, F>(&self, left: P, weight: F) -> Assignment<K, N, E, W>
+    where
+        W: Weight,
+        P: FnMut(&Node<K, N, E>) -> bool,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        crate::algo::hungarian(self, left, weight)
+    }
+
+    /// Finds a maximum cardinality matching of the graph with Edmonds'
+    /// blossom algorithm: a largest set of edges of which no two share a
+    /// node. The graph does not need to be bipartite. Each matched pair is
+    /// returned as one edge between the two nodes. See
+    /// [`crate::algo::maximum_matching`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// // Peers in a mesh, paired up for an exchange.
+    /// let g = gdsl::ungraph![
+    ///     (char)
+    ///     ('A') => ['B', 'C']
+    ///     ('B') => ['C', 'D']
+    ///     ('C') => ['E']
+    ///     ('D') => ['E', 'F']
+    ///     ('E') => []
+    ///     ('F') => []
+    /// ];
+    ///
+    /// let matching = g.maximum_matching();
+    ///
+    /// assert!(matching.len() == 3);
+    /// ```
+    pub fn maximum_matching(&self) -> Vec<Edge<K, N, E>> {
+        crate::algo::maximum_matching(self)
+    }
+
+    /// Finds a matching of maximum total `weight` with the weighted version
+    /// of Edmonds' blossom algorithm. The matching does not need to be of
+    /// maximum cardinality, edges of negative weight are never chosen. See
+    /// [`crate::algo::maximum_weight_matching`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (char) => [i32]
+    ///     ('A') => [('B', 6), ('C', 4)]
+    ///     ('B') => [('C', 5), ('D', 4)]
+    ///     ('C') => []
+    ///     ('D') => []
+    /// ];
+    ///
+    /// let matching = g.maximum_weight_matching(|Edge(_, _, e)| *e);
+    ///
+    /// assert!(matching.len() == 2);
+    /// assert!(matching.iter().map(|Edge(_, _, e)| e).sum::<i32>() == 8);
+    /// ```
+    pub fn maximum_weight_matching<W, F>(&self, weight: F) -> Vec<Edge<K, N, E>>
+    where
+        W: Weight + std::ops::Div<Output = W> + From<u8>,
+        F: FnMut(&Edge<K, N, E>) -> W,
+    {
+        crate::algo::maximum_weight_matching(self, weight)
+    }
+
+    /// Prepares the computation of the betweenness centrality of every
+    /// node and edge with Brandes' algorithm, to be run with
+    /// [`Brandes::unweighted`] or [`Brandes::weighted`]. See
+    /// [`crate::algo::Brandes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize) => [u32]
+    ///     (0) => [ (1, 1), (2, 4) ]
+    ///     (1) => [ (2, 1) ]
+    ///     (2) => [ (3, 1) ]
+    ///     (3) => []
+    /// ];
+    ///
+    /// let betweenness = g.brandes().weighted(|Edge(_, _, e)| *e);
+    ///
+    /// assert!(betweenness.node(&1) == Some(2.0));
+    /// assert!(betweenness.edge(&0, &2) == Some(0.0));
+    /// ```
+    pub fn brandes(&self) -> Brandes<K, N, E> {
+        crate::algo::Brandes::new(self)
+    }
+
+    /// Computes the closeness centrality of every node, the inverse of the
+    /// average distance to the nodes it reaches scaled by the fraction of
+    /// nodes reached. See [`crate::algo::closeness_centrality`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize)
+    ///     (0) => [1]
+    ///     (1) => [2]
+    ///     (2) => [0]
+    /// ];
+    ///
+    /// let closeness = g.closeness_centrality();
+    ///
+    /// assert!(closeness.values().all(|&c| c == closeness[&0]));
+    /// ```
+    pub fn closeness_centrality(&self) -> ahash::AHashMap<K, f64> {
+        crate::algo::closeness_centrality(self)
+    }
+
+    /// Computes the harmonic centrality of every node, the sum of the
+    /// inverse distances to all other nodes. See
+    /// [`crate::algo::harmonic_centrality`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize)
+    ///     (0) => [1, 2]
+    ///     (1) => []
+    ///     (2) => []
+    /// ];
+    ///
+    /// let harmonic = g.harmonic_centrality();
+    ///
+    /// assert!(harmonic[&0] == 2.0);
+    /// ```
+    pub fn harmonic_centrality(&self) -> ahash::AHashMap<K, f64> {
+        crate::algo::harmonic_centrality(self)
+    }
+
+    /// Computes the eigenvector centrality of every node, weighting the
+    /// edges by `weight`. Iterates until the scores change by less than
+    /// `tolerance` in total, and fails if this takes more than `max_iter`
+    /// iterations. See [`crate::algo::eigenvector_centrality`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize) => [f64]
+    ///     (0) => [ (1, 1.0), (2, 1.0) ]
+    ///     (1) => [ (2, 1.0) ]
+    ///     (2) => [ (0, 1.0) ]
+    /// ];
+    ///
+    /// let scores = g.eigenvector_centrality(|Edge(_, _, e)| *e, 1e-12, 1000).unwrap();
+    ///
+    /// assert!(scores[&2] > scores[&1]);
+    /// ```
+    pub fn eigenvector_centrality<F>(
+        &self,
+        weight: F,
+        tolerance: f64,
+        max_iter: usize,
+    ) -> Result<ahash::AHashMap<K, f64>, NotConverged<K, N, E>>
+    where
+        F: FnMut(&Edge<K, N, E>) -> f64,
+    {
+        crate::algo::eigenvector_centrality(self, weight, tolerance, max_iter)
+    }
+
+    /// Computes the Katz centrality of every node, the score of a node
+    /// being `beta` plus `alpha` times the weighted scores of the nodes
+    /// linking to it. Iterates until the scores change by less than
+    /// `tolerance` in total, and fails if this takes more than `max_iter`
+    /// iterations. See [`crate::algo::katz_centrality`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize) => [f64]
+    ///     (0) => [ (1, 1.0) ]
+    ///     (1) => [ (2, 1.0) ]
+    ///     (2) => [ (0, 1.0) ]
+    /// ];
+    ///
+    /// let scores = g.katz_centrality(0.1, 1.0, |Edge(_, _, e)| *e, 1e-12, 100).unwrap();
+    ///
+    /// assert!(scores[&0] == scores[&1] && scores[&1] == scores[&2]);
+    /// assert!(g.katz_centrality(2.0, 1.0, |Edge(_, _, e)| *e, 1e-12, 100).is_err());
+    /// ```
+    pub fn katz_centrality<F>(
+        &self,
+        alpha: f64,
+        beta: f64,
+        weight: F,
+        tolerance: f64,
+        max_iter: usize,
+    ) -> Result<ahash::AHashMap<K, f64>, NotConverged<K, N, E>>
+    where
+        F: FnMut(&Edge<K, N, E>) -> f64,
+    {
+        crate::algo::katz_centrality(self, alpha, beta, weight, tolerance, max_iter)
+    }
+
+    /// Computes the modularity of a `partition` of the nodes into
+    /// communities, using `weight` as the weight of each edge. See
+    /// [`crate::algo::modularity`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize)
+    ///     (0) => [1]
+    ///     (1) => []
+    ///     (2) => [3]
+    ///     (3) => []
+    /// ];
+    ///
+    /// let partition = g.connected_components();
+    ///
+    /// assert!(g.modularity(&partition, |_| 1.0) == 0.5);
+    /// ```
+    pub fn modularity<F>(&self, partition: &Components<K, N, E>, weight: F) -> f64
+    where
+        F: FnMut(&Edge<K, N, E>) -> f64,
+    {
+        crate::algo::modularity(self, partition, weight)
+    }
+
+    /// Partitions the nodes into communities with the Louvain method, using
+    /// `weight` as the weight of each edge. The same `seed` always gives
+    /// the same partition. See [`crate::algo::louvain`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize) => [f64]
+    ///     (0) => [ (1, 5.0), (2, 5.0) ]
+    ///     (1) => [ (2, 5.0) ]
+    ///     (2) => [ (3, 1.0) ]
+    ///     (3) => [ (4, 5.0), (5, 5.0) ]
+    ///     (4) => [ (5, 5.0) ]
+    ///     (5) => []
+    /// ];
+    ///
+    /// let communities = g.louvain(|Edge(_, _, e)| *e, 1);
+    ///
+    /// assert!(communities.len() == 2);
+    /// assert!(g.modularity(&communities, |Edge(_, _, e)| *e) > 0.4);
+    /// ```
+    pub fn louvain<F>(&self, weight: F, seed: u64) -> Components<K, N, E>
+    where
+        K: Ord,
+        F: FnMut(&Edge<K, N, E>) -> f64,
+    {
+        crate::algo::louvain(self, weight, seed)
+    }
+
+    /// Partitions the nodes into communities with asynchronous label
+    /// propagation, using `weight` as the weight of each edge. The same
+    /// `seed` always gives the same partition. See
+    /// [`crate::algo::label_propagation`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize)
+    ///     (0) => [1, 2]
+    ///     (1) => [2]
+    ///     (2) => []
+    ///     (3) => [4]
+    ///     (4) => []
+    /// ];
+    ///
+    /// let communities = g.label_propagation(|_| 1.0, 1);
+    ///
+    /// assert!(communities.len() == 2);
+    /// assert!(communities.same_component(&0, &2));
+    /// ```
+    pub fn label_propagation<F>(&self, weight: F, seed: u64) -> Components<K, N, E>
+    where
+        K: Ord,
+        F: FnMut(&Edge<K, N, E>) -> f64,
+    {
+        crate::algo::label_propagation(self, weight, seed)
+    }
+
+    /// Colours the nodes greedily so that no two neighbours share a colour,
+    /// in the order given by `strategy`. Returns the colour of every node,
+    /// numbered from zero. See [`crate::algo::greedy_colouring`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// // An even cycle only needs two colours.
+    /// let g = gdsl::ungraph![
+    ///     (usize)
+    ///     (0) => [1]
+    ///     (1) => [2]
+    ///     (2) => [3]
+    ///     (3) => [0]
+    /// ];
+    ///
+    /// let colouring = g.greedy_colouring(ColouringStrategy::DSatur);
+    ///
+    /// assert!(colouring.values().all(|&c| c < 2));
+    /// assert!(colouring[&0] == colouring[&2]);
+    /// ```
+    pub fn greedy_colouring(&self, strategy: ColouringStrategy) -> ahash::AHashMap<K, usize> {
+        crate::algo::greedy_colouring(self, strategy)
+    }
+
+    /// Colours the nodes with as few colours as possible so that no two
+    /// neighbours share a colour, exploring at most `limit` partial
+    /// colourings. If the limit is reached, the best colouring found is
+    /// returned as the error. See [`crate::algo::chromatic_colouring`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize)
+    ///     (0) => [1, 2, 3]
+    ///     (1) => [2, 3]
+    ///     (2) => [3]
+    ///     (3) => []
+    ///     (4) => [0]
+    /// ];
+    ///
+    /// let colouring = g.chromatic_colouring(100).unwrap();
+    ///
+    /// assert!(colouring.values().max() == Some(&3));
+    /// ```
+    pub fn chromatic_colouring(
+        &self,
+        limit: usize,
+    ) -> Result<ahash::AHashMap<K, usize>, LimitReached<K, N, E>> {
+        crate::algo::chromatic_colouring(self, limit)
+    }
+
+    /// Builds a new graph with a copy of every node of this graph, with the
+    /// same key and value, connected only by the given `edges`. Edges
+    /// between nodes that are not in this graph are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gdsl::ungraph::*;
+    ///
+    /// let g = gdsl::ungraph![
+    ///     (usize) => [u64]
+    ///     (0) => [ (1, 1), (2, 2) ]
+    ///     (1) => [ (2, 3) ]
+    ///     (2) => []
+    /// ];
+    ///
+    /// let forest = g.edge_subgraph(&g.kruskal(|Edge(_, _, e)| *e));
+    ///
+    /// assert!(forest.len() == 3);
+    /// assert!(forest[0].is_connected(&1) && forest[0].is_connected(&2));
+    /// assert!(!forest[1].is_connected(&2));
+    ///
+    /// // The original graph is unchanged.
+    /// assert!(g[1].is_connected(&2));
+    /// ```
+    pub fn edge_subgraph(&self, edges: &[Edge<K, N, E>]) -> Graph<K, N, E> {
+        let mut graph = Graph::new();
+        for (key, node) in self.iter() {
+            graph.insert(Node::new(key.clone(), node.value().clone()));
+        }
+        for Edge(u, v, e) in edges {
+            if let (Some(u), Some(v)) = (graph.get(u.key()), graph.get(v.key())) {
+                u.connect(&v, e.clone());
+            }
+        }
+        graph
+    }
+
     pub fn to_dot(&self) -> String {
         let mut s = String::new();
         s.push_str("digraph {\n");
@@ -318,3 +1023,32 @@ where
         Self::new()
     }
 }
+
+impl<K, N, E> GraphContainer for Graph<K, N, E>
+where
+    K: Clone + Hash + Display + Eq,
+    N: Clone,
+    E: Clone,
+{
+    type Node = Node<K, N, E>;
+
+    fn get(&self, key: &K) -> Option<Node<K, N, E>> {
+        self.nodes.get(key).cloned()
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.nodes.contains_key(key)
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn insert(&mut self, node: Node<K, N, E>) -> bool {
+        Graph::insert(self, node)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Node<K, N, E>> {
+        self.nodes.values()
+    }
+}
@@ -286,6 +286,123 @@ fn ut_digraph_bfs_cycle_2() {
     assert!(cycle.last().unwrap() == &g[0]);
 }
 
+#[test]
+fn ut_digraph_bfs_iter() {
+    use gdsl::*;
+
+    let g = digraph![
+        (usize)
+        (0) => [1, 2, 3]
+        (1) => [3]
+        (2) => [4]
+        (3) => [2, 0]
+        (4) => []
+    ];
+
+    let mut bfs = g[0].bfs();
+    let first = bfs
+        .by_ref()
+        .take(2)
+        .map(|digraph::Edge(_, v, _)| *v.key())
+        .collect::<Vec<_>>();
+    let rest = bfs
+        .map(|digraph::Edge(_, v, _)| *v.key())
+        .collect::<Vec<_>>();
+
+    assert!(first == vec![1, 2]);
+    assert!(rest == vec![3, 4]);
+
+    let keys = g[0]
+        .bfs()
+        .target(&2)
+        .map(|digraph::Edge(_, v, _)| *v.key())
+        .collect::<Vec<_>>();
+
+    assert!(keys == vec![1, 2]);
+}
+
+#[test]
+fn ut_digraph_dfs_iter() {
+    use gdsl::*;
+
+    let g = digraph![
+        (usize)
+        (0) => [1, 2, 3]
+        (1) => [3]
+        (2) => [4]
+        (3) => [2, 0]
+        (4) => []
+    ];
+
+    let keys = g[0]
+        .dfs()
+        .map(|digraph::Edge(_, v, _)| *v.key())
+        .collect::<Vec<_>>();
+
+    assert!(keys == vec![1, 3, 2, 4]);
+
+    let keys = g[4]
+        .dfs()
+        .transpose()
+        .map(|digraph::Edge(_, v, _)| *v.key())
+        .take_while(|&key| key != 1)
+        .collect::<Vec<_>>();
+
+    assert!(keys == vec![2, 0, 3]);
+}
+
+#[test]
+fn ut_digraph_bfs_pipeline() {
+    use gdsl::digraph::*;
+    use gdsl::*;
+
+    let g = digraph![
+        (usize)
+        (0) => [1, 2, 3]
+        (1) => [3]
+        (2) => [4]
+        (3) => [2, 0]
+        (4) => []
+    ];
+
+    let mut seen = vec![];
+    let mut passed = vec![];
+
+    let keys = g[0]
+        .bfs()
+        .for_each(&mut |Edge(_, v, _)| seen.push(*v.key()))
+        .filter(&mut |Edge(_, v, _)| *v.key() != 2)
+        .for_each(&mut |Edge(_, v, _)| passed.push(*v.key()))
+        .map(|Edge(_, v, _)| *v.key())
+        .collect::<Vec<_>>();
+
+    assert!(keys == vec![1, 3]);
+    assert!(seen == vec![1, 2, 3, 3, 2, 0]);
+    assert!(passed == vec![1, 3, 3, 0]);
+
+    let keys = g[0]
+        .bfs()
+        .inspect(&mut |Edge(_, v, _)| match *v.key() {
+            3 => Control::Stop,
+            _ => Control::Continue,
+        })
+        .map(|Edge(_, v, _)| *v.key())
+        .collect::<Vec<_>>();
+
+    assert!(keys == vec![1, 2]);
+
+    let found = g[0]
+        .dfs()
+        .target(&4)
+        .inspect(&mut |Edge(u, _, _)| match *u.key() {
+            2 => Control::Prune,
+            _ => Control::Continue,
+        })
+        .search();
+
+    assert!(found.is_none());
+}
+
 #[test]
 fn ut_digraph_sizes() {
     use gdsl::digraph::*;
@@ -455,6 +572,29 @@ fn ut_digraph_order() {
     assert!(order[0] == n3);
     assert!(order[1] == n2);
     assert!(order[2] == n1);
+
+    let order = n1.postorder().nodes().collect::<Vec<_>>();
+
+    assert!(order == vec![n3.clone(), n2.clone(), n1.clone()]);
+
+    // Postorder follows inbound edges and yields the root last.
+    let n4 = Node::new(4, ());
+    n4.connect(&n1, ());
+
+    let order = n1.postorder().search_nodes();
+
+    assert!(order == vec![n3.clone(), n2.clone(), n4.clone(), n1.clone()]);
+
+    let order = n1.preorder().post().search_nodes();
+
+    assert!(order == vec![n3.clone(), n2.clone(), n1.clone()]);
+
+    let mut edges = n1.preorder();
+    let Edge(u, v, _) = edges.next().unwrap();
+
+    assert!(u == n1 && v == n2);
+    assert!(edges.next().unwrap().target() == &n3);
+    assert!(edges.next().is_none());
 }
 
 #[test]
@@ -582,3 +722,937 @@ fn ttt() {
     assert!(iter.next().unwrap() == Edge(n1.clone(), n2.clone(), ()));
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn ut_digraph_dijkstra_side_table() {
+    use gdsl::digraph::*;
+    use gdsl::*;
+
+    let g = digraph![
+        (char) => [u64]
+        ('A') => [ ('B', 4), ('H', 8) ]
+        ('B') => [ ('C', 8), ('H', 11) ]
+        ('C') => [ ('D', 7), ('F', 4), ('I', 2) ]
+        ('D') => [ ('E', 9), ('F', 14) ]
+        ('E') => []
+        ('F') => [ ('E', 10) ]
+        ('G') => [ ('F', 2), ('I', 6) ]
+        ('H') => [ ('G', 1), ('I', 7) ]
+        ('I') => []
+        ('X') => []
+    ];
+
+    let paths = g['A'].dijkstra(|Edge(_, _, e)| *e);
+
+    assert!(paths.source() == &g['A']);
+    assert!(paths.distance_to(&'A') == Some(0));
+    assert!(paths.distance_to(&'X').is_none());
+    assert!(paths.path_to(&'X').is_none());
+    assert!(paths.path_to(&'A').unwrap().is_empty());
+    assert!(paths.predecessor(&'A').is_none());
+    assert!(paths.tree_edges().count() == 8);
+
+    let path = paths.path_to(&'E').unwrap();
+    let weight = path.iter_edges().map(|Edge(_, _, e)| e).sum::<u64>();
+
+    assert!(Some(weight) == paths.distance_to(&'E'));
+    assert!(path.first_edge().unwrap().source() == &g['A']);
+    assert!(path.last_edge().unwrap().target() == &g['E']);
+}
+
+#[test]
+fn ut_digraph_pfs_by() {
+    use gdsl::digraph::*;
+    use gdsl::*;
+
+    // Node values are `f64`, which is not `Ord` and can not be used by `pfs()`.
+    let g = digraph![
+        (usize, f64) => [f64]
+        (0, 0.5) => [ (1, 1.5), (2, 0.5) ]
+        (1, 0.5) => [ (3, 1.0) ]
+        (2, 0.5) => [ (1, 0.5), (3, 3.0) ]
+        (3, 0.5) => [ (4, 1.0) ]
+        (4, 0.5) => []
+    ];
+
+    let order = g[0]
+        .pfs_by(0.0, |Edge(_, _, e), d| d + e)
+        .map(|(Edge(u, v, _), d)| (*u.key(), *v.key(), d))
+        .collect::<Vec<_>>();
+
+    assert!(order == vec![(0, 2, 0.5), (2, 1, 1.0), (1, 3, 2.0), (3, 4, 3.0)]);
+
+    let order = g[0]
+        .pfs_by(0.0, |Edge(_, _, e), d| d + e)
+        .max()
+        .map(|(Edge(_, v, _), _)| *v.key())
+        .collect::<Vec<_>>();
+
+    assert!(order == vec![1, 3, 4, 2]);
+
+    let mut pruned = 0;
+    let path = g[0]
+        .pfs_by(0.0, |Edge(_, _, e), d| d + e)
+        .target(&3)
+        .inspect(&mut |Edge(_, v, _)| match *v.key() {
+            1 => {
+                pruned += 1;
+                Control::Prune
+            }
+            _ => Control::Continue,
+        })
+        .search_path()
+        .unwrap()
+        .to_vec_nodes();
+
+    assert!(pruned == 2);
+    assert!(path == vec![g[0].clone(), g[2].clone(), g[3].clone()]);
+}
+
+#[test]
+fn ut_digraph_bellman_ford() {
+    use gdsl::digraph::*;
+    use gdsl::*;
+
+    let g = digraph![
+        (usize) => [i64]
+        (0) => [ (1, 6), (2, 7) ]
+        (1) => [ (2, 8), (3, 5), (4, -4) ]
+        (2) => [ (3, -3), (4, 9) ]
+        (3) => [ (1, -2) ]
+        (4) => [ (0, 2), (3, 7) ]
+        (5) => [ (6, -1) ]
+        (6) => [ (5, -1) ]
+    ];
+
+    // The negative cycle between 5 and 6 is not reachable from 0.
+    let bf = g.bellman_ford(&0, |Edge(_, _, e)| *e).unwrap().unwrap();
+    let spfa = g.spfa(&0, |Edge(_, _, e)| *e).unwrap().unwrap();
+
+    for (key, expect) in [(0, 0), (1, 2), (2, 7), (3, 4), (4, -2)] {
+        assert!(bf.distance_to(&key) == Some(expect));
+        assert!(spfa.distance_to(&key) == Some(expect));
+    }
+    assert!(bf.distance_to(&5).is_none());
+    assert!(spfa.distance_to(&5).is_none());
+
+    let path = bf.path_to(&4).unwrap().to_vec_nodes();
+    assert!(
+        path == vec![
+            g[0].clone(),
+            g[2].clone(),
+            g[3].clone(),
+            g[1].clone(),
+            g[4].clone()
+        ]
+    );
+
+    for source in [5, 6] {
+        let cycle = g
+            .bellman_ford(&source, |Edge(_, _, e)| *e)
+            .unwrap()
+            .unwrap_err();
+        let mut keys = cycle
+            .cycle()
+            .iter_nodes()
+            .map(|n| *n.key())
+            .collect::<Vec<_>>();
+        keys.sort();
+        assert!(keys == vec![5, 5, 6] || keys == vec![5, 6, 6]);
+
+        let cycle = g.spfa(&source, |Edge(_, _, e)| *e).unwrap().unwrap_err();
+        assert!(cycle.cycle().len() == 3);
+    }
+
+    assert!(g.bellman_ford(&7, |Edge(_, _, e)| *e).is_none());
+
+    // A negative self loop.
+    let n = Node::new(0, ());
+    n.connect(&n, -1);
+
+    let cycle = n.bellman_ford(|Edge(_, _, e)| *e).unwrap_err();
+    assert!(cycle.cycle().len() == 2);
+    assert!(format!("{}", cycle) == "Negative cycle: 0 -> 0");
+
+    let cycle = n.spfa(|Edge(_, _, e)| *e).unwrap_err();
+    assert!(cycle.cycle().len() == 2);
+}
+
+#[test]
+fn ut_digraph_all_pairs() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..10 {
+        // Random graphs with negative edges but no negative cycles: every
+        // edge goes forward in the key order or backward with a positive
+        // weight large enough to dominate any negative path.
+        let mut g = Graph::<usize, (), i64>::new();
+        for i in 0..20 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..60 {
+            let (u, v) = (rng.gen_range(0..20), rng.gen_range(0..20));
+            let w = match u < v {
+                true => rng.gen_range(-5..10),
+                false => rng.gen_range(100..120),
+            };
+            g[u].connect(&g[v], w);
+        }
+
+        let fw = g.floyd_warshall(|Edge(_, _, e)| *e).unwrap();
+        let jo = g.johnson(|Edge(_, _, e)| *e).unwrap();
+
+        assert!(fw.len() == 20 && jo.len() == 20);
+
+        for u in 0..20 {
+            let bf = g.bellman_ford(&u, |Edge(_, _, e)| *e).unwrap().unwrap();
+            for v in 0..20 {
+                assert!(fw.distance(&u, &v) == bf.distance_to(&v));
+                assert!(jo.distance(&u, &v) == bf.distance_to(&v));
+
+                for table in [&fw, &jo] {
+                    match table.path(&u, &v) {
+                        Some(path) => {
+                            let length = path.iter_edges().map(|Edge(_, _, e)| e).sum::<i64>();
+                            assert!(Some(length) == bf.distance_to(&v));
+                            if u != v {
+                                assert!(path.first_edge().unwrap().source() == &g[u]);
+                                assert!(path.last_edge().unwrap().target() == &g[v]);
+                            }
+                        }
+                        None => assert!(bf.distance_to(&v).is_none()),
+                    }
+                }
+            }
+        }
+    }
+
+    let g = gdsl::digraph![
+        (usize) => [i64]
+        (0) => [ (1, 1) ]
+        (1) => [ (2, -1) ]
+        (2) => [ (1, -1) ]
+        (3) => [ (3, 1) ]
+    ];
+
+    for cycle in [
+        g.floyd_warshall(|Edge(_, _, e)| *e).unwrap_err(),
+        g.johnson(|Edge(_, _, e)| *e).unwrap_err(),
+    ] {
+        let cycle = cycle.into_cycle();
+        assert!(cycle.len() == 3);
+        assert!(cycle.iter_edges().map(|Edge(_, _, e)| e).sum::<i64>() == -2);
+    }
+
+    // Distances around a dense negative cycle grow exponentially, which
+    // must be detected before they overflow.
+    let mut g = Graph::<usize, (), i32>::new();
+    for i in 0..40 {
+        g.insert(Node::new(i, ()));
+    }
+    for u in 0..40 {
+        for v in 0..40 {
+            if u != v {
+                g[u].connect(&g[v], -1);
+            }
+        }
+    }
+    let cycle = g
+        .floyd_warshall(|Edge(_, _, e)| *e)
+        .unwrap_err()
+        .into_cycle();
+    assert!(cycle.first_edge().unwrap().source() == cycle.last_edge().unwrap().target());
+    assert!(cycle.iter_edges().map(|Edge(_, _, e)| e).sum::<i32>() < 0);
+}
+
+#[test]
+fn ut_digraph_toposort() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..10 {
+        // Random acyclic graphs: every edge goes forward in the key order.
+        let mut g = Graph::<usize, (), ()>::new();
+        for i in 0..20 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..40 {
+            let u = rng.gen_range(0..19);
+            let v = rng.gen_range(u + 1..20);
+            g[u].connect(&g[v], ());
+        }
+
+        for order in [g.toposort().unwrap(), g.toposort_lexicographic().unwrap()] {
+            assert!(order.len() == 20);
+            let position = |key: &usize| order.iter().position(|n| n.key() == key);
+            for node in g.iter().map(|(_, n)| n) {
+                for Edge(u, v, _) in node {
+                    assert!(position(u.key()) < position(v.key()));
+                }
+            }
+        }
+
+        // No edge is forced between the smallest roots, so the lexicographic
+        // order starts with the smallest key.
+        let order = g.toposort_lexicographic().unwrap();
+        assert!(order[0].key() == &0);
+    }
+
+    let g = gdsl::digraph![
+        (usize)
+        (0) => [1]
+        (1) => [2]
+        (2) => [3]
+        (3) => [1]
+        (4) => [0]
+    ];
+
+    let error = g.toposort().err().unwrap();
+    assert!(error.cycle().len() == 4);
+    let cycle = error.cycle();
+    assert!(cycle.first_edge().unwrap().source() == cycle.last_edge().unwrap().target());
+    for Edge(u, _, _) in error.cycle().iter_edges() {
+        assert!([1, 2, 3].contains(u.key()));
+    }
+
+    let error = g.toposort_lexicographic().err().unwrap();
+    let rotations = ["1 -> 2 -> 3 -> 1", "2 -> 3 -> 1 -> 2", "3 -> 1 -> 2 -> 3"];
+    assert!(rotations.contains(&error.cycle().to_string().as_str()));
+    assert!(format!("{}", error).starts_with("Cycle: "));
+
+    let n = Node::new(0, ());
+    n.connect(&n, ());
+    let mut g = Graph::new();
+    g.insert(n);
+    assert!(g.toposort().err().unwrap().cycle().len() == 2);
+}
+
+#[test]
+fn ut_digraph_topological_generations() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..10 {
+        let mut g = Graph::<usize, (), ()>::new();
+        for i in 0..20 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..30 {
+            let u = rng.gen_range(0..19);
+            let v = rng.gen_range(u + 1..20);
+            g[u].connect(&g[v], ());
+        }
+
+        let earliest = g.topological_generations().unwrap();
+        let latest = g.topological_generations_latest().unwrap();
+        assert!(earliest.len() == latest.len());
+
+        for generations in [&earliest, &latest] {
+            assert!(generations.iter().map(|gen| gen.len()).sum::<usize>() == 20);
+            assert!(generations.iter().all(|gen| !gen.is_empty()));
+
+            let layer = |key: &usize| generations.iter().position(|gen| gen.contains(&g[*key]));
+            for (_, node) in g.iter() {
+                for Edge(u, v, _) in node {
+                    assert!(layer(u.key()) < layer(v.key()));
+                }
+            }
+        }
+
+        // Roots start the earliest generations and leaves end the latest.
+        for root in g.roots() {
+            assert!(earliest[0].contains(&root));
+        }
+        for leaf in g.leaves() {
+            assert!(latest.last().unwrap().contains(&leaf));
+        }
+    }
+
+    let g = gdsl::digraph![
+        (usize)
+        (0) => [1]
+        (1) => [2]
+        (2) => [1]
+    ];
+
+    for result in [
+        g.topological_generations(),
+        g.topological_generations_latest(),
+    ] {
+        let error = result.err().unwrap();
+        assert!(error.cycle().len() == 3);
+    }
+    assert!(Graph::<usize, (), ()>::new()
+        .topological_generations_latest()
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn ut_digraph_scc_tarjan() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..10 {
+        let mut g = Graph::<usize, (), ()>::new();
+        for i in 0..30 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..45 {
+            let (u, v) = (rng.gen_range(0..30), rng.gen_range(0..30));
+            g[u].connect(&g[v], ());
+        }
+
+        let reaches = |u: usize, v: usize| u == v || g[u].bfs().target(&v).search().is_some();
+        let scc = g.scc();
+        let id = |key: usize| scc.iter().position(|c| c.contains(&g[key])).unwrap();
+
+        assert!(scc.iter().map(|c| c.len()).sum::<usize>() == 30);
+        for u in 0..30 {
+            for v in 0..30 {
+                let mutual = reaches(u, v) && reaches(v, u);
+                assert!(mutual == (id(u) == id(v)));
+            }
+            for Edge(_, v, _) in &g[u] {
+                assert!(id(u) <= id(*v.key()));
+            }
+        }
+
+        let c = g.condensation();
+        assert!(c.len() == scc.len());
+        assert!(c.toposort().is_ok());
+        let edges = c
+            .iter()
+            .flat_map(|(_, n)| {
+                n.iter_out()
+                    .map(|Edge(_, _, e)| e.len())
+                    .collect::<Vec<_>>()
+            })
+            .sum::<usize>();
+        let crossing = (0..30)
+            .flat_map(|u| {
+                g[u].iter_out()
+                    .filter(move |Edge(_, v, _)| id(u) != id(*v.key()))
+            })
+            .count();
+        assert!(edges == crossing);
+    }
+
+    // A long cycle does not overflow the call stack.
+    let mut g = Graph::<usize, (), ()>::new();
+    for i in 0..20000 {
+        g.insert(Node::new(i, ()));
+    }
+    for i in 0..20000 {
+        g[i].connect(&g[(i + 1) % 20000], ());
+    }
+    assert!(g.scc().len() == 1);
+    assert!(g.condensation().len() == 1);
+}
+
+#[test]
+fn ut_digraph_weakly_connected_components() {
+    let g = gdsl::digraph![
+        (usize)
+        (0) => [1]
+        (1) => []
+        (2) => [1]
+        (3) => [4]
+        (4) => [5]
+        (5) => [3]
+        (6) => [6]
+    ];
+
+    let components = g.weakly_connected_components();
+    assert!(components.len() == 3);
+    assert!(components.same_component(&0, &2));
+    assert!(components.same_component(&3, &5));
+    assert!(!components.same_component(&2, &3));
+
+    let mut sizes = components
+        .into_groups()
+        .iter()
+        .map(Vec::len)
+        .collect::<Vec<_>>();
+    sizes.sort();
+    assert!(sizes == vec![1, 3, 3]);
+
+    // Weak components are unions of strong components.
+    let components = g.weakly_connected_components();
+    for scc in g.scc() {
+        let id = components.component(scc[0].key());
+        assert!(scc.iter().all(|n| components.component(n.key()) == id));
+    }
+}
+
+#[test]
+fn ut_digraph_max_flow() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..20 {
+        let mut g = Graph::<usize, (), u64>::new();
+        for i in 0..12 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..40 {
+            let (u, v) = (rng.gen_range(0..12), rng.gen_range(0..12));
+            g[u].connect(&g[v], rng.gen_range(0..20));
+        }
+
+        let capacity = |Edge(_, _, c): &Edge<usize, (), u64>| *c;
+        let flows = [
+            g.edmonds_karp(&0, &11, capacity).unwrap(),
+            g.dinic(&0, &11, capacity).unwrap(),
+            g.push_relabel(&0, &11, capacity).unwrap(),
+        ];
+
+        for flow in &flows {
+            assert!(flow.value() == flows[0].value());
+
+            // Capacities are respected and flow is conserved at every node
+            // other than the source and the sink.
+            let mut balance = [0i64; 12];
+            for (Edge(u, v, c), f) in flow.iter() {
+                assert!(f <= *c);
+                balance[*u.key()] -= f as i64;
+                balance[*v.key()] += f as i64;
+            }
+            assert!(balance[0] == -(flow.value() as i64));
+            assert!(balance[11] == flow.value() as i64);
+            assert!(balance[1..11].iter().all(|&b| b == 0));
+
+            // The cut separates source and sink, and its edges are
+            // saturated and add up to the value of the flow.
+            assert!(flow.is_source_side(&0) && !flow.is_source_side(&11));
+            let cut = flow.cut_edges();
+            assert!(cut.iter().map(|Edge(_, _, c)| c).sum::<u64>() == flow.value());
+            let (source_side, sink_side) = flow.min_cut();
+            assert!(source_side.len() + sink_side.len() == 12);
+            assert!(source_side.iter().all(|n| flow.is_source_side(n.key())));
+        }
+    }
+
+    let g = gdsl::digraph![
+        (usize) => [u64]
+        (0) => [ (1, 3), (1, 4) ]
+        (1) => []
+        (2) => []
+    ];
+
+    for flow in [
+        g.edmonds_karp(&0, &1, |Edge(_, _, c)| *c),
+        g.dinic(&0, &1, |Edge(_, _, c)| *c),
+        g.push_relabel(&0, &1, |Edge(_, _, c)| *c),
+    ] {
+        let flow = flow.unwrap();
+        assert!(flow.value() == 7);
+        assert!(flow.flow(&0, &1) == Some(7));
+        assert!(flow.flow(&1, &0).is_none());
+    }
+    assert!(g.dinic(&0, &2, |Edge(_, _, c)| *c).unwrap().value() == 0);
+    assert!(g.push_relabel(&0, &0, |Edge(_, _, c)| *c).unwrap().value() == 0);
+    assert!(g.edmonds_karp(&0, &9, |Edge(_, _, c)| *c).is_none());
+
+    // Rounding errors with fractional capacities leave excess flow behind
+    // that can not return to the source, which must not stall the search.
+    let g = gdsl::digraph![
+        (usize) => [f64]
+        (0) => [ (1, 0.1), (1, 0.2), (2, 1e16) ]
+        (1) => []
+        (2) => [ (3, 0.1) ]
+        (3) => [ (2, 1e16) ]
+        (4) => []
+    ];
+
+    assert!(g.push_relabel(&0, &4, |Edge(_, _, c)| *c).unwrap().value() == 0.0);
+    let flow = g.push_relabel(&0, &3, |Edge(_, _, c)| *c).unwrap();
+    assert!(flow.value() == 0.1);
+}
+
+#[test]
+fn ut_digraph_min_cost_flow() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    type G = Graph<usize, (), (i64, i64, i64)>;
+
+    // A flow within its bounds has minimum cost if its residual network
+    // has no cycle of negative cost.
+    fn is_optimal(g: &G, flow: &MinCostFlow<usize, (), (i64, i64, i64), i64>) -> bool {
+        let mut residual = Graph::<usize, (), i64>::new();
+        for i in 0..g.len() {
+            residual.insert(Node::new(i, ()));
+        }
+        for (Edge(u, v, (lower, capacity, cost)), f) in flow.iter() {
+            assert!(*lower <= f && f <= *capacity);
+            if f < *capacity {
+                residual[*u.key()].connect(&residual[*v.key()], *cost);
+            }
+            if f > *lower {
+                residual[*v.key()].connect(&residual[*u.key()], -cost);
+            }
+        }
+        residual.floyd_warshall(|Edge(_, _, e)| *e).is_ok()
+    }
+
+    fn balance(flow: &MinCostFlow<usize, (), (i64, i64, i64), i64>, n: usize) -> Vec<i64> {
+        let mut balance = vec![0; n];
+        for (Edge(u, v, _), f) in flow.iter() {
+            balance[*u.key()] -= f;
+            balance[*v.key()] += f;
+        }
+        balance
+    }
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut feasible = 0;
+
+    for _ in 0..20 {
+        let mut g = G::new();
+        for i in 0..10 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..30 {
+            let (u, v) = (rng.gen_range(0..10), rng.gen_range(0..10));
+            if u != v {
+                let lower = if rng.gen_bool(0.2) {
+                    rng.gen_range(0..3)
+                } else {
+                    0
+                };
+                let capacity = lower + rng.gen_range(0..10);
+                g[u].connect(&g[v], (lower, capacity, rng.gen_range(-5..10)));
+            }
+        }
+
+        // The lower bounds can be met if a maximum flow saturates the
+        // edges from a virtual source to the nodes receiving lower bounds
+        // and from the nodes sending them to a virtual sink.
+        let mut h = Graph::<usize, (), i64>::new();
+        for i in 0..12 {
+            h.insert(Node::new(i, ()));
+        }
+        let mut demand = 0;
+        for (_, node) in g.iter() {
+            for Edge(u, v, (lower, capacity, _)) in node {
+                h[*u.key()].connect(&h[*v.key()], capacity - lower);
+                h[10].connect(&h[*v.key()], lower);
+                h[*u.key()].connect(&h[11], lower);
+                demand += lower;
+            }
+        }
+        let saturated = h.dinic(&10, &11, |Edge(_, _, c)| *c).unwrap().value() == demand;
+
+        let circulation = g.min_cost_circulation(|e| e.2 .0, |e| e.2 .1, |e| e.2 .2);
+        assert!(circulation.is_some() == saturated);
+        if let Some(flow) = circulation {
+            feasible += 1;
+            assert!(balance(&flow, 10).iter().all(|&b| b == 0));
+            assert!(is_optimal(&g, &flow));
+            let cost = flow.iter().map(|(Edge(_, _, e), f)| f * e.2).sum::<i64>();
+            assert!(flow.cost() == cost && flow.value() == 0);
+        }
+    }
+    assert!(feasible > 0 && feasible < 20);
+
+    for _ in 0..20 {
+        let mut g = G::new();
+        for i in 0..10 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..30 {
+            let (u, v) = (rng.gen_range(0..10), rng.gen_range(0..10));
+            g[u].connect(&g[v], (0, rng.gen_range(0..10), rng.gen_range(0..10)));
+        }
+
+        let flow = g
+            .min_cost_max_flow(&0, &9, |e| e.2 .1, |e| e.2 .2)
+            .unwrap()
+            .unwrap();
+        let max_flow = g.dinic(&0, &9, |e| e.2 .1).unwrap();
+        assert!(flow.value() == max_flow.value());
+
+        let balance = balance(&flow, 10);
+        assert!(balance[0] == -flow.value() && balance[9] == flow.value());
+        assert!(balance[1..9].iter().all(|&b| b == 0));
+
+        // Forcing the maximum flow back from the sink to the source turns
+        // it into a circulation of the same cost.
+        g[9].connect(&g[0], (flow.value(), flow.value(), -1000));
+        let circulation = g
+            .min_cost_circulation(|e| e.2 .0, |e| e.2 .1, |e| e.2 .2)
+            .unwrap();
+        assert!(circulation.cost() == flow.cost() - 1000 * flow.value());
+    }
+
+    let g = gdsl::digraph![
+        (usize) => [(i64, i64)]
+        (0) => [ (1, (1, 1)) ]
+        (1) => [ (2, (1, -3)) ]
+        (2) => [ (1, (1, 1)), (3, (1, 1)) ]
+        (3) => []
+    ];
+    let cycle = g
+        .min_cost_max_flow(&0, &3, |e| e.2 .0, |e| e.2 .1)
+        .unwrap()
+        .err()
+        .unwrap();
+    assert!(cycle.cycle().len() == 3);
+    assert!(g
+        .min_cost_max_flow(&0, &7, |e| e.2 .0, |e| e.2 .1)
+        .is_none());
+}
+
+#[test]
+fn ut_digraph_link_analysis() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..10 {
+        let n = 30;
+        let mut g = Graph::<usize, (), ()>::new();
+        for i in 0..n {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..80 {
+            let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            g[u].connect(&g[v], ());
+        }
+
+        // The rank of every node is what flows into it along the edges, and
+        // from jumps and nodes without outbound edges.
+        let rank = g.page_rank(0.85, 1e-12, 1000);
+        assert!((rank.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        let dangling = (0..n)
+            .filter(|u| g[*u].out_degree() == 0)
+            .map(|u| rank[&u])
+            .sum::<f64>();
+        for v in 0..n {
+            let inflow = g[v]
+                .iter_in()
+                .map(|Edge(u, _, _)| rank[u.key()] / u.out_degree() as f64)
+                .sum::<f64>();
+            let expected = 0.85 * inflow + (0.85 * dangling + 0.15) / n as f64;
+            assert!((rank[&v] - expected).abs() < 1e-9);
+        }
+
+        // Jumps to a single node, and the rank of nodes without outbound
+        // edges with them, stay within what the node reaches.
+        let source = rng.gen_range(0..n);
+        let personal = g.personalized_page_rank(0.85, &[(source, 1.0)].into(), 1e-12, 1000);
+        let reachable = g[source].preorder().search_nodes();
+        let positive = personal.values().filter(|&&score| score > 0.0).count();
+        assert!(positive == reachable.len());
+        assert!(personal[&source] >= 0.15);
+
+        // At convergence the scores are each other's images under the
+        // adjacency matrix, up to scaling.
+        let (hubs, authorities) = g.hits(1e-12, 10000);
+        let image = |u: usize| {
+            g[u].iter_out()
+                .map(|Edge(_, v, _)| authorities[v.key()])
+                .sum::<f64>()
+        };
+        let scale = (0..n).map(image).sum::<f64>();
+        for u in 0..n {
+            assert!((hubs[&u] - image(u) / scale).abs() < 1e-6);
+        }
+        let image = |v: usize| {
+            g[v].iter_in()
+                .map(|Edge(u, _, _)| hubs[u.key()])
+                .sum::<f64>()
+        };
+        let scale = (0..n).map(image).sum::<f64>();
+        for v in 0..n {
+            assert!((authorities[&v] - image(v) / scale).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn ut_digraph_personalized_page_rank_negative_weight() {
+    let g = gdsl::digraph![
+        (usize)
+        (0) => [1]
+        (1) => [0]
+    ];
+
+    g.personalized_page_rank(0.85, &[(0, 1.0), (1, -1.0)].into(), 1e-9, 100);
+}
+
+#[test]
+fn ut_digraph_centrality() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..12);
+        let mut g = Graph::<usize, (), u64>::new();
+        for i in 0..n {
+            g.insert(Node::new(i, ()));
+        }
+        let mut weights = vec![vec![None; n]; n];
+        for _ in 0..rng.gen_range(0..30) {
+            let (u, v, w) = (
+                rng.gen_range(0..n),
+                rng.gen_range(0..n),
+                rng.gen_range(1..4),
+            );
+            if u != v && weights[u][v].is_none() {
+                g[u].connect(&g[v], w);
+                weights[u][v] = Some(w);
+            }
+        }
+
+        // Count the shortest paths between every pair of nodes by their
+        // lengths, relaxing through the nodes in the order of the distance.
+        let mut distance = vec![vec![u64::MAX; n]; n];
+        let mut count = vec![vec![0.0; n]; n];
+        for s in 0..n {
+            distance[s][s] = 0;
+            count[s][s] = 1.0;
+            let mut done = vec![false; n];
+            for _ in 0..n {
+                let v = (0..n)
+                    .filter(|&v| !done[v])
+                    .min_by_key(|&v| distance[s][v])
+                    .unwrap();
+                done[v] = true;
+                if distance[s][v] == u64::MAX {
+                    continue;
+                }
+                for w in 0..n {
+                    if let Some(len) = weights[v][w] {
+                        let d = distance[s][v] + len;
+                        if !done[w] && d < distance[s][w] {
+                            distance[s][w] = d;
+                            count[s][w] = count[s][v];
+                        } else if !done[w] && d == distance[s][w] {
+                            count[s][w] += count[s][v];
+                        }
+                    }
+                }
+            }
+        }
+        let on_path = |s: usize, v: usize, t: usize| {
+            distance[s][v] != u64::MAX
+                && distance[v][t] != u64::MAX
+                && distance[s][v] + distance[v][t] == distance[s][t]
+        };
+
+        let betweenness = g.brandes().weighted(|Edge(_, _, w)| *w);
+        for v in 0..n {
+            let mut expected = 0.0;
+            for s in (0..n).filter(|&s| s != v) {
+                for t in (0..n).filter(|&t| t != v && t != s) {
+                    if on_path(s, v, t) {
+                        expected += count[s][v] * count[v][t] / count[s][t];
+                    }
+                }
+            }
+            assert!((betweenness.node(&v).unwrap() - expected).abs() < 1e-9);
+        }
+        for (Edge(u, v, w), score) in betweenness.edges() {
+            let (u, v) = (*u.key(), *v.key());
+            let mut expected = 0.0;
+            for s in 0..n {
+                for t in 0..n {
+                    if on_path(s, u, t)
+                        && on_path(u, v, t)
+                        && distance[s][u] + w + distance[v][t] == distance[s][t]
+                    {
+                        expected += count[s][u] * count[v][t] / count[s][t];
+                    }
+                }
+            }
+            assert!((score - expected).abs() < 1e-9);
+        }
+        assert!(betweenness.edges().len() == weights.iter().flatten().flatten().count());
+
+        // Sampling every node is exact, and normalizing divides by the
+        // number of pairs.
+        let sampled = g.brandes().sample(n, 3).weighted(|Edge(_, _, w)| *w);
+        let normalized = g.brandes().normalized(true).weighted(|Edge(_, _, w)| *w);
+        for v in 0..n {
+            let exact = betweenness.node(&v).unwrap();
+            assert!((sampled.node(&v).unwrap() - exact).abs() < 1e-9);
+            if n > 2 {
+                let pairs = ((n - 1) * (n - 2)) as f64;
+                assert!((normalized.node(&v).unwrap() * pairs - exact).abs() < 1e-9);
+            }
+        }
+
+        // Without weights every edge counts as one.
+        let hops = g.brandes().unweighted();
+        let ones = g.brandes().weighted(|_| 1);
+        for v in 0..n {
+            assert!((hops.node(&v).unwrap() - ones.node(&v).unwrap()).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn ut_digraph_katz_centrality() {
+    use gdsl::digraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..20);
+        let mut g = Graph::<usize, (), f64>::new();
+        for i in 0..n {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..rng.gen_range(0..40) {
+            let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            if u != v {
+                g[u].connect(&g[v], rng.gen_range(1..5) as f64);
+            }
+        }
+
+        // Inbound edges count, and every node gets the same constant on
+        // top, scaled along with the scores.
+        let alpha = 0.01;
+        let katz = g
+            .katz_centrality(alpha, 1.0, |Edge(_, _, w)| *w, 1e-12, 1000)
+            .unwrap();
+        let norm = katz.values().map(|x| x * x).sum::<f64>();
+        assert!((norm - 1.0).abs() < 1e-9);
+        let beta = (0..n)
+            .map(|v| {
+                let inflow = g[v]
+                    .iter_in()
+                    .map(|Edge(u, _, w)| w * katz[u.key()])
+                    .sum::<f64>();
+                katz[&v] - alpha * inflow
+            })
+            .collect::<Vec<_>>();
+        assert!(beta.iter().all(|b| (b - beta[0]).abs() < 1e-9));
+
+        // Nodes without inbound edges have the smallest score.
+        let least = katz.values().cloned().fold(f64::INFINITY, f64::min);
+        for v in 0..n {
+            if g[v].iter_in().next().is_none() {
+                assert!((katz[&v] - least).abs() < 1e-12);
+            }
+        }
+    }
+}
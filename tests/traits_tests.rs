@@ -0,0 +1,247 @@
+use gdsl::algo::Bfs;
+use gdsl::traits::*;
+
+// Collects the keys of the nodes reachable from `root` using only the
+// shared traits.
+fn reachable<G>(root: &G) -> Vec<G::Key>
+where
+    G: Neighbors,
+    G::Key: Ord,
+{
+    let mut keys = vec![root.key().clone()];
+    Bfs::new(root)
+        .for_each(&mut |edge| {
+            if !keys.contains(edge.target().key()) {
+                keys.push(edge.target().key().clone());
+            }
+        })
+        .search();
+    keys.sort();
+    keys
+}
+
+fn count_edges<C>(g: &C) -> usize
+where
+    C: GraphContainer,
+    C::Node: Neighbors,
+{
+    g.nodes().map(|node| node.outbound().count()).sum()
+}
+
+#[test]
+fn ut_traits_digraph() {
+    use gdsl::digraph::*;
+
+    let g = gdsl::digraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [3]
+        (2) => [3]
+        (3) => []
+        (4) => [0]
+    ];
+
+    assert!(reachable(&g[0]) == vec![0, 1, 2, 3]);
+    assert!(count_edges(&g) == 5);
+
+    let Edge(u, v, _) = g[3].inbound().next().unwrap();
+    assert!(u == g[1] && v == g[3]);
+    assert!(GraphEdge::reverse(&Edge(u.clone(), v.clone(), ())).source() == &v);
+}
+
+#[test]
+fn ut_traits_sync_digraph() {
+    let g = gdsl::sync_digraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [3]
+        (2) => [3]
+        (3) => []
+        (4) => [0]
+    ];
+
+    assert!(reachable(&g[0]) == vec![0, 1, 2, 3]);
+    assert!(count_edges(&g) == 5);
+}
+
+#[test]
+fn ut_traits_ungraph() {
+    use gdsl::ungraph::*;
+
+    let g = gdsl::ungraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [3]
+        (2) => []
+        (3) => []
+        (4) => []
+    ];
+
+    assert!(reachable(&g[3]) == vec![0, 1, 2, 3]);
+    assert!(count_edges(&g) == 6);
+
+    for Edge(u, v, _) in g[1].inbound() {
+        assert!(v == g[1]);
+        assert!(u == g[0] || u == g[3]);
+    }
+}
+
+#[test]
+fn ut_traits_sync_ungraph() {
+    let g = gdsl::sync_ungraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [3]
+        (2) => []
+        (3) => []
+        (4) => []
+    ];
+
+    assert!(reachable(&g[3]) == vec![0, 1, 2, 3]);
+    assert!(count_edges(&g) == 6);
+    assert!(GraphContainer::get(&g, &4)
+        .unwrap()
+        .outbound()
+        .next()
+        .is_none());
+}
+
+// Counts tree, back and forward or cross edges of a depth-first traversal.
+#[derive(Default)]
+struct EdgeCounts(usize, usize, usize);
+
+impl<G: GraphNode> gdsl::algo::DfsVisitor<G> for EdgeCounts {
+    fn tree_edge(&mut self, _: &G::Edge) {
+        self.0 += 1;
+    }
+
+    fn back_edge(&mut self, _: &G::Edge) {
+        self.1 += 1;
+    }
+
+    fn forward_or_cross_edge(&mut self, _: &G::Edge) {
+        self.2 += 1;
+    }
+}
+
+#[test]
+fn ut_traits_dfs_visit() {
+    let g = gdsl::sync_digraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [2]
+        (2) => [0]
+    ];
+
+    let mut counts = EdgeCounts::default();
+    g[0].dfs().visit(&mut counts);
+    assert!((counts.0, counts.1, counts.2) == (2, 1, 1));
+
+    let g = gdsl::sync_ungraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [2]
+        (2) => []
+    ];
+
+    let mut counts = EdgeCounts::default();
+    g[0].dfs().visit(&mut counts);
+    assert!((counts.0, counts.1, counts.2) == (2, 1, 0));
+}
+
+#[test]
+fn ut_traits_topological_generations() {
+    let g = gdsl::sync_digraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [3]
+        (2) => []
+        (3) => []
+        (4) => [3]
+    ];
+
+    let sizes = |generations: Vec<Vec<_>>| generations.iter().map(Vec::len).collect::<Vec<_>>();
+    assert!(sizes(g.topological_generations().unwrap()) == vec![2, 2, 1]);
+    assert!(sizes(g.topological_generations_latest().unwrap()) == vec![1, 2, 2]);
+
+    g[3].connect(&g[0], ());
+    let cycle = g.topological_generations().err().unwrap().into_cycle();
+    assert!(cycle.len() == 4);
+}
+
+#[test]
+fn ut_traits_condensation() {
+    let g = gdsl::sync_digraph![
+        (usize)
+        (0) => [1]
+        (1) => [0, 2]
+        (2) => [3]
+        (3) => [2, 4]
+        (4) => []
+    ];
+
+    let scc = g.scc();
+    assert!(scc.len() == 3);
+    assert!(scc[0].contains(&g[0]) && scc[0].contains(&g[1]));
+    assert!(scc[2] == vec![g[4].clone()]);
+
+    let c = g.condensation();
+    assert!(c.len() == 3);
+    assert!(c[0].is_connected(&1) && c[1].is_connected(&2));
+    assert!(!c[0].is_connected(&2));
+}
+
+#[test]
+fn ut_traits_components() {
+    let g = gdsl::sync_ungraph![
+        (usize)
+        (0) => [1]
+        (1) => []
+        (2) => [3]
+        (3) => []
+    ];
+
+    let components = g.connected_components();
+    assert!(components.len() == 2);
+    assert!(components.same_component(&1, &0));
+    assert!(!components.same_component(&1, &2));
+    assert!(!components.same_component(&1, &4));
+
+    let g = gdsl::sync_digraph![
+        (usize)
+        (0) => [2]
+        (1) => [2]
+        (2) => []
+        (3) => []
+    ];
+
+    let components = g.weakly_connected_components();
+    assert!(components.len() == 2);
+    assert!(components[components.component(&0).unwrap()].len() == 3);
+}
+
+#[test]
+fn ut_traits_biconnected() {
+    let g = gdsl::sync_ungraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [2]
+        (2) => [3]
+        (3) => [4, 5]
+        (4) => [5]
+        (5) => []
+        (6) => [6]
+    ];
+
+    let mut points = g.articulation_points();
+    points.sort_by_key(|node| *node.key());
+    assert!(points == vec![g[2].clone(), g[3].clone()]);
+
+    let bridges = g.bridges();
+    assert!(bridges.len() == 1);
+    assert!(bridges[0].0.key() + bridges[0].1.key() == 5);
+
+    let mut components = g.biconnected_components();
+    components.sort_by_key(|edges| edges.len());
+    assert!(components.iter().map(Vec::len).collect::<Vec<_>>() == vec![1, 3, 3]);
+}
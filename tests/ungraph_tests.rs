@@ -165,6 +165,35 @@ fn ut_ungraph_isolate() {
 
 // TEST Dfs
 
+#[test]
+fn ut_ungraph_order() {
+    use gdsl::ungraph::*;
+
+    let n1 = Node::new(1, ());
+    let n2 = Node::new(2, ());
+    let n3 = Node::new(3, ());
+
+    n1.connect(&n2, ());
+    n2.connect(&n3, ());
+
+    // Edges are yielded from the child to the parent, whichever end they
+    // were connected from.
+    let edges = n3.order().search_edges();
+
+    assert!(edges.len() == 2);
+    assert!(edges[0].source() == &n2 && edges[0].target() == &n3);
+    assert!(edges[1].source() == &n1 && edges[1].target() == &n2);
+
+    let edges = n3.order().post().search_edges();
+
+    assert!(edges[0].source() == &n1 && edges[0].target() == &n2);
+    assert!(edges[1].source() == &n2 && edges[1].target() == &n3);
+
+    let order = n3.order().post().search_nodes();
+
+    assert!(order == vec![n1.clone(), n2.clone(), n3.clone()]);
+}
+
 #[test]
 fn ut_ungraph_dfs_find_1() {
     use gdsl::*;
@@ -356,3 +385,962 @@ fn ut_ungraph_dijkstra() {
     assert!(g['H'].get() == 8);
     assert!(g['I'].get() == 14);
 }
+
+#[test]
+fn ut_ungraph_pfs_iter() {
+    use gdsl::ungraph::*;
+    use gdsl::*;
+
+    let g = ungraph![
+        (usize, usize)
+        (0, 0) => [1, 2]
+        (1, 5) => [3]
+        (2, 1) => [4]
+        (3, 0) => []
+        (4, 0) => []
+    ];
+
+    let keys = g[0].pfs().map(|Edge(_, v, _)| *v.key()).collect::<Vec<_>>();
+
+    assert!(keys == vec![1, 2, 4, 3]);
+
+    let keys = g[0]
+        .pfs()
+        .max()
+        .map(|Edge(_, v, _)| *v.key())
+        .collect::<Vec<_>>();
+
+    assert!(keys == vec![1, 2, 3, 4]);
+
+    let mut pfs = g[3].pfs().target(&4);
+    let path = pfs
+        .by_ref()
+        .map(|Edge(_, v, _)| *v.key())
+        .collect::<Vec<_>>();
+
+    assert!(path == vec![1, 0, 2, 4]);
+    assert!(pfs.next().is_none());
+}
+
+#[test]
+fn ut_ungraph_dfs_visit() {
+    use gdsl::ungraph::*;
+    use gdsl::*;
+
+    #[derive(Default)]
+    struct Classify {
+        tree: Vec<(usize, usize)>,
+        back: Vec<(usize, usize)>,
+        other: usize,
+        times: Vec<(usize, usize, usize)>,
+    }
+
+    impl DfsVisitor<Node<usize, (), ()>> for Classify {
+        fn discover_node(&mut self, node: &Node<usize, (), ()>, time: usize) {
+            self.times.push((*node.key(), time, 0));
+        }
+
+        fn tree_edge(&mut self, Edge(u, v, _): &Edge<usize, (), ()>) {
+            self.tree.push((*u.key(), *v.key()));
+        }
+
+        fn back_edge(&mut self, Edge(u, v, _): &Edge<usize, (), ()>) {
+            self.back.push((*u.key(), *v.key()));
+        }
+
+        fn forward_or_cross_edge(&mut self, _: &Edge<usize, (), ()>) {
+            self.other += 1;
+        }
+
+        fn finish_node(&mut self, node: &Node<usize, (), ()>, time: usize) {
+            let entry = self.times.iter_mut().find(|(k, _, _)| k == node.key());
+            entry.unwrap().2 = time;
+        }
+    }
+
+    // A triangle 0-1-2 with a pendant node 3 attached to 2.
+    let g = ungraph![
+        (usize)
+        (0) => [1, 2]
+        (1) => [2]
+        (2) => [3]
+        (3) => []
+    ];
+
+    let mut visitor = Classify::default();
+    g[0].dfs().visit(&mut visitor);
+
+    assert!(visitor.tree == vec![(0, 1), (1, 2), (2, 3)]);
+    assert!(visitor.back == vec![(2, 0)]);
+    assert!(visitor.other == 0);
+    assert!(visitor.times == vec![(0, 0, 7), (1, 1, 6), (2, 2, 5), (3, 3, 4)]);
+}
+
+#[test]
+fn ut_ungraph_dijkstra_side_table() {
+    use gdsl::ungraph::*;
+    use gdsl::*;
+
+    let g = ungraph![
+        (char) => [u64]
+        ('A') => [ ('B', 4), ('H', 8) ]
+        ('B') => [ ('C', 8), ('H', 11) ]
+        ('C') => [ ('D', 7), ('F', 4), ('I', 2) ]
+        ('D') => [ ('E', 9), ('F', 14) ]
+        ('E') => []
+        ('F') => [ ('E', 10) ]
+        ('G') => [ ('F', 2), ('I', 6) ]
+        ('H') => [ ('G', 1), ('I', 7) ]
+        ('I') => []
+        ('X') => []
+    ];
+
+    let paths = g['A'].dijkstra(|Edge(_, _, e)| *e);
+
+    assert!(paths.source() == &g['A']);
+    assert!(paths.distance_to(&'A') == Some(0));
+    assert!(paths.distance_to(&'X').is_none());
+    assert!(paths.path_to(&'X').is_none());
+    assert!(paths.path_to(&'A').unwrap().is_empty());
+    assert!(paths.predecessor(&'A').is_none());
+    assert!(paths.tree_edges().count() == 8);
+
+    let path = paths.path_to(&'E').unwrap();
+    let weight = path.iter_edges().map(|Edge(_, _, e)| e).sum::<u64>();
+
+    assert!(Some(weight) == paths.distance_to(&'E'));
+    assert!(path.first_edge().unwrap().source() == &g['A']);
+    assert!(path.last_edge().unwrap().target() == &g['E']);
+}
+
+#[test]
+fn ut_ungraph_astar() {
+    use gdsl::ungraph::*;
+
+    // A 5x5 grid with a wall in the middle column, except for the top row.
+    let mut g = Graph::<usize, (i64, i64), u64>::new();
+    for i in 0..25 {
+        g.insert(Node::new(i, (i as i64 % 5, i as i64 / 5)));
+    }
+    for i in 0..25 {
+        let wall = |j: usize| j % 5 == 2 && j / 5 > 0;
+        if i % 5 < 4 && !wall(i) && !wall(i + 1) {
+            g[i].connect(&g[i + 1], 1 + (i as u64 % 3));
+        }
+        if i / 5 < 4 && !wall(i) && !wall(i + 5) {
+            g[i].connect(&g[i + 5], 1 + (i as u64 % 2));
+        }
+    }
+    g.insert(Node::new(25, (9, 9)));
+
+    let target = 24;
+    let manhattan = |node: &Node<usize, (i64, i64), u64>| {
+        let (x, y) = *node.value();
+        ((4 - x).abs() + (4 - y).abs()) as u64
+    };
+
+    let dijkstra = g[0].dijkstra(|Edge(_, _, e)| *e);
+    let (path, cost) = g[0]
+        .astar(|Edge(_, _, e)| *e, manhattan)
+        .target(&target)
+        .search_path()
+        .unwrap();
+
+    assert!(Some(cost) == dijkstra.distance_to(&target));
+    assert!(path.iter_edges().map(|Edge(_, _, e)| e).sum::<u64>() == cost);
+    assert!(path.last_edge().unwrap().target() == &g[target]);
+
+    // A zero heuristic finds the same cost.
+    let (_, zero) = g[0]
+        .astar(|Edge(_, _, e)| *e, |_| 0)
+        .target(&target)
+        .search_path()
+        .unwrap();
+
+    assert!(zero == cost);
+
+    // An admissible but inconsistent heuristic still finds the optimum.
+    let (_, inconsistent) = g[0]
+        .astar(
+            |Edge(_, _, e)| *e,
+            |node| match *node.key() % 2 {
+                0 => manhattan(node),
+                _ => 0,
+            },
+        )
+        .target(&target)
+        .search_path()
+        .unwrap();
+
+    assert!(inconsistent == cost);
+
+    let (path, cost) = g[0]
+        .astar(|Edge(_, _, e)| *e, |_| 0)
+        .target(&0)
+        .search_path()
+        .unwrap();
+
+    assert!(path.is_empty() && cost == 0);
+    assert!(g[0]
+        .astar(|Edge(_, _, e)| *e, |_| 0)
+        .target(&25)
+        .search_path()
+        .is_none());
+    assert!(g[0]
+        .astar(|Edge(_, _, e)| *e, |_| 0)
+        .search_path()
+        .is_none());
+}
+
+#[test]
+fn ut_ungraph_connected_components() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..10 {
+        let mut g = Graph::<usize, (), ()>::new();
+        for i in 0..30 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..20 {
+            let (u, v) = (rng.gen_range(0..30), rng.gen_range(0..30));
+            g[u].connect(&g[v], ());
+        }
+
+        let components = g.connected_components();
+        assert!(components.ids().count() == 30);
+        assert!(components.groups().iter().map(|c| c.len()).sum::<usize>() == 30);
+
+        for u in 0..30 {
+            let id = components.component(&u).unwrap();
+            assert!(components[id].contains(&g[u]));
+            for v in 0..30 {
+                let connected = u == v || g[u].bfs().target(&v).search().is_some();
+                assert!(connected == components.same_component(&u, &v));
+            }
+        }
+    }
+
+    let g = Graph::<usize, (), ()>::new();
+    assert!(g.connected_components().is_empty());
+    assert!(g.connected_components().component(&0).is_none());
+}
+
+#[test]
+fn ut_ungraph_biconnected() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..10 {
+        let mut g = Graph::<usize, (), ()>::new();
+        for i in 0..20 {
+            g.insert(Node::new(i, ()));
+        }
+        let mut count = 0;
+        for _ in 0..25 {
+            let (u, v) = (rng.gen_range(0..20), rng.gen_range(0..20));
+            if u != v && !g[u].is_connected(&v) {
+                g[u].connect(&g[v], ());
+                count += 1;
+            }
+        }
+
+        // Counts the nodes reachable from `root` without using `node` or
+        // the edge between `u` and `v`.
+        let reachable = |root: usize, node: Option<usize>, edge: Option<(usize, usize)>| {
+            let mut keys = vec![root];
+            g[root]
+                .bfs()
+                .filter(&mut |Edge(u, v, _)| {
+                    let (u, v) = (*u.key(), *v.key());
+                    Some(v) != node && edge != Some((u, v)) && edge != Some((v, u))
+                })
+                .for_each(&mut |Edge(_, v, _)| keys.push(*v.key()))
+                .search();
+            keys.sort();
+            keys.dedup();
+            keys.len()
+        };
+
+        let points = g.articulation_points();
+        for u in 0..20 {
+            let Some(Edge(_, neighbor, _)) = g[u].iter().next() else {
+                continue;
+            };
+            let before = reachable(*neighbor.key(), None, None) - 1;
+            let after = reachable(*neighbor.key(), Some(u), None);
+            assert!(points.contains(&g[u]) == (after < before));
+        }
+
+        let bridges = g.bridges();
+        for (_, node) in g.iter() {
+            for Edge(u, v, _) in node.iter() {
+                let (u, v) = (*u.key(), *v.key());
+                let is_bridge = bridges.iter().any(|Edge(s, t, _)| {
+                    (*s.key(), *t.key()) == (u, v) || (*s.key(), *t.key()) == (v, u)
+                });
+                let separated = reachable(u, None, Some((u, v))) < reachable(u, None, None);
+                assert!(is_bridge == separated);
+            }
+        }
+
+        let components = g.biconnected_components();
+        assert!(components.iter().map(|c| c.len()).sum::<usize>() == count);
+        assert!(components.iter().filter(|c| c.len() == 1).count() == bridges.len());
+        for component in &components {
+            for Edge(u, v, _) in component {
+                assert!(u.is_connected(v.key()));
+            }
+        }
+    }
+}
+
+#[test]
+fn ut_ungraph_minimum_spanning_forest() {
+    use gdsl::algo::UnionFind;
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..10 {
+        let mut g = Graph::<usize, (), u64>::new();
+        for i in 0..20 {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..40 {
+            let (u, v) = (rng.gen_range(0..20), rng.gen_range(0..20));
+            if u != v && !g[u].is_connected(&v) {
+                g[u].connect(&g[v], rng.gen_range(1..100));
+            }
+        }
+        let components = g.connected_components().len();
+
+        let kruskal = g.kruskal(|Edge(_, _, e)| *e);
+        let prim = g.prim(|Edge(_, _, e)| *e);
+        let total = |edges: &[Edge<usize, (), u64>]| edges.iter().map(|e| e.2).sum::<u64>();
+        assert!(total(&kruskal) == total(&prim));
+
+        for forest in [&kruskal, &prim] {
+            assert!(forest.len() == 20 - components);
+
+            // The forest is acyclic and spans every component.
+            let mut sets = UnionFind::new(20);
+            for Edge(u, v, _) in forest.iter() {
+                assert!(sets.union(*u.key(), *v.key()));
+            }
+            assert!(sets.count() == components);
+
+            let f = g.edge_subgraph(forest);
+            assert!(f.len() == 20);
+            assert!(f.connected_components().len() == components);
+            assert!(f.biconnected_components().iter().all(|c| c.len() == 1));
+        }
+
+        // Every edge not in the forest is at least as heavy as every edge
+        // on the forest path between its endpoints.
+        let f = g.edge_subgraph(&kruskal);
+        for (_, node) in g.iter() {
+            for Edge(u, v, e) in node {
+                let path = f[*u.key()].bfs().target(v.key()).search_path().unwrap();
+                assert!(path.iter_edges().all(|Edge(_, _, w)| w <= e));
+            }
+        }
+    }
+}
+
+#[test]
+fn ut_ungraph_bipartite_matching() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..20 {
+        // Workers 0..15 and shifts 15..30.
+        let mut g = Graph::<usize, (), ()>::new();
+        let mut flow = gdsl::digraph::Graph::<usize, (), u32>::new();
+        for i in 0..32 {
+            flow.insert(gdsl::digraph::Node::new(i, ()));
+        }
+        for i in 0..30 {
+            g.insert(Node::new(i, ()));
+            match i < 15 {
+                true => flow[30].connect(&flow[i], 1),
+                false => flow[i].connect(&flow[31], 1),
+            }
+        }
+        for _ in 0..rng.gen_range(10..50) {
+            let (u, v) = (rng.gen_range(0..15), rng.gen_range(15..30));
+            if !g[u].is_connected(&v) {
+                g[u].connect(&g[v], ());
+                flow[u].connect(&flow[v], 1);
+            }
+        }
+
+        let bipartition = g.bipartition().unwrap();
+        assert!(bipartition.left().len() + bipartition.right().len() == 30);
+        for (_, node) in g.iter() {
+            for Edge(u, v, _) in node {
+                assert!(bipartition.colour(u.key()) != bipartition.colour(v.key()));
+            }
+        }
+
+        let matching = g.hopcroft_karp().unwrap();
+        let maximum = flow.dinic(&30, &31, |e| e.2).unwrap();
+        assert!(matching.len() as u32 == maximum.value());
+
+        let mut matched = [false; 30];
+        for Edge(u, v, _) in &matching {
+            assert!(u.is_connected(v.key()));
+            assert!(bipartition.colour(u.key()) == Some(0));
+            assert!(!matched[*u.key()] && !matched[*v.key()]);
+            matched[*u.key()] = true;
+            matched[*v.key()] = true;
+        }
+
+        // An edge within a side makes the graph bipartite only if it closes
+        // no odd cycle, which the witness must show.
+        let (u, v) = (rng.gen_range(0..15), rng.gen_range(0..15));
+        g[u].connect(&g[v], ());
+        match g.bipartition() {
+            Ok(b) => assert!(g.hopcroft_karp().is_ok() && b.colour(&u) != b.colour(&v)),
+            Err(odd) => {
+                let cycle = odd.cycle();
+                assert!(cycle.edges.len() % 2 == 1);
+                assert!(
+                    cycle.first_edge().unwrap().source() == cycle.last_edge().unwrap().target()
+                );
+                for (a, b) in cycle.edges.iter().zip(cycle.edges.iter().skip(1)) {
+                    assert!(a.target() == b.source());
+                }
+                for Edge(a, b, _) in cycle.iter_edges() {
+                    assert!(a.is_connected(b.key()));
+                }
+                assert!(g.hopcroft_karp().is_err());
+            }
+        }
+    }
+}
+
+#[test]
+fn ut_ungraph_hungarian() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..30 {
+        // Jobs 0..8 and a varying number of machines 8..8 + m.
+        let m = rng.gen_range(1..12);
+        let mut g = Graph::<usize, (), i64>::new();
+        let mut flow = gdsl::digraph::Graph::<usize, (), (i64, i64)>::new();
+        for i in 0..8 + m {
+            g.insert(Node::new(i, ()));
+        }
+        for i in 0..8 + m + 2 {
+            flow.insert(gdsl::digraph::Node::new(i, ()));
+        }
+        let (s, t) = (8 + m, 8 + m + 1);
+        for i in 0..8 + m {
+            match i < 8 {
+                true => flow[s].connect(&flow[i], (1, 0)),
+                false => flow[i].connect(&flow[t], (1, 0)),
+            }
+        }
+        for _ in 0..rng.gen_range(0..40) {
+            let (u, v) = (rng.gen_range(0..8), rng.gen_range(8..8 + m));
+            let w = rng.gen_range(-20..50);
+            if !g[u].is_connected(&v) {
+                g[u].connect(&g[v], w);
+                flow[u].connect(&flow[v], (1, w));
+            }
+        }
+        // Edges within a side are ignored.
+        g[0].connect(&g[1], -100);
+
+        let assignment = g.hungarian(|node| *node.key() < 8, |e| e.2);
+        let optimum = flow
+            .min_cost_max_flow(&s, &t, |e| e.2 .0, |e| e.2 .1)
+            .unwrap()
+            .unwrap();
+        assert!(assignment.len() as i64 == optimum.value());
+        assert!(assignment.cost() == optimum.cost());
+
+        let mut assigned = vec![false; 8 + m];
+        for Edge(u, v, _) in assignment.edges() {
+            assert!(*u.key() < 8 && *v.key() >= 8);
+            assert!(!assigned[*u.key()] && !assigned[*v.key()]);
+            assigned[*u.key()] = true;
+            assigned[*v.key()] = true;
+        }
+        let total = assignment.edges().iter().map(|e| e.2).sum::<i64>();
+        assert!(total == assignment.cost());
+    }
+}
+
+#[test]
+fn ut_ungraph_blossom_matching() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    // The largest size and weight of a matching among the nodes from `v`
+    // on, found by exhaustive search.
+    fn exhaustive(v: usize, used: &mut [bool], edges: &[(usize, usize, i64)]) -> (usize, i64) {
+        if v == used.len() {
+            return (0, 0);
+        }
+        if used[v] {
+            return exhaustive(v + 1, used, edges);
+        }
+        used[v] = true;
+        let mut best = exhaustive(v + 1, used, edges);
+        for &(a, b, w) in edges {
+            let u = match (a == v, b == v) {
+                (true, _) => b,
+                (_, true) => a,
+                _ => continue,
+            };
+            if !used[u] {
+                used[u] = true;
+                let (size, weight) = exhaustive(v + 1, used, edges);
+                used[u] = false;
+                best = (best.0.max(size + 1), best.1.max(weight + w));
+            }
+        }
+        used[v] = false;
+        best
+    }
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..500 {
+        let n = rng.gen_range(1..10);
+        let mut g = Graph::<usize, (), i64>::new();
+        for i in 0..n {
+            g.insert(Node::new(i, ()));
+        }
+        let mut edges = vec![];
+        for _ in 0..rng.gen_range(0..20) {
+            let (u, v, w) = (
+                rng.gen_range(0..n),
+                rng.gen_range(0..n),
+                rng.gen_range(-5..20),
+            );
+            g[u].connect(&g[v], w);
+            edges.push((u, v, w));
+        }
+        let (size, weight) = exhaustive(0, &mut vec![false; n], &edges);
+
+        let cardinality = g.maximum_matching();
+        let weighted = g.maximum_weight_matching(|e| e.2);
+        assert!(cardinality.len() == size);
+        assert!(weighted.iter().map(|e| e.2).sum::<i64>() == weight);
+
+        for matching in [&cardinality, &weighted] {
+            let mut matched = vec![false; n];
+            for Edge(u, v, _) in matching {
+                assert!(u.is_connected(v.key()));
+                assert!(!matched[*u.key()] && !matched[*v.key()]);
+                matched[*u.key()] = true;
+                matched[*v.key()] = true;
+            }
+        }
+    }
+}
+
+#[test]
+fn ut_ungraph_centrality() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..12);
+        let mut g = Graph::<usize, (), ()>::new();
+        for i in 0..n {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..rng.gen_range(0..25) {
+            let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            if u != v && !g[u].is_connected(&v) {
+                g[u].connect(&g[v], ());
+            }
+        }
+
+        // Count the shortest paths between every pair of nodes, extending
+        // the paths by one edge at a time.
+        let mut distance = vec![vec![usize::MAX; n]; n];
+        let mut count = vec![vec![0.0; n]; n];
+        for s in 0..n {
+            distance[s][s] = 0;
+            count[s][s] = 1.0;
+            for d in 0..n {
+                let layer = (0..n).filter(|&v| distance[s][v] == d).collect::<Vec<_>>();
+                for v in layer {
+                    for Edge(_, w, _) in g[v].iter() {
+                        let w = *w.key();
+                        if distance[s][w] == usize::MAX {
+                            distance[s][w] = d + 1;
+                        }
+                        if distance[s][w] == d + 1 {
+                            count[s][w] += count[s][v];
+                        }
+                    }
+                }
+            }
+        }
+
+        // Every pair of nodes is counted once.
+        let betweenness = g.brandes().unweighted();
+        let normalized = g.brandes().normalized(true).unweighted();
+        for v in 0..n {
+            let mut expected = 0.0;
+            for s in 0..n {
+                for t in s + 1..n {
+                    if s != v
+                        && t != v
+                        && distance[s][v] != usize::MAX
+                        && distance[v][t] != usize::MAX
+                        && distance[s][v] + distance[v][t] == distance[s][t]
+                    {
+                        expected += count[s][v] * count[v][t] / count[s][t];
+                    }
+                }
+            }
+            assert!((betweenness.node(&v).unwrap() - expected).abs() < 1e-9);
+            if n > 2 {
+                let pairs = ((n - 1) * (n - 2) / 2) as f64;
+                assert!((normalized.node(&v).unwrap() * pairs - expected).abs() < 1e-9);
+            }
+        }
+        for (Edge(u, v, _), score) in betweenness.edges() {
+            let (u, v) = (*u.key(), *v.key());
+            assert!(betweenness.edge(&v, &u) == Some(*score));
+            let mut expected = 0.0;
+            for s in 0..n {
+                for t in s + 1..n {
+                    for (a, b) in [(u, v), (v, u)] {
+                        if distance[s][a] != usize::MAX
+                            && distance[b][t] != usize::MAX
+                            && distance[s][a] + 1 + distance[b][t] == distance[s][t]
+                        {
+                            expected += count[s][a] * count[b][t] / count[s][t];
+                        }
+                    }
+                }
+            }
+            assert!((score - expected).abs() < 1e-9);
+        }
+
+        // Sampling half of the nodes gives an estimate of the same total.
+        if n > 1 {
+            let sampled = g.brandes().sample(n / 2, 11).unweighted();
+            assert!(sampled.nodes().len() == n);
+            assert!(sampled.nodes().values().all(|&score| score >= 0.0));
+        }
+
+        let closeness = g.closeness_centrality();
+        let harmonic = g.harmonic_centrality();
+        for v in 0..n {
+            let reached = (0..n)
+                .filter(|&u| u != v && distance[v][u] != usize::MAX)
+                .map(|u| distance[v][u])
+                .collect::<Vec<_>>();
+            let total = reached.iter().sum::<usize>();
+            let expected = match total {
+                0 => 0.0,
+                _ => {
+                    let r = reached.len() as f64;
+                    (r / total as f64) * (r / (n - 1) as f64)
+                }
+            };
+            assert!((closeness[&v] - expected).abs() < 1e-9);
+            let expected = reached.iter().map(|&d| 1.0 / d as f64).sum::<f64>();
+            assert!((harmonic[&v] - expected).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn ut_ungraph_eigenvector_centrality() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..20);
+        let mut g = Graph::<usize, (), f64>::new();
+        for i in 0..n {
+            g.insert(Node::new(i, ()));
+        }
+        for _ in 0..rng.gen_range(0..40) {
+            let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            if u != v && !g[u].is_connected(&v) {
+                g[u].connect(&g[v], rng.gen_range(1..5) as f64);
+            }
+        }
+        let image = |scores: &ahash::AHashMap<usize, f64>, v: usize| {
+            g[v].iter()
+                .map(|Edge(_, u, w)| w * scores[u.key()])
+                .sum::<f64>()
+        };
+
+        // The scores are an eigenvector of unit length with a non-negative
+        // eigenvalue.
+        let scores = g
+            .eigenvector_centrality(|Edge(_, _, w)| *w, 1e-12, 100000)
+            .unwrap();
+        let norm = scores.values().map(|x| x * x).sum::<f64>();
+        assert!((norm - 1.0).abs() < 1e-9);
+        let eigenvalue = (0..n).map(|v| image(&scores, v) * scores[&v]).sum::<f64>();
+        assert!(eigenvalue >= 0.0);
+        for v in 0..n {
+            assert!(scores[&v] >= 0.0);
+            assert!((image(&scores, v) - eigenvalue * scores[&v]).abs() < 1e-6);
+        }
+
+        // The scores are the same up to scaling for every beta, and only
+        // converge for small enough alpha.
+        let alpha = 0.5 / (eigenvalue + 1.0);
+        let katz = g
+            .katz_centrality(alpha, 2.0, |Edge(_, _, w)| *w, 1e-12, 1000)
+            .unwrap();
+        let beta = (0..n)
+            .map(|v| katz[&v] - alpha * image(&katz, v))
+            .collect::<Vec<_>>();
+        assert!(beta.iter().all(|b| (b - beta[0]).abs() < 1e-9));
+        if eigenvalue > 0.0 {
+            let error = g
+                .katz_centrality(2.0 / eigenvalue, 1.0, |Edge(_, _, w)| *w, 1e-12, 50)
+                .unwrap_err();
+            assert!(error.iterations() == 50);
+            assert!(error.scores().len() == n);
+        }
+    }
+}
+
+#[test]
+fn ut_ungraph_communities() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    // A ring of cliques joined by single edges is split into the cliques.
+    let (cliques, size) = (4, 6);
+    let mut g = Graph::<usize, (), f64>::new();
+    for i in 0..cliques * size {
+        g.insert(Node::new(i, ()));
+    }
+    for c in 0..cliques {
+        for i in 0..size {
+            for j in i + 1..size {
+                g[c * size + i].connect(&g[c * size + j], 1.0);
+            }
+        }
+        g[c * size].connect(&g[(c + 1) % cliques * size + 1], 1.0);
+    }
+    for seed in 0..10 {
+        for communities in [
+            g.louvain(|Edge(_, _, w)| *w, seed),
+            g.label_propagation(|Edge(_, _, w)| *w, seed),
+        ] {
+            assert!(communities.len() == cliques);
+            for v in 0..cliques * size {
+                assert!(communities.same_component(&v, &(v / size * size)));
+            }
+        }
+    }
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..30);
+        let mut g = Graph::<usize, (), f64>::new();
+        for i in 0..n {
+            g.insert(Node::new(i, ()));
+        }
+        let mut weights = vec![vec![0.0; n]; n];
+        for _ in 0..rng.gen_range(0..60) {
+            let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            if u != v && !g[u].is_connected(&v) {
+                let w = rng.gen_range(1..4) as f64;
+                g[u].connect(&g[v], w);
+                weights[u][v] = w;
+                weights[v][u] = w;
+            }
+        }
+        let degree = weights
+            .iter()
+            .map(|row| row.iter().sum::<f64>())
+            .collect::<Vec<_>>();
+        let total = degree.iter().sum::<f64>();
+
+        // The modularity summed over all pairs of nodes in a community.
+        let expected = |partition: &Components<usize, (), f64>| {
+            if total == 0.0 {
+                return 0.0;
+            }
+            let mut sum = 0.0;
+            for u in 0..n {
+                for v in 0..n {
+                    if partition.same_component(&u, &v) {
+                        sum += weights[u][v] - degree[u] * degree[v] / total;
+                    }
+                }
+            }
+            sum / total
+        };
+
+        let seed = rng.gen();
+        let louvain = g.louvain(|Edge(_, _, w)| *w, seed);
+        let propagation = g.label_propagation(|Edge(_, _, w)| *w, seed);
+        let components = g.connected_components();
+        for partition in [&louvain, &propagation, &components] {
+            let modularity = g.modularity(partition, |Edge(_, _, w)| *w);
+            assert!((modularity - expected(partition)).abs() < 1e-9);
+            assert!(partition.groups().iter().map(Vec::len).sum::<usize>() == n);
+        }
+
+        // Merging communities only helps, so Louvain does at least as well
+        // as every node on its own.
+        let singletons = -degree.iter().map(|d| d * d).sum::<f64>() / (total * total);
+        let modularity = g.modularity(&louvain, |Edge(_, _, w)| *w);
+        assert!(total == 0.0 || modularity >= singletons - 1e-9);
+
+        // Every node ends up with a most common label of its neighbours.
+        for v in 0..n {
+            let mut links = vec![0.0; propagation.len()];
+            for u in 0..n {
+                links[propagation.component(&u).unwrap()] += weights[v][u];
+            }
+            let own = links[propagation.component(&v).unwrap()];
+            assert!(links.iter().all(|&w| w <= own) || degree[v] == 0.0);
+        }
+
+        // The same seed gives the same partition, however the graph was
+        // built.
+        let mut h = Graph::<usize, (), f64>::new();
+        for i in (0..n).rev() {
+            h.insert(Node::new(i, ()));
+        }
+        for u in (0..n).rev() {
+            for v in 0..u {
+                if weights[u][v] > 0.0 {
+                    h[u].connect(&h[v], weights[u][v]);
+                }
+            }
+        }
+        let again = h.louvain(|Edge(_, _, w)| *w, seed);
+        let propagated = h.label_propagation(|Edge(_, _, w)| *w, seed);
+        for v in 0..n {
+            assert!(again.component(&v) == louvain.component(&v));
+            assert!(propagated.component(&v) == propagation.component(&v));
+        }
+    }
+}
+
+#[test]
+fn ut_ungraph_colouring() {
+    use gdsl::ungraph::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    // Whether the nodes from `v` on can be coloured with `k` colours.
+    fn colourable(v: usize, k: usize, colour: &mut [usize], adjacent: &[Vec<bool>]) -> bool {
+        if v == colour.len() {
+            return true;
+        }
+        for c in 0..k {
+            if (0..v).all(|u| !adjacent[v][u] || colour[u] != c) {
+                colour[v] = c;
+                if colourable(v + 1, k, colour, adjacent) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..10);
+        let mut g = Graph::<usize, (), ()>::new();
+        for i in 0..n {
+            g.insert(Node::new(i, ()));
+        }
+        let mut adjacent = vec![vec![false; n]; n];
+        for _ in 0..rng.gen_range(0..30) {
+            let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            if u != v && !g[u].is_connected(&v) {
+                g[u].connect(&g[v], ());
+                adjacent[u][v] = true;
+                adjacent[v][u] = true;
+            }
+        }
+        let chromatic = (0..=n)
+            .find(|&k| colourable(0, k, &mut vec![0; n], &adjacent))
+            .unwrap();
+
+        // The smallest number of nodes a node has left among the remaining
+        // ones when removing them in the smallest last order.
+        let mut degeneracy = 0;
+        let mut removed = vec![false; n];
+        for _ in 0..n {
+            let degree = |v: usize| (0..n).filter(|&u| !removed[u] && adjacent[v][u]).count();
+            let v = (0..n)
+                .filter(|&v| !removed[v])
+                .min_by_key(|&v| degree(v))
+                .unwrap();
+            degeneracy = degeneracy.max(degree(v));
+            removed[v] = true;
+        }
+
+        let colours = |colouring: &ahash::AHashMap<usize, usize>| {
+            assert!(colouring.len() == n);
+            for u in 0..n {
+                for v in 0..n {
+                    assert!(!adjacent[u][v] || colouring[&u] != colouring[&v]);
+                }
+            }
+            colouring.values().map(|&c| c + 1).max().unwrap_or(0)
+        };
+        for strategy in [
+            ColouringStrategy::LargestFirst,
+            ColouringStrategy::SmallestLast,
+            ColouringStrategy::DSatur,
+        ] {
+            let used = colours(&g.greedy_colouring(strategy));
+            assert!(used >= chromatic);
+            if strategy == ColouringStrategy::SmallestLast {
+                assert!(used <= degeneracy + 1);
+            }
+        }
+        assert!(colours(&g.chromatic_colouring(usize::MAX).unwrap()) == chromatic);
+        match g.chromatic_colouring(0) {
+            Ok(colouring) => assert!(colours(&colouring) == chromatic),
+            Err(error) => assert!(colours(error.colouring()) == error.colours()),
+        }
+    }
+
+    // An odd cycle needs a search to prove that three colours are needed.
+    let g = gdsl::ungraph![
+        (usize)
+        (0) => [1]
+        (1) => [2]
+        (2) => [3]
+        (3) => [4]
+        (4) => [0]
+    ];
+    let error = g.chromatic_colouring(0).unwrap_err();
+    assert!(error.colours() == 3);
+    assert!(g.chromatic_colouring(10).is_ok());
+
+    let g = Graph::<usize, (), ()>::new();
+    assert!(g.chromatic_colouring(0).unwrap().is_empty());
+}